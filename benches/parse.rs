@@ -0,0 +1,76 @@
+//! Times lexing and parsing of a large generated java file
+//!
+//! Run with `cargo bench`
+
+#![allow(dead_code)]
+
+extern crate clap;
+extern crate colored;
+extern crate git2;
+extern crate mdbook;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+extern crate threadpool;
+
+#[path = "../src/document/mod.rs"]
+mod document;
+#[path = "../src/grammar/mod.rs"]
+mod grammar;
+#[path = "../src/model/mod.rs"]
+mod model;
+#[path = "../src/parse/mod.rs"]
+mod parse;
+
+use parse::parse::construct_ast;
+use parse::parse::lex_contents;
+use std::time::Duration;
+use std::time::Instant;
+
+/// Generates a java class with the given number of documented fields and methods
+fn generate_class(members: usize) -> String {
+    let mut source = String::from("package com.example.bench;\n\nimport java.util.List;\n\n");
+    source.push_str("/**\n * A generated class\n *\n * @author Bench\n */\npublic class Generated {\n");
+
+    for i in 0..members {
+        source.push_str(format!("    /** Field number {} */\n", i).as_str());
+        source.push_str(format!("    private List<String> field{} = null;\n\n", i).as_str());
+        source.push_str("    /**\n");
+        source.push_str(format!("     * Computes value {} from the inputs\n", i).as_str());
+        source.push_str("     *\n     * @param a The first input\n     * @param b The second input\n");
+        source.push_str("     * @return The computed value\n     */\n");
+        source.push_str(format!("    public static int compute{}(int a, long b) {{\n", i).as_str());
+        source.push_str("        // Combine the inputs\n");
+        source.push_str("        String text = \"{ not a block }\";\n");
+        source.push_str("        return a + (int) b;\n    }\n\n");
+    }
+    source.push_str("}\n");
+
+    source
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs() as f64 * 1000.0 + duration.subsec_nanos() as f64 / 1_000_000.0
+}
+
+fn main() {
+    let source = generate_class(5000);
+    let runs = 10;
+    let mut lex_total = Duration::new(0, 0);
+    let mut parse_total = Duration::new(0, 0);
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let tokens = lex_contents(&source);
+        lex_total += start.elapsed();
+
+        let start = Instant::now();
+        construct_ast(tokens);
+        parse_total += start.elapsed();
+    }
+
+    println!("Source size: {} bytes", source.len());
+    println!("lex_contents:  {:.2} ms/run", millis(lex_total) / runs as f64);
+    println!("construct_ast: {:.2} ms/run", millis(parse_total) / runs as f64);
+}
@@ -1,9 +1,16 @@
+pub mod render;
+pub mod renderer;
+
 pub mod document {
     extern crate colored;
     extern crate git2;
 
     use mdbook::MDBook;
 
+    use std::cmp;
+    use std::cmp::Ordering;
+    use std::collections::BTreeMap;
+    use std::collections::HashSet;
     use std::fs;
     use std::fs::File;
     use std::io::prelude::*;
@@ -11,15 +18,31 @@ pub mod document {
     use std::path::PathBuf;
 
     use colored::*;
+    use document::render::*;
+    use document::renderer::render_with;
+    use document::renderer::HtmlRenderer;
     use git2::Repository;
+    use serde_json;
     use model::contents::ApplicationDoc;
+    use model::links::page_path;
+    use model::model::Access;
+    use model::model::AnnotationType;
     use model::model::Class;
     use model::model::Options;
+    use model::model::OutputFormat;
     use model::model::Enumeration;
+    use model::model::GenConfig;
     use model::model::Interface;
+    use model::model::LicenseStyle;
+    use model::model::LintKind;
+    use model::model::LintWarning;
     use model::model::Member;
     use model::model::Method;
+    use model::model::NestedTypes;
+    use model::model::ObjectType;
+    use model::model::Param;
     use model::model::Project;
+    use model::model::TypeLinks;
 
     /// Traverses the file structure to find all java files for parsing.
     ///
@@ -100,63 +123,67 @@ pub mod document {
         files
     }
 
+    /// Continues a blockquote over every line of multi-line text
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text inside the blockquote
+    fn quote_lines(text: &str) -> String {
+        text.replace("\n", "\n > ")
+    }
+
     /// Generates the markdown documentation for a class
     ///
     /// # Arguments
     ///
     /// * `class` - The class struct containing the javadoc data
-    pub fn gen_class_docs(class: Class) -> String {
+    /// * `config` - The generation settings
+    pub fn gen_class_docs(class: &Class, config: &GenConfig) -> String {
         let mut doc = String::new();
+        let kind = if class.record { "Record" } else { "Class" };
 
         if class.file_path != "" {
             doc.push_str(
-                format!("# Class {} [[src]]({})  \n\n", class.name, class.file_path).as_str(),
+                format!("# {} {} [[src]]({})  \n\n", kind, class.name, class.file_path).as_str(),
             );
             doc.push_str(format!(" > {}  \n\n", class.signature.trim()).as_str());
         } else {
-            doc.push_str(format!("# Class {}\n\n", class.name).as_str());
+            doc.push_str(format!("# {} {}\n\n", kind, class.name).as_str());
         }
 
-        if class.license != "" {
-            doc.push_str("<details>  \n");
-            doc.push_str("  <summary>  \n");
-            doc.push_str("    Show license  \n\n");
-            doc.push_str("  </summary>  \n");
-
-            doc.push_str("  <ul>  \n");
-            doc.push_str(class.license.as_str());
-            doc.push_str("  </ul>  \n");
-            doc.push_str("</details>  \n\n");
-            doc.push_str("<br/>");
-        }
+        doc.push_str(gen_metadata(&class.author, &class.version, &class.since, config).as_str());
+        doc.push_str(gen_license(class.license.as_str(), config).as_str());
 
         doc.push_str(format!("Access: {}  \n", class.access.trim()).as_str());
-        if class.description.as_str() != "" {
-            doc.push_str(format!("Description:  \n > {}  \n\n", class.description.trim()).as_str());
-        }
-        if class.author != "" {
-            doc.push_str(format!("Author: {}  \n", class.author).as_str());
+        if !class.modifiers.is_empty() {
+            doc.push_str(format!("Modifiers: {}  \n", order_modifiers(&class.modifiers).join(" ")).as_str());
         }
-        if class.version != "" {
-            doc.push_str(format!("Since version: {}  \n", class.version).as_str());
+        if class.description.as_str() != "" {
+            doc.push_str(format!("Description:  \n > {}  \n\n", quote_lines(&description_to_markdown(&class.description))).as_str());
         }
-        if class.parent != "" {
-            doc.push_str(format!("Parent class: {}  \n", class.parent).as_str());
+        if !class.components.is_empty() {
+            doc.push_str(format!("Components:\n\n{}\n", gen_param_table(&class.components)).as_str());
         }
+        doc.push_str(gen_inheritance(&class.parent, &class.interfaces, config).as_str());
+        doc.push_str(gen_permits(&class.permits, config).as_str());
+        doc.push_str(gen_custom_tags(&class.custom_tags).as_str());
 
-        if class.interfaces.len() > 0 {
-            doc.push_str("Interfaces:  \n");
+        if !class.serial_data.is_empty() {
+            doc.push_str(format!("Serial data: {}  \n", class.serial_data).as_str());
+        }
+        if !class.serial_fields.is_empty() {
+            doc.push_str("Serial fields:  \n");
 
-            for inter in class.interfaces {
-                doc.push_str(format!("- {}  \n", inter).as_str());
+            for field in &class.serial_fields {
+                doc.push_str(format!("- {} `{}`: {}  \n", field.name, field.field_type, field.desc).as_str());
             }
-            doc.push_str("\n");
+            doc.push('\n');
         }
 
         doc.push_str(format!("package: {}  \n\n", class.package_name.trim()).as_str());
 
         if class.exceptions.len() > 0 {
-            for exception in class.exceptions {
+            for exception in &class.exceptions {
                 doc.push_str(
                     format!(
                         "Throws {}: {}  \n\n",
@@ -174,7 +201,7 @@ pub mod document {
         doc.push_str("  </summary>  \n");
 
         doc.push_str("  <ul>  \n");
-        for dep in class.dependencies {
+        for dep in &class.dependencies {
             doc.push_str(format!("<li>{}</li>\n", dep).as_str());
         }
         doc.push_str("  </ul>  \n");
@@ -183,12 +210,151 @@ pub mod document {
         doc
     }
 
+    /// Generates the metadata line of a type listing its authors, version, and since
+    /// version, e.g. `Author(s): Alice, Bob · Version: 2.0 · Since: 1.2`. Empty parts are
+    /// left out, and no line is generated when the type has none of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `author` - The authors of the type, separated by commas
+    /// * `version` - The `@version` of the type
+    /// * `since` - The `@since` version of the type
+    /// * `config` - The generation settings with the separator between the parts
+    pub fn gen_metadata(author: &str, version: &str, since: &str, config: &GenConfig) -> String {
+        let mut parts = Vec::new();
+
+        if !author.is_empty() {
+            parts.push(format!("Author(s): {}", author));
+        }
+        if !version.is_empty() {
+            parts.push(format!("Version: {}", version));
+        }
+        if !since.is_empty() {
+            parts.push(format!("Since: {}", since));
+        }
+
+        if parts.is_empty() {
+            String::new()
+        } else {
+            format!("{}  \n\n", parts.join(config.metadata_separator.as_str()))
+        }
+    }
+
+    /// Generates the license header of a type as a collapsed `<details>` block or an
+    /// HTML comment. The footer style is rendered by `gen_license_footer`.
+    ///
+    /// # Arguments
+    ///
+    /// * `license` - The license header text, empty when the file has none
+    /// * `config` - The generation settings deciding where the license is rendered
+    fn gen_license(license: &str, config: &GenConfig) -> String {
+        if license.is_empty() {
+            return String::new();
+        }
+
+        match config.license_style {
+            LicenseStyle::Details => format!(
+                "<details>\n<summary>Show license</summary>\n\n```text\n{}\n```\n\n</details>\n\n",
+                license
+            ),
+            LicenseStyle::Comment => format!("<!--\n{}\n-->\n\n", license.replace("-->", "-- >")),
+            LicenseStyle::Footer => String::new(),
+        }
+    }
+
+    /// Generates the license footer of a type when the config renders the license
+    /// after the member sections
+    ///
+    /// # Arguments
+    ///
+    /// * `license` - The license header text, empty when the file has none
+    /// * `config` - The generation settings deciding where the license is rendered
+    fn gen_license_footer(license: &str, config: &GenConfig) -> String {
+        if license.is_empty() || config.license_style != LicenseStyle::Footer {
+            return String::new();
+        }
+
+        let lines: Vec<&str> = license.lines().map(|l| l.trim_end()).collect();
+        let mut footer = String::from("---\n\n");
+
+        // Lines followed by another line of the same paragraph end with a hard break
+        for (i, line) in lines.iter().enumerate() {
+            footer.push_str(line);
+            if !line.is_empty() && lines.get(i + 1).map_or(false, |next| !next.is_empty()) {
+                footer.push_str("  ");
+            }
+            footer.push('\n');
+        }
+
+        footer
+    }
+
+    /// Generates the line listing the parent class and implemented interfaces of a
+    /// type. Types that are part of the documented application are linked. Returns an
+    /// empty string when the type has neither.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The parent class, empty when the type doesn't extend one
+    /// * `interfaces` - The implemented interfaces
+    /// * `config` - The generation settings
+    fn gen_inheritance(parent: &str, interfaces: &[String], config: &GenConfig) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if !parent.is_empty() {
+            parts.push(format!("Extends: {}", config.link_code(parent)));
+        }
+        if !interfaces.is_empty() {
+            let names: Vec<String> = interfaces.iter().map(|i| config.link_code(i)).collect();
+            parts.push(format!("Implements: {}", names.join(", ")));
+        }
+
+        if !parts.is_empty() {
+            format!("{}  \n", parts.join(" \u{2014} "))
+        } else {
+            String::new()
+        }
+    }
+
+    /// Generates the line listing the subtypes permitted by a sealed type, empty when
+    /// the type is not sealed
+    ///
+    /// # Arguments
+    ///
+    /// * `permits` - The permitted subtypes
+    /// * `config` - The generation settings
+    fn gen_permits(permits: &[String], config: &GenConfig) -> String {
+        if permits.is_empty() {
+            return String::new();
+        }
+
+        let names: Vec<String> = permits.iter().map(|p| config.link_code(p)).collect();
+        format!("Permits: {}  \n", names.join(", "))
+    }
+
+    /// Generates a labeled line for each custom block tag of a type, e.g.
+    /// `reviewedBy: Alice`
+    ///
+    /// # Arguments
+    ///
+    /// * `tags` - The custom tags with their text
+    fn gen_custom_tags(tags: &Vec<(String, String)>) -> String {
+        let mut doc = String::new();
+
+        for (tag, text) in tags {
+            doc.push_str(format!("{}: {}  \n", tag, description_to_markdown(text)).as_str());
+        }
+
+        doc
+    }
+
     /// Generates the markdown documentation for an interface
     ///
     /// # Arguments
     ///
     /// * `inter` - The interface struct containing the javadoc data
-    pub fn gen_interface_docs(inter: Interface) -> String {
+    /// * `config` - The generation settings
+    pub fn gen_interface_docs(inter: &Interface, config: &GenConfig) -> String {
         let mut doc = String::new();
 
         if inter.file_path != "" {
@@ -203,9 +369,17 @@ pub mod document {
             doc.push_str(format!("# Interface {}\n\n", inter.name).as_str());
         }
 
+        doc.push_str(gen_metadata(&inter.author, &inter.version, &inter.since, config).as_str());
+
         if inter.description.as_str() != "" {
-            doc.push_str(format!("description: {}  \n", inter.description.trim()).as_str());
+            doc.push_str(format!("description: {}  \n", description_to_markdown(&inter.description)).as_str());
+        }
+        if !inter.parents.is_empty() {
+            let names: Vec<String> = inter.parents.iter().map(|p| config.link_code(p)).collect();
+            doc.push_str(format!("Extends: {}  \n", names.join(", ")).as_str());
         }
+        doc.push_str(gen_permits(&inter.permits, config).as_str());
+        doc.push_str(gen_custom_tags(&inter.custom_tags).as_str());
         doc.push_str(format!("privacy: {}  \n", inter.access.trim()).as_str());
         doc.push_str(format!("package: {}  \n\n", inter.package_name.trim()).as_str());
         doc.push_str("## Dependencies\n\n");
@@ -215,7 +389,7 @@ pub mod document {
         doc.push_str("  </summary>  \n");
 
         doc.push_str("  <ul>  \n");
-        for dep in inter.dependencies {
+        for dep in &inter.dependencies {
             doc.push_str(format!("    <li>{}</li>\n", dep).as_str());
         }
         doc.push_str("  </ul>  \n");
@@ -224,59 +398,75 @@ pub mod document {
         doc
     }
 
+    /// Generates the markdown documentation for an annotation type
+    ///
+    /// # Arguments
+    ///
+    /// * `annotation` - The annotation type struct containing the javadoc data
+    /// * `config` - The generation settings
+    pub fn gen_annotation_docs(annotation: &AnnotationType, config: &GenConfig) -> String {
+        let mut doc = String::new();
+
+        if !annotation.file_path.is_empty() {
+            doc.push_str(
+                format!(
+                    "# Annotation {} [[src]]({})  \n\n",
+                    annotation.name, annotation.file_path
+                ).as_str(),
+            );
+            doc.push_str(format!(" > {}  \n\n", annotation.signature.trim()).as_str());
+        } else {
+            doc.push_str(format!("# Annotation {}\n\n", annotation.name).as_str());
+        }
+
+        doc.push_str(gen_metadata(&annotation.author, &annotation.version, &annotation.since, config).as_str());
+
+        if annotation.description.as_str() != "" {
+            doc.push_str(format!("description: {}  \n", description_to_markdown(&annotation.description)).as_str());
+        }
+        doc.push_str(gen_custom_tags(&annotation.custom_tags).as_str());
+        doc.push_str(format!("privacy: {}  \n", annotation.access.trim()).as_str());
+        doc.push_str(format!("package: {}  \n\n", annotation.package_name.trim()).as_str());
+        doc.push_str(
+            section_to_markdown(&element_section(&annotation.elements, &annotation.file_path, &config.for_interface()))
+                .as_str(),
+        );
+
+        doc
+    }
+
     /// Generates the markdown documentation for a enumeration
     ///
     /// # Arguments
     ///
-    /// * `class` - The class struct containing the javadoc data
-    pub fn gen_enum_docs(enum_ob: Enumeration) -> String {
+    /// * `enum_ob` - The enumeration struct containing the javadoc data
+    /// * `config` - The generation settings
+    pub fn gen_enum_docs(enum_ob: &Enumeration, config: &GenConfig) -> String {
         let mut doc = String::new();
 
         if enum_ob.file_path != "" {
             doc.push_str(
                 format!(
-                    "# Class {} [[src]]({})  \n\n",
+                    "# Enum {} [[src]]({})  \n\n",
                     enum_ob.name, enum_ob.file_path
                 ).as_str(),
             );
         } else {
-            doc.push_str(format!("# Class {}\n\n", enum_ob.name).as_str());
+            doc.push_str(format!("# Enum {}\n\n", enum_ob.name).as_str());
         }
 
-        if enum_ob.license != "" {
-            doc.push_str("<details>  \n");
-            doc.push_str("  <summary>  \n");
-            doc.push_str("    Show license  \n\n");
-            doc.push_str("  </summary>  \n");
-
-            doc.push_str("  <ul>  \n");
-            doc.push_str(enum_ob.license.as_str());
-            doc.push_str("  </ul>  \n");
-            doc.push_str("</details>  \n\n");
-            doc.push_str("<br/>");
-        }
+        doc.push_str(gen_metadata(&enum_ob.author, &enum_ob.version, &enum_ob.since, config).as_str());
+        doc.push_str(gen_license(enum_ob.license.as_str(), config).as_str());
 
         doc.push_str(format!("Access: {}  \n", enum_ob.access.trim()).as_str());
         if enum_ob.description.as_str() != "" {
             doc.push_str(
-                format!("Description:  \n > {}  \n\n", enum_ob.description.trim()).as_str(),
+                format!("Description:  \n > {}  \n\n", quote_lines(&description_to_markdown(&enum_ob.description))).as_str(),
             );
         }
-        if enum_ob.author != "" {
-            doc.push_str(format!("Author: {}  \n", enum_ob.author).as_str());
-        }
-        if enum_ob.version != "" {
-            doc.push_str(format!("Since version: {}  \n", enum_ob.version).as_str());
-        }
 
-        if enum_ob.interfaces.len() > 0 {
-            doc.push_str("Interfaces:  \n");
-
-            for inter in enum_ob.interfaces {
-                doc.push_str(format!("- {}  \n", inter).as_str());
-            }
-            doc.push_str("\n");
-        }
+        doc.push_str(gen_inheritance("", &enum_ob.interfaces, config).as_str());
+        doc.push_str(gen_custom_tags(&enum_ob.custom_tags).as_str());
 
         doc.push_str(format!("package: {}  \n\n", enum_ob.package_name.trim()).as_str());
 
@@ -287,12 +477,25 @@ pub mod document {
         doc.push_str("  </summary>  \n");
 
         doc.push_str("  <ul>  \n");
-        for dep in enum_ob.dependencies {
+        for dep in &enum_ob.dependencies {
             doc.push_str(format!("<li>{}</li>\n", dep).as_str());
         }
         doc.push_str("  </ul>  \n");
         doc.push_str("</details>  \n\n");
 
+        if !enum_ob.fields.is_empty() {
+            doc.push_str("## Constants\n\n");
+
+            for field in &enum_ob.fields {
+                if !field.args.is_empty() {
+                    doc.push_str(format!("- {}({})  \n", field.name, field.args).as_str());
+                } else {
+                    doc.push_str(format!("- {}  \n", field.name).as_str());
+                }
+            }
+            doc.push('\n');
+        }
+
         doc
     }
 
@@ -300,137 +503,659 @@ pub mod document {
     ///
     /// # Arguments
     ///
-    /// * `variables` - The vector of class methods to be documented
-    /// * `ignore` - Variables with this permission will be skipped, if not empty
-    pub fn gen_var_docs(variables: Vec<Member>, path: String, ignore: String) -> String {
-        let mut doc = String::new();
+    /// * `variables` - The vector of class member variables to be documented
+    /// * `path` - The source file path used for `[[src]]` links, if not empty
+    /// * `config` - The generation settings deciding which variables are documented
+    pub fn gen_var_docs(variables: &[Member], path: &str, config: &GenConfig) -> String {
+        section_to_markdown(&var_section(variables, path, config))
+    }
+
+    /// The modifiers of a java declaration in the order recommended by the Java
+    /// Language Specification
+    const MODIFIER_ORDER: [&str; 12] = [
+        "public",
+        "protected",
+        "private",
+        "abstract",
+        "default",
+        "static",
+        "final",
+        "transient",
+        "volatile",
+        "synchronized",
+        "native",
+        "strictfp",
+    ];
+
+    /// Orders the modifiers of a declaration the way java code conventionally writes
+    /// them, access first, then `static`, then `final`, and so on
+    ///
+    /// # Arguments
+    ///
+    /// * `modifiers` - The modifiers in the order they were declared
+    pub fn order_modifiers(modifiers: &[String]) -> Vec<String> {
+        let mut ordered = modifiers.to_vec();
+        ordered.sort_by_key(|m| MODIFIER_ORDER.iter().position(|o| o == m).unwrap_or(MODIFIER_ORDER.len()));
+
+        ordered
+    }
+
+    /// Removes the body opening and trailing `;` from a captured declaration line,
+    /// collapses the whitespace between its parts, and puts its modifiers in the
+    /// conventional order
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - The source line containing the declaration
+    pub fn clean_signature(signature: &str) -> String {
+        let declaration = signature.split("{").next().unwrap_or("");
+        let mut words: Vec<String> = declaration
+            .trim()
+            .trim_end_matches(";")
+            .split_whitespace()
+            .map(|w| w.to_string())
+            .collect();
+
+        // The modifiers follow the annotations of the declaration
+        let start = words.iter().take_while(|w| w.starts_with("@")).count();
+        let count = words[start..].iter().take_while(|w| MODIFIER_ORDER.contains(&w.as_str())).count();
+        let modifiers = order_modifiers(&words[start..start + count]);
+        words.splice(start..start + count, modifiers);
+
+        words.join(" ")
+    }
+
+    /// Gets the declaration of a method as shown in its signature block: the captured
+    /// source line, or a declaration rebuilt from the parsed parts, including its type
+    /// parameters, when no line was captured
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The method or constructor
+    pub fn method_signature(method: &Method) -> String {
+        if method.signature.trim() != "" {
+            return clean_signature(&method.signature);
+        }
+
+        let mut parts: Vec<String> = Vec::new();
+        if method.privacy != Access::Package {
+            parts.push(method.privacy.as_str().to_string());
+        }
+        parts.extend(order_modifiers(&method.modifiers));
+        if !method.type_params.is_empty() {
+            parts.push(format!("<{}>", method.type_params.join(", ")));
+        }
+        if !method.is_constructor && !method.return_type.is_empty() {
+            parts.push(method.return_type.clone());
+        }
+
+        let params: Vec<String> = method.parameters.iter().map(|p| format!("{} {}", p.var_type, p.name)).collect();
+        parts.push(format!("{}({})", method.name, params.join(", ")));
+
+        if !method.exceptions.is_empty() {
+            let names: Vec<&str> = method.exceptions.iter().map(|e| e.exception_type.as_str()).collect();
+            parts.push(format!("throws {}", names.join(", ")));
+        }
+
+        parts.join(" ")
+    }
+
+    /// Generates a markdown table of a method's parameters with their types and descriptions.
+    /// Returns an empty string when the method has no parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters of the method
+    pub fn gen_param_table(params: &[Param]) -> String {
+        params_to_markdown(params, &[])
+    }
+
+    /// Generates the markdown documentation for the constructors of a class
+    ///
+    /// # Arguments
+    ///
+    /// * `methods` - The vector of class methods containing the constructors
+    /// * `type_name` - The name of the class the constructors belong to
+    /// * `path` - The source file path used for `[[src]]` links, if not empty
+    /// * `config` - The generation settings deciding which constructors are documented
+    pub fn gen_constructor_docs(methods: &[Method], type_name: &str, path: &str, config: &GenConfig) -> String {
+        match constructor_section(methods, type_name, path, config) {
+            Some(section) => section_to_markdown(&section),
+            None => String::new(),
+        }
+    }
+
+    /// Generates the markdown documentation for the methods of a class
+    ///
+    /// # Arguments
+    ///
+    /// * `methods` - The vector of class methods to be documented
+    /// * `path` - The source file path used for `[[src]]` links, if not empty
+    /// * `config` - The generation settings deciding which methods are documented
+    pub fn gen_method_docs(methods: &[Method], path: &str, config: &GenConfig) -> String {
+        section_to_markdown(&method_section(methods, path, config))
+    }
+
+    /// Converts heading text into the anchor GitHub generates for it. The text is
+    /// lowercased, spaces become hyphens, and other punctuation is removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The rendered text of the heading
+    pub fn slugify(text: &str) -> String {
+        let mut slug = String::new();
+
+        for ch in text.trim().to_lowercase().chars() {
+            if ch.is_alphanumeric() || ch == '-' || ch == '_' {
+                slug.push(ch);
+            } else if ch == ' ' {
+                slug.push('-');
+            }
+        }
+
+        slug
+    }
 
-        if variables.len() > 0 {
-            doc.push_str("## Member Variables\n\n");
+    /// Gets the anchor for a member heading, accounting for the `[[src]]` link text
+    /// which is part of the heading when a source path is known
+    ///
+    /// # Arguments
+    ///
+    /// * `heading` - The heading text without the source link
+    /// * `line_num` - The line the member is declared on, part of the heading when line
+    ///   numbers are enabled
+    /// * `path` - The source file path used for `[[src]]` links, if not empty
+    /// * `config` - The generation settings
+    pub fn member_anchor(heading: &str, line_num: &str, path: &str, config: &GenConfig) -> String {
+        let heading = line_heading(heading, &entry_line(line_num, config));
+
+        if path.is_empty() {
+            config.prefix_anchor(slugify(&heading))
         } else {
-            doc.push_str("## No member variables in this class\n\n");
+            config.prefix_anchor(slugify(format!("{} [src]", heading).as_str()))
+        }
+    }
 
+    /// Generates a table of contents linking to the field, constructor, and method
+    /// headings of a type
+    ///
+    /// # Arguments
+    ///
+    /// * `type_name` - The name of the class, interface, or enum
+    /// * `variables` - The member variables of the type
+    /// * `methods` - The methods and constructors of the type
+    /// * `path` - The source file path used for `[[src]]` links, if not empty
+    /// * `config` - The generation settings deciding which members are documented
+    pub fn gen_toc(
+        type_name: &str,
+        variables: &[Member],
+        methods: &[Method],
+        path: &str,
+        config: &GenConfig,
+    ) -> String {
+        let mut doc = String::new();
+        let all_methods = methods;
+        let fields: Vec<&Member> = variables
+            .iter()
+            .filter(|v| config.documents_member(v.access, &v.annotations, ""))
+            .collect();
+        let fields = order_variables(fields, config);
+        let constructors: Vec<&Method> = methods
+            .iter()
+            .filter(|m| m.is_constructor && config.documents_member(m.privacy, &m.annotations, &m.since))
+            .collect();
+        let constructors = order_methods(constructors, config);
+        let methods: Vec<&Method> = methods
+            .iter()
+            .filter(|m| !m.is_constructor && !m.name.is_empty() && config.documents_member(m.privacy, &m.annotations, &m.since))
+            .collect();
+        let methods = order_methods(methods, config);
+
+        if fields.is_empty() && constructors.is_empty() && methods.is_empty() {
             return doc;
         }
 
-        for member in variables {
-            if member.access != ignore {
-                if path != "" {
-                    let mut file_path = path.clone();
-                    file_path.push_str(format!("#L{}", member.line_num).as_str());
-                    doc.push_str(
-                        format!(
-                            "#### {} {} [[src]]({})\n\n",
-                            member.var_type, member.name, file_path
-                        ).as_str(),
-                    );
-                    doc.push_str(format!(" > {}  \n\n", member.signature.trim()).as_str());
-                } else {
-                    doc.push_str(format!("#### {} {}\n\n", member.var_type, member.name).as_str());
-                }
+        doc.push_str("## Contents\n\n");
 
-                if member.desc != "" {
-                    doc.push_str(format!("+ Description: {}  \n", member.desc).as_str());
-                }
+        if !fields.is_empty() {
+            doc.push_str("- Member Variables\n");
 
-                if member.access == "" {
-                    doc.push_str("+ Access: package-private  \n");
-                } else {
-                    doc.push_str(format!("+ Access: {}  \n", member.access).as_str());
-                }
+            for member in fields {
+                let heading = format!("{} {}", member.var_type, member.name);
+                doc.push_str(format!("  - [{}](#{})\n", member.name, member_anchor(&heading, &member.line_num, path, config)).as_str());
+            }
+        }
 
-                if member.modifiers.len() > 0 {
-                    doc.push_str("+ Modifiers: ");
+        if !constructors.is_empty() {
+            doc.push_str("- Constructors\n");
 
-                    for mem in member.modifiers {
-                        doc.push_str(format!("{} ", mem).as_str())
-                    }
+            for member in constructors {
+                let heading = heading_text(type_name, member.is_deprecated());
+                let anchor = overload_anchor(member, type_name, all_methods, config)
+                    .unwrap_or_else(|| member_anchor(&heading, &member.line_num, path, config));
+                doc.push_str(format!("  - [{}](#{})\n", type_name, anchor).as_str());
+            }
+        }
 
-                    doc.push_str("\n");
-                }
+        if !methods.is_empty() {
+            doc.push_str("- Methods\n");
 
-                doc.push_str("\n");
+            for member in methods {
+                let heading = heading_text(&member.name, member.is_deprecated());
+                let anchor = overload_anchor(member, &member.name, all_methods, config)
+                    .unwrap_or_else(|| member_anchor(&heading, &member.line_num, path, config));
+                doc.push_str(format!("  - [{}](#{})\n", member.name, anchor).as_str());
             }
         }
 
+        doc.push('\n');
+
         doc
     }
 
-    /// Generates the markdown documentation for the methods of a class
+    /// Generates a summary table of the methods of a type linking to their
+    /// documentation, with the first sentence of each description. A `Since` column
+    /// is included when enabled in the config, left blank for methods without `@since`.
     ///
     /// # Arguments
     ///
-    /// * `methods` - The vector of class methods to be documented
-    /// * `ignore` - Methods with this permission will be skipped, if not empty
-    pub fn gen_method_docs(methods: Vec<Method>, path: String, ignore: String) -> String {
+    /// * `methods` - The methods and constructors of the type
+    /// * `path` - The source file path used for `[[src]]` links, if not empty
+    /// * `config` - The generation settings deciding which members are documented
+    pub fn gen_method_summary(methods: &[Method], path: &str, config: &GenConfig) -> String {
         let mut doc = String::new();
+        let documented: Vec<&Method> = methods
+            .iter()
+            .filter(|m| !m.is_constructor && !m.name.is_empty() && config.documents_member(m.privacy, &m.annotations, &m.since))
+            .collect();
+        let documented = order_methods(documented, config);
 
-        if methods.len() > 0 {
-            doc.push_str("## Methods\n\n");
+        if documented.is_empty() {
+            return doc;
+        }
+
+        doc.push_str("## Method Summary\n\n");
+        if config.since_column {
+            doc.push_str("| Method | Description | Since |  \n| ----- | ----- | ----- |  \n");
         } else {
-            doc.push_str("## No methods in this class\n\n");
+            doc.push_str("| Method | Description |  \n| ----- | ----- |  \n");
+        }
+
+        for member in documented {
+            let anchor = overload_anchor(member, &member.name, methods, config)
+                .unwrap_or_else(|| member_anchor(&heading_text(&member.name, member.is_deprecated()), &member.line_num, path, config));
+            let summary = match member.description.find(". ") {
+                Some(end) => &member.description[..end + 1],
+                None => member.description.as_str(),
+            };
+            let mut row = format!(
+                "| [{}](#{}) | {} |",
+                member.name,
+                anchor,
+                table_cell(&description_to_markdown(summary))
+            );
+
+            if config.since_column {
+                row.push_str(format!(" {} |", table_cell(&member.since)).as_str());
+            }
+            doc.push_str(format!("{}  \n", row).as_str());
+        }
+
+        doc.push('\n');
+
+        doc
+    }
 
+    /// Generates a summary of the deprecated constructors and methods of a type, linking
+    /// to their documentation. Returns an empty string when nothing is deprecated.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_name` - The name of the class, interface, or enum
+    /// * `methods` - The methods and constructors of the type
+    /// * `path` - The source file path used for `[[src]]` links, if not empty
+    /// * `config` - The generation settings deciding which members are documented
+    pub fn gen_deprecated_summary(type_name: &str, methods: &[Method], path: &str, config: &GenConfig) -> String {
+        let mut doc = String::new();
+        let deprecated: Vec<&Method> = methods
+            .iter()
+            .filter(|m| m.is_deprecated() && config.documents_member(m.privacy, &m.annotations, &m.since))
+            .filter(|m| !m.name.is_empty())
+            .collect();
+
+        if deprecated.is_empty() {
             return doc;
         }
 
-        for member in methods {
-            if member.privacy != ignore {
-                if member.name != String::from("") {
-                    if path != "" {
-                        let mut file_path = path.clone();
-                        file_path.push_str(format!("#L{}", member.line_num).as_str());
-                        doc.push_str(
-                            format!("### {} [[src]]({})\n\n", member.name, file_path).as_str(),
-                        );
-                    } else {
-                        doc.push_str(format!("### {}\n\n", member.name).as_str());
-                    }
+        doc.push_str("## Deprecated\n\n");
 
-                    doc.push_str(format!("+ Description: {}  \n", member.description).as_str());
+        for member in deprecated {
+            let name = if member.is_constructor { type_name } else { member.name.as_str() };
+            let anchor = overload_anchor(member, name, methods, config)
+                .unwrap_or_else(|| member_anchor(&heading_text(name, true), &member.line_num, path, config));
 
-                    if member.privacy == "" {
-                        doc.push_str("+ Access: package-private  \n");
-                    } else {
-                        doc.push_str(format!("+ Access: {}  \n", member.privacy).as_str());
-                    }
+            match member.deprecation {
+                Some(ref message) if !message.is_empty() => {
+                    doc.push_str(format!("- [{}](#{}): {}\n", name, anchor, message).as_str())
+                }
+                _ => doc.push_str(format!("- [{}](#{})\n", name, anchor).as_str()),
+            }
+        }
 
-                    if member.modifiers.len() > 0 {
-                        doc.push_str("+ Modifiers: ");
+        doc.push('\n');
 
-                        for mem in member.modifiers {
-                            doc.push_str(format!("{} ", mem).as_str())
-                        }
+        doc
+    }
 
-                        doc.push_str("\n");
-                    }
+    /// Generates the markdown documentation for the fields, constructors, and methods
+    /// of a type, in that order. Members keep the order they were declared in.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_name` - The name of the class, interface, or enum
+    /// * `variables` - The member variables of the type
+    /// * `methods` - The methods and constructors of the type
+    /// * `path` - The source file path used for `[[src]]` links, if not empty
+    /// * `config` - The generation settings deciding which members are documented
+    pub fn gen_member_docs(
+        type_name: &str,
+        variables: &[Member],
+        methods: &[Method],
+        path: &str,
+        config: &GenConfig,
+    ) -> String {
+        let mut doc = String::new();
 
-                    for exception in member.exceptions {
-                        doc.push_str(
-                            format!(
-                                "+ Throws {}: {}  \n",
-                                exception.exception_type, exception.desc
-                            ).as_str(),
-                        );
-                    }
-                    doc.push_str(format!("+ return: {}  \n\n", member.return_type).as_str());
+        doc.push_str(gen_var_docs(variables, path, config).as_str());
+        doc.push_str(gen_constructor_docs(methods, type_name, path, config).as_str());
+        doc.push_str(gen_method_docs(methods, path, config).as_str());
 
-                    if member.parameters.len() > 0 {
-                        doc.push_str("| Name | Type | Description |  \n");
-                        doc.push_str("| ----- | ----- | ----- |  \n");
-                    } else {
-                        doc.push_str("This method has no parameters.  \n");
-                    }
+        doc
+    }
 
-                    for param in member.parameters {
-                        doc.push_str(
-                            format!(
-                                "| {} | {} | {} |  \n",
-                                param.name, param.var_type, param.desc
-                            ).as_str(),
-                        );
-                    }
+    /// Shifts every markdown heading of a document down by a number of levels so the
+    /// document can be embedded in a larger one. Headings are clamped at `######` and
+    /// lines inside fenced code blocks are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `doc` - The markdown document
+    /// * `heading_offset` - The number of levels to shift the headings by
+    pub fn shift_headings(doc: &str, heading_offset: usize) -> String {
+        if heading_offset == 0 {
+            return doc.to_string();
+        }
+
+        let mut shifted = String::new();
+        let mut in_code = false;
+
+        for line in doc.split_terminator("\n") {
+            let level = line.chars().take_while(|ch| *ch == '#').count();
+
+            if line.starts_with("```") {
+                in_code = !in_code;
+            }
 
-                    doc.push_str("\n\n");
+            if !in_code && level > 0 && line[level..].starts_with(" ") {
+                let new_level = cmp::min(level + heading_offset, 6);
+                shifted.push_str("#".repeat(new_level).as_str());
+                shifted.push_str(&line[level..]);
+            } else {
+                shifted.push_str(line);
+            }
+            shifted.push('\n');
+        }
+
+        shifted
+    }
+
+    /// Cleans up the spacing of a generated markdown document. Runs of blank lines are
+    /// collapsed to a single blank line and trailing whitespace is trimmed, except for
+    /// the two spaces of a hard line break. Lines inside fenced code blocks are left
+    /// unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `doc` - The markdown document
+    pub fn normalize_markdown(doc: &str) -> String {
+        let mut normalized = String::new();
+        let mut in_code = false;
+        let mut blank = false;
+
+        for line in doc.split_terminator("\n") {
+            if line.trim_start().starts_with("```") {
+                in_code = !in_code;
+            } else if in_code {
+                normalized.push_str(line);
+                normalized.push('\n');
+                continue;
+            }
+
+            let trimmed = line.trim_end();
+            if trimmed.is_empty() {
+                if !blank {
+                    normalized.push('\n');
+                }
+                blank = true;
+                continue;
+            }
+
+            normalized.push_str(trimmed);
+            if line.ends_with("  ") {
+                normalized.push_str("  ");
+            }
+            normalized.push('\n');
+            blank = false;
+        }
+
+        normalized
+    }
+
+    /// Struct borrowing the parts of a class, interface, or enum its markdown document
+    /// is generated from
+    struct TypeParts<'a> {
+        name: &'a str,
+        variables: &'a [Member],
+        methods: &'a [Method],
+        path: &'a str,
+        package_name: &'a str,
+        deprecated: bool,
+        license: &'a str,
+    }
+
+    impl<'a> TypeParts<'a> {
+        /// The parts of a parsed class
+        fn of_class(class: &'a Class) -> TypeParts<'a> {
+            TypeParts {
+                name: &class.name,
+                variables: &class.variables,
+                methods: &class.methods,
+                path: &class.file_path,
+                package_name: &class.package_name,
+                deprecated: class.deprecation.is_some(),
+                license: &class.license,
+            }
+        }
+
+        /// The parts of a parsed interface, which has no license header
+        fn of_interface(inter: &'a Interface) -> TypeParts<'a> {
+            TypeParts {
+                name: &inter.name,
+                variables: &inter.variables,
+                methods: &inter.methods,
+                path: &inter.file_path,
+                package_name: &inter.package_name,
+                deprecated: inter.deprecation.is_some(),
+                license: "",
+            }
+        }
+
+        /// The parts of a parsed enum
+        fn of_enum(enum_ob: &'a Enumeration) -> TypeParts<'a> {
+            TypeParts {
+                name: &enum_ob.name,
+                variables: &enum_ob.variables,
+                methods: &enum_ob.methods,
+                path: &enum_ob.file_path,
+                package_name: &enum_ob.package_name,
+                deprecated: enum_ob.deprecation.is_some(),
+                license: &enum_ob.license,
+            }
+        }
+    }
+
+    /// Generates the markdown document for a type from its already rendered header.
+    /// The deprecated summary, the table of contents, the method summary, and the member
+    /// sections follow the header as configured, the headings are shifted by the
+    /// heading offset, and the spacing is normalized.
+    ///
+    /// # Arguments
+    ///
+    /// * `header` - The rendered heading, description, and details of the type
+    /// * `parts` - The members, source path, package, and license of the type
+    /// * `config` - The generation settings
+    fn gen_type_markdown(header: String, parts: &TypeParts, config: &GenConfig) -> String {
+        let mut doc = header;
+
+        doc.push_str(gen_deprecated_summary(parts.name, parts.methods, parts.path, config).as_str());
+
+        if config.toc {
+            doc.push_str(gen_toc(parts.name, parts.variables, parts.methods, parts.path, config).as_str());
+        }
+
+        if config.method_summary {
+            doc.push_str(gen_method_summary(parts.methods, parts.path, config).as_str());
+        }
+
+        doc.push_str(gen_member_docs(parts.name, parts.variables, parts.methods, parts.path, config).as_str());
+
+        finish_type_markdown(doc, parts.name, parts.package_name, parts.deprecated, parts.license, config)
+    }
+
+    /// Finishes the markdown document of a type: adds the license footer, shifts the
+    /// headings, and adds the front matter when enabled
+    ///
+    /// # Arguments
+    ///
+    /// * `doc` - The markdown of the type and its members
+    /// * `type_name` - The name of the type
+    /// * `package_name` - The package of the type
+    /// * `deprecated` - Whether the type is deprecated
+    /// * `license` - The license header of the java file
+    /// * `config` - The generation settings
+    fn finish_type_markdown(
+        mut doc: String,
+        type_name: &str,
+        package_name: &str,
+        deprecated: bool,
+        license: &str,
+        config: &GenConfig,
+    ) -> String {
+        doc.push_str(gen_license_footer(license, config).as_str());
+
+        let doc = normalize_markdown(&shift_headings(doc.as_str(), config.heading_offset));
+        if config.frontmatter {
+            format!("{}{}", gen_front_matter(type_name, package_name, deprecated), doc)
+        } else {
+            doc
+        }
+    }
+
+    /// Generates the YAML front matter block read by static site generators such as
+    /// Hugo and Jekyll
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title of the page
+    /// * `package_name` - The package of the documented type
+    /// * `deprecated` - Whether the documented type is deprecated
+    pub fn gen_front_matter(title: &str, package_name: &str, deprecated: bool) -> String {
+        let mut doc = String::from("---\n");
+
+        doc.push_str(format!("title: {}\n", yaml_value(title)).as_str());
+        doc.push_str(format!("package: {}\n", yaml_value(package_name.trim())).as_str());
+        doc.push_str(format!("deprecated: {}\n", deprecated).as_str());
+        doc.push_str("---\n\n");
+
+        doc
+    }
+
+    /// Quotes a YAML scalar when it is empty, has surrounding whitespace, contains a
+    /// character with a meaning in YAML, or would be read as a boolean, null, or number
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value of the front matter key
+    fn yaml_value(value: &str) -> String {
+        let special = value.chars().any(|ch| ":#{}[],&*!|>'\"%@`\\".contains(ch));
+        let reserved = match value.to_lowercase().as_str() {
+            "true" | "false" | "yes" | "no" | "on" | "off" | "null" | "~" => true,
+            _ => value.parse::<f64>().is_ok(),
+        };
+
+        if value.is_empty() || value.trim() != value || special || reserved {
+            format!("\"{}\"", value.replace("\\", "\\\\").replace("\"", "\\\""))
+        } else {
+            value.to_string()
+        }
+    }
+
+    /// Generates the markdown document for a parsed class, interface, or enum using
+    /// the given settings. The output format of the config is not used.
+    ///
+    /// # Arguments
+    ///
+    /// * `obj` - The parsed java type
+    /// * `config` - The generation settings
+    pub fn to_markdown_with_config(obj: &ObjectType, config: &GenConfig) -> String {
+        match obj {
+            ObjectType::Class(class) => {
+                gen_type_markdown(gen_class_docs(class, config), &TypeParts::of_class(class), config)
+            }
+            ObjectType::Interface(inter) => gen_type_markdown(
+                gen_interface_docs(inter, config),
+                &TypeParts::of_interface(inter),
+                &config.for_interface(),
+            ),
+            ObjectType::Enumeration(enum_ob) => {
+                gen_type_markdown(gen_enum_docs(enum_ob, config), &TypeParts::of_enum(enum_ob), config)
+            }
+            ObjectType::Annotation(annotation) => finish_type_markdown(
+                gen_annotation_docs(annotation, config),
+                &annotation.name,
+                &annotation.package_name,
+                annotation.deprecation.is_some(),
+                "",
+                config,
+            ),
+        }
+    }
+
+    /// Generates the markdown page of a parsed type. When nested types are documented
+    /// inline, the types nested in it follow its members as subsections, one heading
+    /// level deeper.
+    ///
+    /// # Arguments
+    ///
+    /// * `app` - The parsed application containing the nested types
+    /// * `obj` - The parsed java type
+    /// * `config` - The generation settings
+    pub fn gen_type_page(app: &ApplicationDoc, obj: &ObjectType, config: &GenConfig) -> String {
+        let mut doc = to_markdown_with_config(obj, config);
+
+        if config.nested_types == NestedTypes::Inline {
+            let (package_name, name) = obj.package_and_name();
+            let nested_config = config.clone().heading_offset(config.heading_offset + 1).with_frontmatter(false);
+
+            for object in &app.objects {
+                let (object_package, object_name) = object.package_and_name();
+                let directly_nested = object_name.len() > name.len() + 1
+                    && object_name.starts_with(name)
+                    && object_name[name.len()..].starts_with(".")
+                    && !object_name[name.len() + 1..].contains(".");
+
+                if object_package == package_name && directly_nested {
+                    doc.push_str(gen_type_page(app, object, &nested_config).as_str());
                 }
             }
         }
@@ -438,6 +1163,53 @@ pub mod document {
         doc
     }
 
+    /// Renders a parsed class, interface, or enum in the output format of the config
+    ///
+    /// # Arguments
+    ///
+    /// * `obj` - The parsed java type
+    /// * `config` - The generation settings
+    pub fn render_object(obj: &ObjectType, config: &GenConfig) -> String {
+        match config.format {
+            OutputFormat::Markdown => to_markdown_with_config(obj, config),
+            OutputFormat::Html => render_with(&mut HtmlRenderer, obj, config),
+            OutputFormat::Json => serde_json::to_string_pretty(obj).expect("Unable to serialize the type"),
+        }
+    }
+
+    /// Generates the markdown document for a parsed type with every heading shifted
+    /// down by `heading_offset` levels
+    ///
+    /// # Arguments
+    ///
+    /// * `obj` - The parsed java type
+    /// * `heading_offset` - The number of levels to shift the headings by
+    pub fn to_markdown_with_offset(obj: &ObjectType, heading_offset: usize) -> String {
+        to_markdown_with_config(obj, &GenConfig::new().heading_offset(heading_offset))
+    }
+
+    /// Generates the markdown document for a parsed class, interface, or enum.
+    ///
+    /// The document starts with the type heading, description, and author/version
+    /// information, followed by the fields, constructors, and methods sections.
+    ///
+    /// # Arguments
+    ///
+    /// * `obj` - The parsed java type
+    pub fn to_markdown(obj: &ObjectType) -> String {
+        to_markdown_with_config(obj, &GenConfig::new())
+    }
+
+    /// Generates a self contained HTML fragment for a parsed class, interface, or enum.
+    /// Uses the same rendering tree as the markdown member sections.
+    ///
+    /// # Arguments
+    ///
+    /// * `obj` - The parsed java type
+    pub fn to_html(obj: &ObjectType) -> String {
+        render_object(obj, &GenConfig::new().format(OutputFormat::Html))
+    }
+
     /// Generates the markdown documentation for the methods of a class
     ///
     /// # Arguments
@@ -455,7 +1227,290 @@ pub mod document {
             doc.push_str("\n\n");
         }
 
-        doc
+        normalize_markdown(&doc)
+    }
+
+    /// Generates the markdown index of the application listing every package, with
+    /// its `package-info.java` description, and links to the documentation of its
+    /// types. Packages are sorted alphabetically, their members alphabetically or by
+    /// `@version` when the config sorts by version.
+    ///
+    /// # Arguments
+    ///
+    /// * `app` - The parsed application
+    /// * `config` - The generation settings
+    pub fn gen_package_index(app: &ApplicationDoc, config: &GenConfig) -> String {
+        let mut doc = if !config.project_title.is_empty() {
+            format!("# {}\n\n", config.project_title)
+        } else {
+            String::from("# Package Index\n\n")
+        };
+        doc.push_str(format!("+ Files: {}  \n", app.file_num).as_str());
+        doc.push_str(format!("+ Classes: {}  \n", app.class_num).as_str());
+        doc.push_str(format!("+ Interfaces: {}  \n", app.interface_num).as_str());
+        doc.push_str(format!("+ Enums: {}  \n\n", app.enum_num).as_str());
+
+        let mut packages = app.packages.clone();
+        packages.sort_by(|a, b| a.name.cmp(&b.name));
+
+        for mut package in packages {
+            package.members.sort();
+            if config.sort_by_version {
+                let name = package.name.clone();
+                package.members.sort_by(|a, b| {
+                    compare_versions(&type_version(app, &name, a), &type_version(app, &name, b))
+                });
+            }
+
+            if !package.description.is_empty() {
+                let description = package.description.split_whitespace().collect::<Vec<&str>>().join(" ");
+                doc.push_str(format!("- {}: {}\n", package.name, description).as_str());
+            } else {
+                doc.push_str(format!("- {}\n", package.name).as_str());
+            }
+            for member in package.members {
+                if config.nested_types == NestedTypes::Inline && member.contains(".") {
+                    continue;
+                }
+                doc.push_str(format!("    - [{}]({})\n", member, page_path(&package.package_path, &member)).as_str());
+            }
+        }
+
+        normalize_markdown(&doc)
+    }
+
+    /// Gets the `@version` of a parsed type, empty when the type has none
+    ///
+    /// # Arguments
+    ///
+    /// * `app` - The parsed application
+    /// * `package_name` - The package of the type
+    /// * `name` - The name of the type
+    fn type_version(app: &ApplicationDoc, package_name: &str, name: &str) -> String {
+        for object in &app.objects {
+            let (package, type_name, version) = match object {
+                ObjectType::Class(class) => (&class.package_name, &class.name, &class.version),
+                ObjectType::Interface(inter) => (&inter.package_name, &inter.name, &inter.version),
+                ObjectType::Enumeration(enum_ob) => (&enum_ob.package_name, &enum_ob.name, &enum_ob.version),
+                ObjectType::Annotation(annotation) => (&annotation.package_name, &annotation.name, &annotation.version),
+            };
+
+            if package == package_name && type_name == name {
+                return version.trim().to_string();
+            }
+        }
+
+        String::new()
+    }
+
+    /// Gets the numeric parts of a version such as `v2.1.0-beta`, `None` when the
+    /// version doesn't start with a number
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The version string
+    fn version_parts(version: &str) -> Option<Vec<u64>> {
+        let core = version.trim_start_matches(|ch| ch == 'v' || ch == 'V');
+        let core = core.split(|ch| ch == '-' || ch == '+' || ch == ' ').next().unwrap_or("");
+        let mut parts = Vec::new();
+
+        for part in core.split(".") {
+            let digits: String = part.chars().take_while(|ch| ch.is_digit(10)).collect();
+
+            match digits.parse::<u64>() {
+                Ok(number) => parts.push(number),
+                Err(_) => break,
+            }
+        }
+
+        if !parts.is_empty() {
+            Some(parts)
+        } else {
+            None
+        }
+    }
+
+    /// Compares two versions leniently following semver. Missing parts count as 0
+    /// and versions that can't be parsed sort lexicographically after the others.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first version
+    /// * `b` - The second version
+    pub fn compare_versions(a: &str, b: &str) -> Ordering {
+        match (version_parts(a), version_parts(b)) {
+            (Some(a_parts), Some(b_parts)) => {
+                for i in 0..cmp::max(a_parts.len(), b_parts.len()) {
+                    let a_part = a_parts.get(i).unwrap_or(&0);
+                    let b_part = b_parts.get(i).unwrap_or(&0);
+
+                    if a_part != b_part {
+                        return a_part.cmp(b_part);
+                    }
+                }
+
+                Ordering::Equal
+            }
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => a.cmp(b),
+        }
+    }
+
+    /// Serializes the parsed application model to pretty printed JSON. The JSON
+    /// contains the package index and every parsed type with its members.
+    ///
+    /// # Arguments
+    ///
+    /// * `doc` - The parsed application
+    pub fn to_json(doc: &ApplicationDoc) -> String {
+        serde_json::to_string_pretty(doc).expect("Unable to serialize the application")
+    }
+
+    /// Writes a file unless it already exists with identical contents
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file to write
+    /// * `contents` - The new contents of the file
+    fn write_if_changed(path: &Path, contents: &str) {
+        if let Ok(existing) = fs::read_to_string(path) {
+            if existing == contents {
+                return;
+            }
+        }
+
+        let mut file = File::create(path).expect("Unable to create file for the documentation");
+        file.write_all(contents.as_bytes())
+            .expect("Not able to write to file");
+    }
+
+    /// Writes one markdown file per parsed type to `<out>/<package path>/<name>.md`,
+    /// mirroring the package structure of the application. References to other
+    /// parsed types link to their pages. Files whose contents have not changed are
+    /// left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `app` - The parsed application
+    /// * `out` - The directory the markdown tree is written to
+    /// * `config` - The generation settings
+    pub fn write_markdown_tree(app: &ApplicationDoc, out: &Path, config: &GenConfig) {
+        let inline = config.nested_types == NestedTypes::Inline;
+        let mut links = TypeLinks::from_app(app);
+        if inline {
+            links.inline_nested();
+        }
+
+        for object in &app.objects {
+            let (package_name, name) = match object {
+                ObjectType::Class(class) => (&class.package_name, &class.name),
+                ObjectType::Interface(inter) => (&inter.package_name, &inter.name),
+                ObjectType::Enumeration(enum_ob) => (&enum_ob.package_name, &enum_ob.name),
+                ObjectType::Annotation(annotation) => (&annotation.package_name, &annotation.name),
+            };
+            // Inline nested types are part of the page of their top-level type
+            if inline && name.contains(".") {
+                continue;
+            }
+
+            let package_path = match app.packages.iter().find(|p| &p.name == package_name) {
+                Some(package) => package.package_path.clone(),
+                None => package_name.replace(".", "/"),
+            };
+
+            let page_config = config.clone().with_links(links.for_page(&page_path(&package_path, name)));
+            let dir = out.join(package_path);
+            fs::create_dir_all(&dir).expect("File path not able to be created");
+            write_if_changed(
+                &dir.join(format!("{}.md", name)),
+                gen_type_page(app, object, &page_config).as_str(),
+            );
+        }
+
+        fs::create_dir_all(out).expect("File path not able to be created");
+        write_if_changed(&out.join("index.md"), gen_package_index(app, config).as_str());
+    }
+
+    /// Generates one markdown document for the whole application. The types are
+    /// grouped by package, both sorted alphabetically, after a table of contents
+    /// linking to each type. The anchor of a type is its qualified name, so two types
+    /// with the same name in different packages get different anchors. The anchors of
+    /// their members are prefixed with the anchor of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `app` - The parsed application
+    /// * `config` - The generation settings, whose headings are shifted down two levels
+    pub fn gen_single_file(app: &ApplicationDoc, config: &GenConfig) -> String {
+        let mut packages: BTreeMap<String, Vec<(String, &ObjectType)>> = BTreeMap::new();
+
+        for object in &app.objects {
+            let (package_name, name) = match object {
+                ObjectType::Class(class) => (&class.package_name, &class.name),
+                ObjectType::Interface(inter) => (&inter.package_name, &inter.name),
+                ObjectType::Enumeration(enum_ob) => (&enum_ob.package_name, &enum_ob.name),
+                ObjectType::Annotation(annotation) => (&annotation.package_name, &annotation.name),
+            };
+
+            packages
+                .entry(package_name.trim().to_string())
+                .or_insert(Vec::new())
+                .push((name.clone(), object));
+        }
+
+        let mut anchors: HashSet<String> = HashSet::new();
+        let mut toc = String::from("# API Documentation\n\n");
+        let mut body = String::new();
+        let config = config.clone().heading_offset(config.heading_offset + 2);
+
+        for (package_name, types) in packages.iter_mut() {
+            types.sort_by(|a, b| a.0.cmp(&b.0));
+            let title = if package_name.is_empty() { "Default package" } else { package_name.as_str() };
+
+            toc.push_str(format!("- {}\n", title).as_str());
+            body.push_str(format!("## {}\n\n", title).as_str());
+
+            for &(ref name, object) in types.iter() {
+                let qualified = if package_name.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{}.{}", package_name, name)
+                };
+                let base = slugify(&qualified.replace(".", " "));
+                let mut anchor = base.clone();
+                let mut count = 1;
+
+                // Qualified names can still slugify the same, e.g. `a.b_c` and `a_b.c`
+                while anchors.contains(&anchor) {
+                    anchor = format!("{}-{}", base, count);
+                    count += 1;
+                }
+                anchors.insert(anchor.clone());
+
+                toc.push_str(format!("    - [{}](#{})\n", name, anchor).as_str());
+                body.push_str(format!("<a id=\"{}\"></a>\n\n", anchor).as_str());
+                body.push_str(to_markdown_with_config(object, &config.clone().anchor_prefix(&anchor)).as_str());
+            }
+        }
+
+        toc.push('\n');
+        normalize_markdown(&format!("{}{}", toc, body))
+    }
+
+    /// Writes the documentation of the whole application to a single markdown file,
+    /// e.g. `API.md`, creating its directory when needed
+    ///
+    /// # Arguments
+    ///
+    /// * `app` - The parsed application
+    /// * `out` - The path of the markdown file
+    /// * `config` - The generation settings
+    pub fn write_single_file(app: &ApplicationDoc, out: &Path, config: &GenConfig) {
+        if let Some(dir) = out.parent() {
+            fs::create_dir_all(dir).expect("File path not able to be created");
+        }
+        write_if_changed(out, gen_single_file(app, config).as_str());
     }
 
     macro_rules! remove_old_md {
@@ -477,7 +1532,8 @@ pub mod document {
     /// * `dest` - The file path where the markdown file will be saved
     /// * `ignore` - Permission to ignore when parsing member variables and methods
     /// * `context` - The project context e.g. `github.com/user/repo`
-    pub fn generate_markdown(proj: Project, options: Options) {
+    /// * `config` - The generation settings
+    pub fn generate_markdown(proj: Project, options: Options, config: &GenConfig) {
         println!("{}", options.dest);
         let mut app_doc = ApplicationDoc::new();
 
@@ -486,14 +1542,24 @@ pub mod document {
             remove_old_md!(dest);
         }
 
-        for mut class in proj.classes {
-            let mut doc = gen_class_docs(class.clone());
-
-            doc.push_str(
-                gen_var_docs(class.variables, class.file_path.clone(), options.ignore.clone()).as_str(),
-            );
-            doc.push_str(gen_method_docs(class.methods, class.file_path, options.ignore.clone()).as_str());
+        let mut links = TypeLinks::new();
+        for class in &proj.classes {
+            links.add_page(&class.name, page_path(&class.package_name.replace(".", "/"), &class.name));
+        }
+        for inter in &proj.interfaces {
+            links.add_page(&inter.name, page_path(&inter.package_name.replace(".", "/"), &inter.name));
+        }
+        for enumeration in &proj.enumerations {
+            links.add_page(&enumeration.name, page_path(&enumeration.package_name.replace(".", "/"), &enumeration.name));
+        }
+        for annotation in &proj.annotations {
+            links.add_page(&annotation.name, page_path(&annotation.package_name.replace(".", "/"), &annotation.name));
+        }
 
+        for class in proj.classes {
+            let page_config =
+                config.clone().with_links(links.for_page(&page_path(&class.package_name.replace(".", "/"), &class.name)));
+            let doc = gen_type_markdown(gen_class_docs(&class, &page_config), &TypeParts::of_class(&class), &page_config);
             let dir = format!("{}/{}", options.dest, class.package_name.replace(".", "/").clone());
             fs::create_dir_all(dir.clone()).expect("File path not able to be created");
             let mut file = File::create(format!("{}/{}.{}", dir, class.name, "md"))
@@ -516,14 +1582,15 @@ pub mod document {
             println!("{}.{} was created", class.name, "md");
         }
 
-        for mut inter in proj.interfaces {
-            let mut doc = gen_interface_docs(inter.clone());
-
-            doc.push_str(
-                gen_var_docs(inter.variables, inter.file_path.clone(), options.ignore.clone()).as_str(),
+        for inter in proj.interfaces {
+            let page_config = config
+                .for_interface()
+                .with_links(links.for_page(&page_path(&inter.package_name.replace(".", "/"), &inter.name)));
+            let doc = gen_type_markdown(
+                gen_interface_docs(&inter, &page_config),
+                &TypeParts::of_interface(&inter),
+                &page_config,
             );
-            doc.push_str(gen_method_docs(inter.methods, inter.file_path, options.ignore.clone()).as_str());
-
             let dir = format!("{}/{}", options.dest, inter.package_name.replace(".", "/").clone());
             fs::create_dir_all(dir.clone()).expect("File path not able to be created");
             let mut file = File::create(format!("{}/{}.{}", dir, inter.name, "md"))
@@ -536,21 +1603,15 @@ pub mod document {
             println!("{}.{} was created", inter.name, "md");
         }
 
-        for mut enumeration in proj.enumerations {
-            let mut doc = gen_enum_docs(enumeration.clone());
-
-            doc.push_str(
-                gen_var_docs(
-                    enumeration.variables,
-                    enumeration.file_path.clone(),
-                    options.ignore.clone(),
-                ).as_str(),
+        for enumeration in proj.enumerations {
+            let page_config = config
+                .clone()
+                .with_links(links.for_page(&page_path(&enumeration.package_name.replace(".", "/"), &enumeration.name)));
+            let doc = gen_type_markdown(
+                gen_enum_docs(&enumeration, &page_config),
+                &TypeParts::of_enum(&enumeration),
+                &page_config,
             );
-            doc.push_str(
-                gen_method_docs(enumeration.methods, enumeration.file_path, options.ignore.clone())
-                    .as_str(),
-            );
-
             let dir = format!(
                 "{}/{}",
                 options.dest,
@@ -567,6 +1628,27 @@ pub mod document {
             println!("{}.{} was created", enumeration.name, "md");
         }
 
+        for annotation in proj.annotations {
+            let page_config = config
+                .clone()
+                .with_links(links.for_page(&page_path(&annotation.package_name.replace(".", "/"), &annotation.name)));
+            let doc = to_markdown_with_config(&ObjectType::Annotation(annotation.clone()), &page_config);
+            let dir = format!(
+                "{}/{}",
+                options.dest,
+                annotation.package_name.replace(".", "/").clone()
+            );
+            fs::create_dir_all(dir.clone()).expect("File path not able to be created");
+            let mut file = File::create(format!("{}/{}.{}", dir, annotation.name, "md"))
+                .expect("Not able to create annotation file");
+            file.write_all(doc.as_str().as_bytes())
+                .expect("Not able to write to file");
+
+            app_doc.add_package_class(annotation.package_name, dir, annotation.name.clone());
+
+            println!("{}.{} was created", annotation.name, "md");
+        }
+
         let mut app_file = File::create(format!("{}/Contents.md", options.dest))
             .expect("Unable to create file for application contents");
         app_file
@@ -574,47 +1656,128 @@ pub mod document {
             .expect("Not able to write to file");
     }
 
-    pub fn lint_method(method: &Method) -> String {
-        let mut method_errs = String::new();
+    /// Lints the javadoc of a java type and its methods. Reports public types
+    /// without a description, public methods without a description, parameters
+    /// without `@param`, and non void methods without `@return`.
+    ///
+    /// # Arguments
+    ///
+    /// * `obj` - The parsed java type to lint
+    pub fn lint_object(obj: &ObjectType) -> Vec<LintWarning> {
+        match obj {
+            ObjectType::Class(class) => {
+                lint_type(&class.name, &class.line_num, &class.access, &class.description, &class.methods, false)
+            }
+            ObjectType::Interface(inter) => {
+                lint_type(&inter.name, &inter.line_num, &inter.access, &inter.description, &inter.methods, true)
+            }
+            ObjectType::Enumeration(enum_ob) => lint_type(
+                &enum_ob.name,
+                &enum_ob.line_num,
+                &enum_ob.access,
+                &enum_ob.description,
+                &enum_ob.methods,
+                false,
+            ),
+            ObjectType::Annotation(annotation) => lint_type(
+                &annotation.name,
+                &annotation.line_num,
+                &annotation.access,
+                &annotation.description,
+                &Vec::new(),
+                true,
+            ),
+        }
+    }
 
-        if method.description == "" {
-            method_errs.push_str(
-                "\tMissing description for method "
-                    .yellow()
-                    .to_string()
-                    .as_str(),
-            );
-            method_errs.push_str(format!("{} (Line: {})\n", method.name, method.line_num).as_str());
+    /// Lints a java type's description and methods
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the type
+    /// * `line_num` - The line the type is declared on
+    /// * `access` - The access modifier of the type
+    /// * `description` - The javadoc description of the type
+    /// * `methods` - The methods declared in the type
+    /// * `implicit_public` - Whether methods without an access modifier are public
+    fn lint_type(
+        name: &str,
+        line_num: &str,
+        access: &str,
+        description: &str,
+        methods: &Vec<Method>,
+        implicit_public: bool,
+    ) -> Vec<LintWarning> {
+        let mut warnings = Vec::new();
+
+        if access == "public" && description.is_empty() {
+            warnings.push(LintWarning::new(
+                LintKind::MissingClassDescription,
+                name.to_string(),
+                line_num.to_string(),
+            ));
         }
-        if method.return_type == "" {
-            method_errs.push_str(
-                "\tMissing return type for method "
-                    .yellow()
-                    .to_string()
-                    .as_str(),
-            );
-            method_errs.push_str(format!("{} (Line: {})\n", method.name, method.line_num).as_str());
+
+        for method in methods {
+            let public = method.privacy == Access::Public || (implicit_public && method.privacy == Access::Package);
+            let method_name = method.name.clone();
+
+            if public && method.description.is_empty() {
+                warnings.push(LintWarning::new(
+                    LintKind::MissingMethodDescription,
+                    method_name.clone(),
+                    method.line_num.clone(),
+                ));
+            }
+            if public {
+                for p in &method.parameters {
+                    if p.desc.is_empty() {
+                        warnings.push(LintWarning::new(
+                            LintKind::MissingParam,
+                            format!("{} in method: {}", p.name, method_name),
+                            method.line_num.clone(),
+                        ));
+                    }
+                }
+            }
+            for name in &method.unknown_params {
+                warnings.push(LintWarning::new(
+                    LintKind::UnknownParam,
+                    format!("{} in method: {}", name, method_name),
+                    method.line_num.clone(),
+                ));
+            }
+            if !method.is_constructor && method.return_type != "void" && method.return_desc.is_empty() {
+                warnings.push(LintWarning::new(
+                    LintKind::MissingReturn,
+                    method_name.clone(),
+                    method.line_num.clone(),
+                ));
+            }
         }
 
-        for p in method.parameters.clone() {
-            if p.desc == "" {
-                method_errs.push_str(
-                    "\tJavadoc parameter not found "
-                        .yellow()
-                        .to_string()
-                        .as_str(),
-                );
-                method_errs.push_str(
-                    format!(
-                        "{} in method: {} (Line: {})\n",
-                        p.name, method.name, method.line_num
-                    ).as_str(),
-                );
+        warnings
+    }
+
+    /// Formats lint warnings in the colored format used by the lint output
+    ///
+    /// # Arguments
+    ///
+    /// * `warnings` - The warnings to format
+    fn format_warnings(warnings: &Vec<LintWarning>) -> String {
+        let mut errs = String::new();
+
+        for warning in warnings {
+            errs.push_str(format!("\t{}", warning.message()).yellow().to_string().as_str());
+            if !warning.line_num.is_empty() {
+                errs.push_str(format!(" (Line: {})", warning.line_num).as_str());
             }
+            errs.push('\n');
         }
 
-        method_errs
+        errs
     }
+
     pub fn lint_var(var: &Member) -> String {
         let mut errs = String::new();
 
@@ -651,12 +1814,19 @@ pub mod document {
         for class in proj.classes {
             let mut temp_err = String::new();
 
-            for v in class.variables {
-                temp_err.push_str(lint_var(&v).as_str());
-            }
-            for m in class.methods {
-                temp_err.push_str(lint_method(&m).as_str());
+            for v in &class.variables {
+                temp_err.push_str(lint_var(v).as_str());
             }
+            temp_err.push_str(
+                format_warnings(&lint_type(
+                    &class.name,
+                    &class.line_num,
+                    &class.access,
+                    &class.description,
+                    &class.methods,
+                    false,
+                )).as_str(),
+            );
 
             if temp_err != "" {
                 jdoc_errs.push_str(
@@ -680,12 +1850,19 @@ pub mod document {
         for mut inter in proj.interfaces {
             let mut temp_err = String::new();
 
-            for v in inter.variables {
-                temp_err.push_str(lint_var(&v).as_str());
-            }
-            for m in inter.methods {
-                temp_err.push_str(lint_method(&m).as_str());
+            for v in &inter.variables {
+                temp_err.push_str(lint_var(v).as_str());
             }
+            temp_err.push_str(
+                format_warnings(&lint_type(
+                    &inter.name,
+                    &inter.line_num,
+                    &inter.access,
+                    &inter.description,
+                    &inter.methods,
+                    true,
+                )).as_str(),
+            );
 
             if temp_err != "" {
                 jdoc_errs.push_str(
@@ -709,12 +1886,19 @@ pub mod document {
         for mut enum_ob in proj.enumerations {
             let mut temp_err = String::new();
 
-            for v in enum_ob.variables {
-                temp_err.push_str(lint_var(&v).as_str());
-            }
-            for m in enum_ob.methods {
-                temp_err.push_str(lint_method(&m).as_str());
+            for v in &enum_ob.variables {
+                temp_err.push_str(lint_var(v).as_str());
             }
+            temp_err.push_str(
+                format_warnings(&lint_type(
+                    &enum_ob.name,
+                    &enum_ob.line_num,
+                    &enum_ob.access,
+                    &enum_ob.description,
+                    &enum_ob.methods,
+                    false,
+                )).as_str(),
+            );
 
             if temp_err != "" {
                 jdoc_errs.push_str(
@@ -892,3 +2076,6 @@ pub mod document {
         }
     }
 }
+
+#[cfg(test)]
+mod test;
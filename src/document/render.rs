@@ -0,0 +1,1140 @@
+//! Format independent rendering tree shared by the markdown and HTML backends
+
+use document::document::clean_signature;
+use document::document::member_anchor;
+use document::document::method_signature;
+use document::document::order_modifiers;
+use document::document::slugify;
+use document::renderer::render_section;
+use document::renderer::MarkdownRenderer;
+use model::links::split_type;
+use model::model::Access;
+use model::model::DocSpan;
+use model::model::Exception;
+use model::model::GenConfig;
+use model::model::Member;
+use model::model::MemberOrder;
+use model::model::Method;
+use model::model::ObjectType;
+use model::model::Param;
+
+/// A documented field, constructor, or method of a type
+pub struct Entry {
+    pub title: String,
+    /// The type of a field, which its title starts with
+    pub var_type: Option<String>,
+    /// The pages of the parsed types referenced in the types of the member, by type name
+    pub type_links: Vec<(String, String)>,
+    /// The explicit anchor of an overloaded member, whose heading is not unique
+    pub anchor: Option<String>,
+    /// The anchor `{@link Type#member}` references point at, the slug of the member name.
+    /// Only the first member of a section with the name has one.
+    pub link_anchor: Option<String>,
+    /// The line the member is declared on, shown in the heading when line numbers are enabled
+    pub line: Option<String>,
+    pub src: String,
+    pub quote: String,
+    pub signature: String,
+    pub description: Option<String>,
+    pub deprecation: Option<String>,
+    pub see: Option<String>,
+    /// The custom block tags of a method with their text
+    pub tags: Vec<(String, String)>,
+    pub access: String,
+    pub modifiers: Vec<String>,
+    pub value: Option<String>,
+    /// The `default` value of an annotation element
+    pub default: Option<String>,
+    pub exceptions: Vec<Exception>,
+    pub return_type: Option<String>,
+    pub return_desc: Option<String>,
+    pub type_params: Vec<Param>,
+    pub params: Option<Vec<Param>>,
+}
+
+/// A titled group of entries such as the methods of a type
+pub struct Section {
+    pub title: String,
+    pub entries: Vec<Entry>,
+}
+
+/// The rendering tree of a whole java type
+pub struct DocTree {
+    pub title: String,
+    pub src: String,
+    pub description: String,
+    pub details: Vec<(String, String)>,
+    pub sections: Vec<Section>,
+}
+
+/// Creates the source link for a member, empty when no source path is known
+///
+/// # Arguments
+///
+/// * `path` - The source file path
+/// * `line_num` - The line the member is declared on
+/// * `config` - The generation settings with the line URL template, if any
+fn src_link(path: &str, line_num: &str, config: &GenConfig) -> String {
+    if path.is_empty() {
+        String::new()
+    } else if !config.line_url.is_empty() {
+        config.line_url.replace("{path}", path).replace("{line}", line_num)
+    } else {
+        format!("{}#L{}", path, line_num)
+    }
+}
+
+/// Gets the line shown in the heading of a member, `None` when line numbers are disabled
+///
+/// # Arguments
+///
+/// * `line_num` - The line the member is declared on
+/// * `config` - The generation settings
+pub fn entry_line(line_num: &str, config: &GenConfig) -> Option<String> {
+    if config.line_numbers && !line_num.is_empty() {
+        Some(line_num.to_string())
+    } else {
+        None
+    }
+}
+
+/// Adds the line of a member to its heading text, e.g. `getValue (line 42)`
+///
+/// # Arguments
+///
+/// * `heading` - The heading text
+/// * `line` - The line of the member, if shown
+pub fn line_heading(heading: &str, line: &Option<String>) -> String {
+    match line {
+        Some(line) => format!("{} (line {})", heading, line),
+        None => heading.to_string(),
+    }
+}
+
+/// Gets the heading text of an entry, marking deprecated members
+///
+/// # Arguments
+///
+/// * `title` - The title of the member
+/// * `deprecated` - Whether the member is deprecated
+pub fn heading_text(title: &str, deprecated: bool) -> String {
+    if deprecated {
+        format!("\u{26a0} Deprecated {}", title)
+    } else {
+        title.to_string()
+    }
+}
+
+/// Gets the type parameter list of a generic method, e.g. `<T extends Comparable<T>>`,
+/// `None` for other entries
+///
+/// # Arguments
+///
+/// * `entry` - The entry of the member
+fn type_param_prefix(entry: &Entry) -> Option<String> {
+    if entry.params.is_some() && !entry.type_params.is_empty() {
+        let names: Vec<&str> = entry.type_params.iter().map(|p| p.var_type.as_str()).collect();
+        Some(format!("<{}>", names.join(", ")))
+    } else {
+        None
+    }
+}
+
+/// Gets the text of the deprecation notice of a member
+fn deprecation_text(message: &str) -> String {
+    if !message.is_empty() {
+        format!("Deprecated: {}", message)
+    } else {
+        "Deprecated".to_string()
+    }
+}
+
+/// Orders the documented fields of a type as configured. Alphabetical order is by
+/// name, ignoring case.
+///
+/// # Arguments
+///
+/// * `variables` - The fields in declaration order
+/// * `config` - The generation settings deciding the member order
+pub fn order_variables<'a>(mut variables: Vec<&'a Member>, config: &GenConfig) -> Vec<&'a Member> {
+    if config.member_order == MemberOrder::Alphabetical {
+        variables.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()).then(a.name.cmp(&b.name)));
+    }
+
+    variables
+}
+
+/// Orders the documented methods of a type as configured. Alphabetical order is by
+/// name, ignoring case, and overloads are ordered by their signature.
+///
+/// # Arguments
+///
+/// * `methods` - The methods in declaration order
+/// * `config` - The generation settings deciding the member order
+pub fn order_methods<'a>(mut methods: Vec<&'a Method>, config: &GenConfig) -> Vec<&'a Method> {
+    if config.member_order == MemberOrder::Alphabetical {
+        methods.sort_by(|a, b| {
+            a.name
+                .to_lowercase()
+                .cmp(&b.name.to_lowercase())
+                .then(a.name.cmp(&b.name))
+                .then(clean_signature(&a.signature).cmp(&clean_signature(&b.signature)))
+        });
+    }
+
+    methods
+}
+
+/// Gets the anchor of an overloaded constructor or method, made of its title and the
+/// types of its parameters, e.g. `max-int-int`. `None` when no other documented
+/// constructor or method of the type has the same name, as its heading is unique.
+///
+/// # Arguments
+///
+/// * `method` - The constructor or method
+/// * `title` - The title used for the method's heading
+/// * `methods` - The methods and constructors of the type
+/// * `config` - The generation settings deciding which members are documented
+pub fn overload_anchor(method: &Method, title: &str, methods: &[Method], config: &GenConfig) -> Option<String> {
+    let overloads = methods
+        .iter()
+        .filter(|m| m.is_constructor == method.is_constructor && m.name == method.name)
+        .filter(|m| config.documents_member(m.privacy, &m.annotations, &m.since))
+        .count();
+
+    if overloads < 2 {
+        return None;
+    }
+
+    let mut fingerprint = title.to_string();
+    for param in &method.parameters {
+        let var_type = param.var_type.replace(" ", "").replace("[]", "array").replace("...", "varargs");
+        fingerprint.push_str(format!(" {}", var_type).as_str());
+    }
+
+    Some(config.prefix_anchor(slugify(&fingerprint)))
+}
+
+/// Gets the pages of the parsed types referenced in the types of a member, by type name
+///
+/// # Arguments
+///
+/// * `types` - The type expressions of the member
+/// * `config` - The generation settings with the links between types, if any
+fn type_links<'a, I: Iterator<Item = &'a str>>(types: I, config: &GenConfig) -> Vec<(String, String)> {
+    let mut links: Vec<(String, String)> = Vec::new();
+
+    for type_expr in types {
+        for link in config.type_hrefs(type_expr) {
+            if !links.contains(&link) {
+                links.push(link);
+            }
+        }
+    }
+
+    links
+}
+
+/// Builds the entry for a single method or constructor
+///
+/// # Arguments
+///
+/// * `method` - The method to be documented
+/// * `title` - The title used for the method's heading
+/// * `anchor` - The explicit anchor of an overloaded method
+/// * `path` - The source file path used for source links, if not empty
+/// * `show_return` - Whether the return type is documented
+/// * `config` - The generation settings used to link referenced types
+fn method_entry(
+    method: &Method,
+    title: &str,
+    anchor: Option<String>,
+    path: &str,
+    show_return: bool,
+    config: &GenConfig,
+) -> Entry {
+    let return_type = if show_return { Some(method.return_type.as_str()) } else { None };
+
+    Entry {
+        title: title.to_string(),
+        var_type: None,
+        type_links: type_links(
+            return_type.into_iter().chain(method.parameters.iter().map(|p| p.var_type.as_str())),
+            config,
+        ),
+        // Prefixed anchors differ from the heading, so every heading gets an explicit one
+        anchor: match anchor {
+            None if !config.anchor_prefix.is_empty() => Some(member_anchor(
+                &heading_text(title, method.is_deprecated()),
+                &method.line_num,
+                path,
+                config,
+            )),
+            anchor => anchor,
+        },
+        link_anchor: Some(config.prefix_anchor(slugify(&method.name))),
+        line: entry_line(&method.line_num, config),
+        src: src_link(path, &method.line_num, config),
+        quote: String::new(),
+        signature: method_signature(method),
+        description: Some(config.link_references(&method.description)),
+        deprecation: if method.is_deprecated() {
+            Some(method.deprecation.clone().unwrap_or(String::new()))
+        } else {
+            None
+        },
+        see: if !method.see.is_empty() {
+            Some(config.link_references(&format!("{{@link {}}}", method.see)))
+        } else {
+            None
+        },
+        tags: method
+            .custom_tags
+            .iter()
+            .map(|(tag, text)| (tag.clone(), config.link_references(text)))
+            .collect(),
+        access: method.privacy.label().to_string(),
+        modifiers: order_modifiers(&method.modifiers),
+        value: None,
+        default: None,
+        exceptions: method.exceptions.iter().map(|e| e.clone()).collect(),
+        return_type: return_type.map(|t| t.to_string()),
+        return_desc: if show_return && !method.return_desc.is_empty() {
+            Some(config.link_references(&method.return_desc))
+        } else {
+            None
+        },
+        type_params: method
+            .type_param_docs
+            .iter()
+            .map(|p| Param {
+                desc: config.link_references(&p.desc),
+                name: p.name.clone(),
+                var_type: p.var_type.clone(),
+            })
+            .collect(),
+        params: Some(
+            method
+                .parameters
+                .iter()
+                .map(|p| Param {
+                    desc: config.link_references(&p.desc),
+                    name: p.name.clone(),
+                    var_type: p.var_type.clone(),
+                })
+                .collect(),
+        ),
+    }
+}
+
+/// Keeps the link anchor of the first entry with each name, so overloaded members
+/// share the anchor of the first overload instead of repeating it
+///
+/// # Arguments
+///
+/// * `entries` - The entries of a section, in the order they are rendered
+fn unique_link_anchors(mut entries: Vec<Entry>) -> Vec<Entry> {
+    let mut seen: Vec<String> = Vec::new();
+
+    for entry in &mut entries {
+        if let Some(anchor) = entry.link_anchor.take() {
+            if !seen.contains(&anchor) {
+                seen.push(anchor.clone());
+                entry.link_anchor = Some(anchor);
+            }
+        }
+    }
+
+    entries
+}
+
+/// Builds the member variable section of a type
+///
+/// # Arguments
+///
+/// * `variables` - The member variables to be documented
+/// * `path` - The source file path used for source links, if not empty
+/// * `config` - The generation settings deciding which variables are documented
+pub fn var_section(variables: &[Member], path: &str, config: &GenConfig) -> Section {
+    if variables.is_empty() {
+        return Section {
+            title: "No member variables in this class".to_string(),
+            entries: Vec::new(),
+        };
+    }
+
+    let variables: Vec<&Member> = variables
+        .iter()
+        .filter(|v| config.documents_member(v.access, &v.annotations, ""))
+        .collect();
+    let entries = order_variables(variables, config)
+        .into_iter()
+        .map(|member| Entry {
+            title: format!("{} {}", member.var_type, member.name),
+            var_type: Some(member.var_type.clone()),
+            type_links: type_links(Some(member.var_type.as_str()).into_iter(), config),
+            anchor: if config.anchor_prefix.is_empty() {
+                None
+            } else {
+                Some(member_anchor(&format!("{} {}", member.var_type, member.name), &member.line_num, path, config))
+            },
+            link_anchor: Some(config.prefix_anchor(slugify(&member.name))),
+            line: entry_line(&member.line_num, config),
+            src: src_link(path, &member.line_num, config),
+            quote: if !path.is_empty() {
+                member.signature.trim().to_string()
+            } else {
+                String::new()
+            },
+            signature: String::new(),
+            description: if !member.desc.is_empty() {
+                Some(config.link_references(&member.desc))
+            } else {
+                None
+            },
+            deprecation: None,
+            see: None,
+            tags: Vec::new(),
+            access: member.access.label().to_string(),
+            modifiers: order_modifiers(&member.modifiers),
+            value: if !member.default_value.is_empty() {
+                Some(member.default_value.clone())
+            } else {
+                None
+            },
+            default: None,
+            exceptions: Vec::new(),
+            return_type: None,
+            return_desc: None,
+            type_params: Vec::new(),
+            params: None,
+        })
+        .collect();
+
+    Section {
+        title: "Member Variables".to_string(),
+        entries: unique_link_anchors(entries),
+    }
+}
+
+/// Builds the element section of an annotation type. Elements are always public and
+/// show their `default` value instead of an initializer.
+///
+/// # Arguments
+///
+/// * `elements` - The elements of the annotation type
+/// * `path` - The source file path used for source links, if not empty
+/// * `config` - The generation settings deciding which elements are documented
+pub fn element_section(elements: &[Member], path: &str, config: &GenConfig) -> Section {
+    if elements.is_empty() {
+        return Section {
+            title: "No elements in this annotation".to_string(),
+            entries: Vec::new(),
+        };
+    }
+
+    let mut section = var_section(elements, path, config);
+    section.title = "Elements".to_string();
+    for entry in section.entries.iter_mut() {
+        entry.title = format!("{}()", entry.title);
+        entry.access = Access::Public.label().to_string();
+        entry.default = entry.value.take();
+    }
+
+    section
+}
+
+/// Builds the constructor section of a type, `None` when it has no constructors
+///
+/// # Arguments
+///
+/// * `methods` - The methods of the type containing the constructors
+/// * `type_name` - The name of the type the constructors belong to
+/// * `path` - The source file path used for source links, if not empty
+/// * `config` - The generation settings deciding which constructors are documented
+pub fn constructor_section(methods: &[Method], type_name: &str, path: &str, config: &GenConfig) -> Option<Section> {
+    let constructors: Vec<&Method> = methods
+        .iter()
+        .filter(|m| m.is_constructor && config.documents_member(m.privacy, &m.annotations, &m.since))
+        .collect();
+    let entries: Vec<Entry> = order_methods(constructors, config)
+        .into_iter()
+        .map(|m| method_entry(m, type_name, overload_anchor(m, type_name, methods, config), path, false, config))
+        .collect();
+
+    if !entries.is_empty() {
+        Some(Section {
+            title: "Constructors".to_string(),
+            entries: unique_link_anchors(entries),
+        })
+    } else {
+        None
+    }
+}
+
+/// Builds the method section of a type
+///
+/// # Arguments
+///
+/// * `methods` - The methods to be documented
+/// * `path` - The source file path used for source links, if not empty
+/// * `config` - The generation settings deciding which methods are documented
+pub fn method_section(methods: &[Method], path: &str, config: &GenConfig) -> Section {
+    let documented: Vec<&Method> = methods
+        .iter()
+        .filter(|m| !m.is_constructor && !m.name.is_empty() && config.documents_member(m.privacy, &m.annotations, &m.since))
+        .collect();
+    let entries: Vec<Entry> = order_methods(documented, config)
+        .into_iter()
+        .map(|m| method_entry(m, m.name.as_str(), overload_anchor(m, &m.name, methods, config), path, true, config))
+        .collect();
+
+    if !entries.is_empty() {
+        Section {
+            title: "Methods".to_string(),
+            entries: unique_link_anchors(entries),
+        }
+    } else {
+        Section {
+            title: "No methods in this class".to_string(),
+            entries: Vec::new(),
+        }
+    }
+}
+
+/// Builds the field, constructor, and method sections of a type
+///
+/// # Arguments
+///
+/// * `type_name` - The name of the class, interface, or enum
+/// * `variables` - The member variables of the type
+/// * `methods` - The methods and constructors of the type
+/// * `path` - The source file path used for source links, if not empty
+/// * `config` - The generation settings deciding which members are documented
+pub fn member_sections(
+    type_name: &str,
+    variables: &[Member],
+    methods: &[Method],
+    path: &str,
+    config: &GenConfig,
+) -> Vec<Section> {
+    let mut sections = vec![var_section(variables, path, config)];
+
+    if let Some(section) = constructor_section(methods, type_name, path, config) {
+        sections.push(section);
+    }
+    sections.push(method_section(methods, path, config));
+
+    sections
+}
+
+/// Builds the rendering tree of a parsed class, interface, or enum
+///
+/// # Arguments
+///
+/// * `obj` - The parsed java type
+/// * `config` - The generation settings deciding which members are documented
+pub fn doc_tree(obj: &ObjectType, config: &GenConfig) -> DocTree {
+    let name = obj.name();
+    let path = obj.path();
+    let doc = obj.doc();
+    let member_config = match obj {
+        ObjectType::Interface(_) | ObjectType::Annotation(_) => config.for_interface(),
+        _ => config.clone(),
+    };
+
+    let mut details = vec![("Access".to_string(), obj.access().trim().to_string())];
+    if let ObjectType::Class(c) = obj {
+        if !c.modifiers.is_empty() {
+            details.push(("Modifiers".to_string(), order_modifiers(&c.modifiers).join(" ")));
+        }
+    }
+    if !doc.author.is_empty() {
+        details.push(("Author".to_string(), doc.author.to_string()));
+    }
+    if !doc.since.is_empty() {
+        details.push(("Since".to_string(), doc.since.to_string()));
+    }
+    if !doc.version.is_empty() {
+        details.push(("Version".to_string(), doc.version.to_string()));
+    }
+    for (tag, text) in doc.custom_tags {
+        details.push((tag.clone(), text.clone()));
+    }
+    details.push(("Package".to_string(), obj.package_and_name().0.to_string()));
+
+    DocTree {
+        title: format!("{} {}", obj.kind(), name),
+        src: path.to_string(),
+        description: doc.description.trim().to_string(),
+        details,
+        sections: match obj {
+            ObjectType::Annotation(_) => vec![element_section(obj.variables(), path, &member_config)],
+            _ => member_sections(name, obj.variables(), obj.methods(), path, &member_config),
+        },
+    }
+}
+
+/// Escapes the characters markdown would otherwise treat as formatting or HTML
+///
+/// # Arguments
+///
+/// * `text` - The text to escape
+pub fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::new();
+
+    for ch in text.chars() {
+        match ch {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '*' | '_' | '`' | '[' | ']' | '\\' => {
+                escaped.push('\\');
+                escaped.push(ch);
+            }
+            _ => escaped.push(ch),
+        }
+    }
+
+    escaped
+}
+
+/// Gets the length of the markdown link `[label](href)` at the start of a text,
+/// `None` when the text does not start with a link
+///
+/// # Arguments
+///
+/// * `text` - The text starting with `[`
+fn link_len(text: &str) -> Option<usize> {
+    let close = match text.find("](") {
+        Some(close) => close,
+        None => return None,
+    };
+    if text[1..close].contains("[") || text[..close].contains("\n") {
+        return None;
+    }
+
+    text[close..].find(")").map(|end| close + end + 1)
+}
+
+/// Escapes the characters of plain description text that markdown would treat as
+/// emphasis, inline code, or a heading. The markdown links created for `{@link}`
+/// tags and HTML elements are kept.
+///
+/// # Arguments
+///
+/// * `text` - The plain description text
+/// * `line_start` - Whether the text starts at the beginning of a line
+pub fn escape_description(text: &str, line_start: bool) -> String {
+    let mut escaped = String::new();
+    let mut line_start = line_start;
+    let mut rest = text;
+
+    while let Some(ch) = rest.chars().next() {
+        if ch == '[' {
+            if let Some(len) = link_len(rest) {
+                escaped.push_str(&rest[..len]);
+                rest = &rest[len..];
+                line_start = false;
+                continue;
+            }
+        }
+
+        match ch {
+            '*' | '_' | '`' => {
+                escaped.push('\\');
+                escaped.push(ch);
+            }
+            '#' if line_start => escaped.push_str("\\#"),
+            _ => escaped.push(ch),
+        }
+
+        if ch == '\n' {
+            line_start = true;
+        } else if !ch.is_whitespace() {
+            line_start = false;
+        }
+        rest = &rest[ch.len_utf8()..];
+    }
+
+    escaped
+}
+
+/// Renders a value for a markdown table cell. Whitespace is collapsed to keep the
+/// value on a single line and pipes are escaped, including inside inline code.
+///
+/// # Arguments
+///
+/// * `text` - The rendered markdown of the cell
+pub fn table_cell(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<&str>>().join(" ").replace("|", "\\|")
+}
+
+/// Renders a javadoc description as markdown. Multi-line `{@code}` content becomes a
+/// fenced code block, inline `{@code}` content becomes inline code, `{@literal}`
+/// content is escaped so it shows literally, and `{@link}` tags become their text.
+/// Markdown formatting characters in the remaining text are escaped.
+///
+/// # Arguments
+///
+/// * `description` - The javadoc description
+pub fn description_to_markdown(description: &str) -> String {
+    let mut doc = String::new();
+
+    for span in DocSpan::split(description) {
+        match span {
+            DocSpan::Text(text) => {
+                let line_start = doc.is_empty() || doc.ends_with("\n");
+                if doc.ends_with("\n") {
+                    doc.push_str(escape_description(text.trim_start(), line_start).as_str());
+                } else {
+                    doc.push_str(escape_description(&text, line_start).as_str());
+                }
+            }
+            DocSpan::Code(code) => doc.push_str(format!("`{}`", code).as_str()),
+            DocSpan::CodeBlock(code) => {
+                doc = doc.trim_end().to_string();
+                doc.push_str(format!("\n\n```java\n{}\n```\n\n", code).as_str());
+            }
+            DocSpan::Literal(text) => doc.push_str(escape_markdown(&text).as_str()),
+            DocSpan::Link(text) => doc.push_str(text.as_str()),
+        }
+    }
+
+    doc.trim().to_string()
+}
+
+/// Renders a type expression as markdown, linking the parsed types it references
+///
+/// # Arguments
+///
+/// * `type_expr` - The type expression
+/// * `links` - The pages of the referenced types, by type name
+pub fn type_to_markdown(type_expr: &str, links: &[(String, String)]) -> String {
+    let mut doc = String::new();
+
+    for (part, is_name) in split_type(type_expr) {
+        match links.iter().find(|(name, _)| is_name && *name == part) {
+            Some((_, href)) => doc.push_str(format!("[{}]({})", part, href).as_str()),
+            None => doc.push_str(part.as_str()),
+        }
+    }
+
+    doc
+}
+
+/// Renders a type expression as escaped HTML, linking the parsed types it references
+///
+/// # Arguments
+///
+/// * `type_expr` - The type expression
+/// * `links` - The pages of the referenced types, by type name
+pub fn type_to_html(type_expr: &str, links: &[(String, String)]) -> String {
+    let mut html = String::new();
+
+    for (part, is_name) in split_type(type_expr) {
+        match links.iter().find(|(name, _)| is_name && *name == part) {
+            Some((_, href)) => {
+                html.push_str(format!("<a href=\"{}\">{}</a>", escape_html(href), escape_html(&part)).as_str())
+            }
+            None => html.push_str(escape_html(&part).as_str()),
+        }
+    }
+
+    html
+}
+
+/// Renders the title of an entry with the type of a field linked by a format
+///
+/// # Arguments
+///
+/// * `entry` - The entry of the member
+/// * `link` - Renders the type of the field
+/// * `text` - Renders the rest of the title
+fn linked_title<L, T>(entry: &Entry, link: L, text: T) -> String
+where
+    L: Fn(&str, &[(String, String)]) -> String,
+    T: Fn(&str) -> String,
+{
+    match entry.var_type {
+        Some(ref var_type) if entry.title.starts_with(var_type.as_str()) => format!(
+            "{}{}",
+            link(var_type, &entry.type_links),
+            text(&entry.title[var_type.len()..])
+        ),
+        _ => text(&entry.title),
+    }
+}
+
+/// Renders a section of entries as markdown
+///
+/// # Arguments
+///
+/// * `section` - The section to render
+pub fn section_to_markdown(section: &Section) -> String {
+    render_section(&mut MarkdownRenderer, section)
+}
+
+/// Renders a documented field, constructor, or method as markdown
+///
+/// # Arguments
+///
+/// * `entry` - The entry to render
+pub fn entry_to_markdown(entry: &Entry) -> String {
+    let mut doc = String::new();
+
+    let level = if entry.params.is_some() { "###" } else { "####" };
+    let heading = line_heading(&heading_text(&entry.title, entry.deprecation.is_some()), &entry.line);
+
+    // The type parameters of a generic method are shown before its name, while the
+    // anchor of the heading without them is kept for the links to the method
+    let own_anchor = match entry.anchor {
+        Some(ref anchor) => anchor.clone(),
+        None if entry.src.is_empty() => slugify(&heading),
+        None => slugify(format!("{} [src]", heading).as_str()),
+    };
+    let anchor = if entry.anchor.is_some() || type_param_prefix(entry).is_some() {
+        Some(own_anchor.clone())
+    } else {
+        None
+    };
+    let title = linked_title(entry, type_to_markdown, |text| text.to_string());
+    let heading = match type_param_prefix(entry) {
+        Some(prefix) => line_heading(
+            &heading_text(&format!("`{}` {}", prefix, title), entry.deprecation.is_some()),
+            &entry.line,
+        ),
+        None => line_heading(&heading_text(&title, entry.deprecation.is_some()), &entry.line),
+    };
+
+    if let Some(ref link_anchor) = entry.link_anchor {
+        if *link_anchor != own_anchor {
+            doc.push_str(format!("<a id=\"{}\"></a>\n\n", link_anchor).as_str());
+        }
+    }
+    if let Some(ref anchor) = anchor {
+        doc.push_str(format!("<a id=\"{}\"></a>\n\n", anchor).as_str());
+    }
+
+    if !entry.src.is_empty() {
+        doc.push_str(format!("{} {} [[src]]({})\n\n", level, heading, entry.src).as_str());
+    } else {
+        doc.push_str(format!("{} {}\n\n", level, heading).as_str());
+    }
+
+    if let Some(ref message) = entry.deprecation {
+        doc.push_str(format!("> {}\n\n", deprecation_text(message)).as_str());
+    }
+
+    if !entry.quote.is_empty() {
+        doc.push_str(format!(" > {}  \n\n", entry.quote).as_str());
+    }
+    if !entry.signature.is_empty() {
+        doc.push_str(format!("```java\n{}\n```\n\n", entry.signature).as_str());
+    }
+    if let Some(ref description) = entry.description {
+        doc.push_str(format!("+ Description: {}  \n", description_to_markdown(description)).as_str());
+    }
+    doc.push_str(format!("+ Access: {}  \n", entry.access).as_str());
+
+    if !entry.modifiers.is_empty() {
+        doc.push_str("+ Modifiers: ");
+
+        for modifier in &entry.modifiers {
+            doc.push_str(format!("{} ", modifier).as_str())
+        }
+
+        doc.push('\n');
+    }
+    if let Some(ref value) = entry.value {
+        doc.push_str(format!("+ Value: `{}`  \n", value).as_str());
+    }
+    if let Some(ref default) = entry.default {
+        doc.push_str(format!("+ Default: `{}`  \n", default).as_str());
+    }
+
+    for exception in &entry.exceptions {
+        doc.push_str(format!("+ Throws {}: {}  \n", exception.exception_type, exception.desc).as_str());
+    }
+    if let Some(ref see) = entry.see {
+        doc.push_str(format!("+ See: {}  \n", description_to_markdown(see)).as_str());
+    }
+    for (tag, text) in &entry.tags {
+        doc.push_str(format!("+ {}: {}  \n", tag, description_to_markdown(text)).as_str());
+    }
+
+    if let Some(ref return_type) = entry.return_type {
+        let return_type = type_to_markdown(return_type, &entry.type_links);
+
+        match entry.return_desc {
+            Some(ref desc) => {
+                doc.push_str(format!("+ return: {} - {}  \n", return_type, description_to_markdown(desc)).as_str())
+            }
+            None => doc.push_str(format!("+ return: {}  \n", return_type).as_str()),
+        }
+    }
+    doc.push('\n');
+
+    if !entry.type_params.is_empty() {
+        doc.push_str(type_params_to_markdown(&entry.type_params).as_str());
+        doc.push('\n');
+    }
+    if let Some(ref params) = entry.params {
+        doc.push_str(params_to_markdown(params, &entry.type_links).as_str());
+        doc.push_str("\n\n");
+    }
+
+    doc
+}
+
+/// Renders a markdown table of parameters with their types and descriptions.
+/// Returns an empty string when there are no parameters.
+///
+/// # Arguments
+///
+/// * `params` - The parameters to render
+/// * `links` - The pages of the parsed types referenced in the parameter types
+pub fn params_to_markdown(params: &[Param], links: &[(String, String)]) -> String {
+    let mut doc = String::new();
+
+    if params.is_empty() {
+        return doc;
+    }
+
+    doc.push_str("| Name | Type | Description |  \n");
+    doc.push_str("| ----- | ----- | ----- |  \n");
+
+    for param in params {
+        // Types linked to their pages cannot be inside inline code
+        let desc = table_cell(&description_to_markdown(&param.desc));
+
+        let var_type = type_to_markdown(&param.var_type, links);
+
+        if var_type != param.var_type {
+            doc.push_str(format!("| {} | {} | {} |  \n", param.name, table_cell(&var_type), desc).as_str());
+        } else {
+            doc.push_str(format!("| {} | `{}` | {} |  \n", param.name, table_cell(&param.var_type), desc).as_str());
+        }
+    }
+
+    doc
+}
+
+/// Renders a markdown table of the type parameters of a method, separate from the
+/// table of its value parameters. Returns an empty string when there are none.
+///
+/// # Arguments
+///
+/// * `type_params` - The type parameters, with their declarations as the type
+pub fn type_params_to_markdown(type_params: &[Param]) -> String {
+    let mut doc = String::new();
+
+    if type_params.is_empty() {
+        return doc;
+    }
+
+    doc.push_str("| Type Parameter | Description |  \n");
+    doc.push_str("| ----- | ----- |  \n");
+
+    for param in type_params {
+        let desc = table_cell(&description_to_markdown(&param.desc));
+        doc.push_str(format!("| `{}` | {} |  \n", table_cell(&param.var_type), desc).as_str());
+    }
+
+    doc
+}
+
+/// Escapes the characters with a special meaning in HTML
+///
+/// # Arguments
+///
+/// * `text` - The text to escape
+pub fn escape_html(text: &str) -> String {
+    text.replace("&", "&amp;")
+        .replace("<", "&lt;")
+        .replace(">", "&gt;")
+        .replace("\"", "&quot;")
+}
+
+/// Creates the link target for a `{@link}` reference such as `Type`, `Type#member`, or `#member`
+fn link_href(target: &str) -> String {
+    let mut parts = target.splitn(2, "#");
+    let type_name = parts.next().unwrap_or("");
+    let member = parts.next().unwrap_or("");
+    let member = member.split("(").next().unwrap_or("");
+
+    let mut href = String::new();
+    if !type_name.is_empty() {
+        href.push_str(format!("{}.html", type_name).as_str());
+    }
+    if !member.is_empty() {
+        href.push_str(format!("#{}", slugify(member)).as_str());
+    }
+
+    href
+}
+
+/// Escapes javadoc text for HTML and converts the inline `{@code}` and `{@link}`
+/// tags into `<code>` and `<a>` elements
+///
+/// # Arguments
+///
+/// * `text` - The javadoc text
+pub fn inline_to_html(text: &str) -> String {
+    let text = escape_html(text);
+    let mut html = String::new();
+    let mut rest = text.as_str();
+
+    while let Some(start) = rest.find("{@") {
+        let end = match rest[start..].find("}") {
+            Some(end) => start + end,
+            None => break,
+        };
+
+        html.push_str(&rest[..start]);
+        let tag = &rest[start + 2..end];
+        let mut parts = tag.trim().splitn(2, char::is_whitespace);
+        let name = parts.next().unwrap_or("");
+        let body = parts.next().unwrap_or("").trim();
+
+        match name {
+            "code" | "literal" => html.push_str(format!("<code>{}</code>", body).as_str()),
+            "link" | "linkplain" => {
+                let mut link_parts = body.splitn(2, char::is_whitespace);
+                let target = link_parts.next().unwrap_or("");
+                let label = link_parts.next().unwrap_or(target).trim();
+
+                html.push_str(format!("<a href=\"{}\">{}</a>", link_href(target), label).as_str());
+            }
+            _ => html.push_str(&rest[start..end + 1]),
+        }
+
+        rest = &rest[end + 1..];
+    }
+    html.push_str(rest);
+
+    html
+}
+
+/// Renders a documented field, constructor, or method as HTML
+///
+/// # Arguments
+///
+/// * `entry` - The entry to render
+pub fn entry_to_html(entry: &Entry) -> String {
+    let mut doc = String::new();
+
+    let id = match entry.anchor {
+        Some(ref anchor) => anchor.clone(),
+        None => slugify(&entry.title),
+    };
+    let title = linked_title(entry, type_to_html, escape_html);
+    let title = match type_param_prefix(entry) {
+        Some(prefix) => format!("{} {}", escape_html(&prefix), title),
+        None => title,
+    };
+    let heading = line_heading(&heading_text(&title, entry.deprecation.is_some()), &entry.line);
+
+    if let Some(ref link_anchor) = entry.link_anchor {
+        if *link_anchor != id {
+            doc.push_str(format!("<a id=\"{}\"></a>\n", link_anchor).as_str());
+        }
+    }
+    if !entry.src.is_empty() {
+        doc.push_str(
+            format!(
+                "<h3 id=\"{}\">{} <a href=\"{}\">[src]</a></h3>\n",
+                id,
+                heading,
+                escape_html(&entry.src)
+            ).as_str(),
+        );
+    } else {
+        doc.push_str(format!("<h3 id=\"{}\">{}</h3>\n", id, heading).as_str());
+    }
+
+    if let Some(ref message) = entry.deprecation {
+        doc.push_str(format!("<blockquote>{}</blockquote>\n", inline_to_html(&deprecation_text(message))).as_str());
+    }
+
+    if !entry.signature.is_empty() {
+        doc.push_str(format!("<pre><code>{}</code></pre>\n", escape_html(&entry.signature)).as_str());
+    } else if !entry.quote.is_empty() {
+        doc.push_str(format!("<pre><code>{}</code></pre>\n", escape_html(&entry.quote)).as_str());
+    }
+    if let Some(ref description) = entry.description {
+        if !description.is_empty() {
+            doc.push_str(format!("<p>{}</p>\n", inline_to_html(description)).as_str());
+        }
+    }
+
+    doc.push_str("<ul>\n");
+    doc.push_str(format!("<li>Access: {}</li>\n", escape_html(&entry.access)).as_str());
+    if !entry.modifiers.is_empty() {
+        doc.push_str(format!("<li>Modifiers: {}</li>\n", escape_html(&entry.modifiers.join(" "))).as_str());
+    }
+    if let Some(ref value) = entry.value {
+        doc.push_str(format!("<li>Value: <code>{}</code></li>\n", escape_html(value)).as_str());
+    }
+    if let Some(ref default) = entry.default {
+        doc.push_str(format!("<li>Default: <code>{}</code></li>\n", escape_html(default)).as_str());
+    }
+    for exception in &entry.exceptions {
+        doc.push_str(
+            format!(
+                "<li>Throws <code>{}</code>: {}</li>\n",
+                escape_html(&exception.exception_type),
+                inline_to_html(&exception.desc)
+            ).as_str(),
+        );
+    }
+    if let Some(ref see) = entry.see {
+        doc.push_str(format!("<li>See: {}</li>\n", inline_to_html(see)).as_str());
+    }
+    for (tag, text) in &entry.tags {
+        doc.push_str(format!("<li>{}: {}</li>\n", escape_html(tag), inline_to_html(text)).as_str());
+    }
+    if let Some(ref return_type) = entry.return_type {
+        let return_type = type_to_html(return_type, &entry.type_links);
+
+        match entry.return_desc {
+            Some(ref desc) => doc.push_str(
+                format!("<li>Returns: <code>{}</code> - {}</li>\n", return_type, inline_to_html(desc)).as_str(),
+            ),
+            None => doc.push_str(format!("<li>Returns: <code>{}</code></li>\n", return_type).as_str()),
+        }
+    }
+    doc.push_str("</ul>\n");
+
+    if !entry.type_params.is_empty() {
+        doc.push_str("<dl class=\"type-params\">\n");
+        for param in &entry.type_params {
+            doc.push_str(
+                format!(
+                    "<dt><code>&lt;{}&gt;</code></dt>\n<dd>{}</dd>\n",
+                    escape_html(&param.var_type),
+                    inline_to_html(&param.desc)
+                ).as_str(),
+            );
+        }
+        doc.push_str("</dl>\n");
+    }
+    if let Some(ref params) = entry.params {
+        if !params.is_empty() {
+            doc.push_str("<dl>\n");
+            for param in params {
+                doc.push_str(
+                    format!(
+                        "<dt><code>{}</code> <code>{}</code></dt>\n<dd>{}</dd>\n",
+                        type_to_html(&param.var_type, &entry.type_links),
+                        escape_html(&param.name),
+                        inline_to_html(&param.desc)
+                    ).as_str(),
+                );
+            }
+            doc.push_str("</dl>\n");
+        }
+    }
+
+    doc
+}
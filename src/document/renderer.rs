@@ -0,0 +1,161 @@
+//! Extension point for the output formats of the generated documentation
+
+use document::render::*;
+use model::model::GenConfig;
+use model::model::ObjectType;
+
+/// Trait for an output format of the documentation. The driver functions walk the
+/// rendering tree of a type and concatenate the output of each method, so a format
+/// only has to render the parts of the tree.
+pub trait Renderer {
+    /// Renders the heading, description, and details of a type
+    fn render_type(&mut self, tree: &DocTree) -> String;
+
+    /// Renders the heading of a section of members, e.g. the methods of a type
+    fn render_section(&mut self, section: &Section) -> String;
+
+    /// Renders a documented field
+    fn render_field(&mut self, field: &Entry) -> String;
+
+    /// Renders a documented constructor or method
+    fn render_method(&mut self, method: &Entry) -> String;
+
+    /// Renders the end of a type, after its sections
+    fn render_end(&mut self, _tree: &DocTree) -> String {
+        String::new()
+    }
+}
+
+/// Renders a section and every entry in it. Entries with parameters are constructors
+/// or methods, the others are fields.
+///
+/// # Arguments
+///
+/// * `renderer` - The output format
+/// * `section` - The section to render
+pub fn render_section<R: Renderer + ?Sized>(renderer: &mut R, section: &Section) -> String {
+    let mut doc = renderer.render_section(section);
+
+    for entry in &section.entries {
+        if entry.params.is_some() {
+            doc.push_str(renderer.render_method(entry).as_str());
+        } else {
+            doc.push_str(renderer.render_field(entry).as_str());
+        }
+    }
+
+    doc
+}
+
+/// Renders the rendering tree of a type: its header, every section, and its end
+///
+/// # Arguments
+///
+/// * `renderer` - The output format
+/// * `tree` - The rendering tree of the type
+pub fn render_tree<R: Renderer + ?Sized>(renderer: &mut R, tree: &DocTree) -> String {
+    let mut doc = renderer.render_type(tree);
+
+    for section in &tree.sections {
+        doc.push_str(render_section(renderer, section).as_str());
+    }
+    doc.push_str(renderer.render_end(tree).as_str());
+
+    doc
+}
+
+/// Renders a parsed class, interface, or enum with a renderer
+///
+/// # Arguments
+///
+/// * `renderer` - The output format
+/// * `obj` - The parsed java type
+/// * `config` - The generation settings deciding which members are documented
+pub fn render_with<R: Renderer + ?Sized>(renderer: &mut R, obj: &ObjectType, config: &GenConfig) -> String {
+    render_tree(renderer, &doc_tree(obj, config))
+}
+
+/// The markdown output format, also used for the member sections of the markdown
+/// pages
+pub struct MarkdownRenderer;
+
+impl Renderer for MarkdownRenderer {
+    fn render_type(&mut self, tree: &DocTree) -> String {
+        let mut doc = if !tree.src.is_empty() {
+            format!("# {} [[src]]({})  \n\n", tree.title, tree.src)
+        } else {
+            format!("# {}\n\n", tree.title)
+        };
+
+        if !tree.description.is_empty() {
+            doc.push_str(format!("{}\n\n", description_to_markdown(&tree.description)).as_str());
+        }
+        for (label, value) in &tree.details {
+            doc.push_str(format!("{}: {}  \n", label, value).as_str());
+        }
+        doc.push('\n');
+
+        doc
+    }
+
+    fn render_section(&mut self, section: &Section) -> String {
+        format!("## {}\n\n", section.title)
+    }
+
+    fn render_field(&mut self, field: &Entry) -> String {
+        entry_to_markdown(field)
+    }
+
+    fn render_method(&mut self, method: &Entry) -> String {
+        entry_to_markdown(method)
+    }
+}
+
+/// The HTML output format, rendering a type as a self contained `<article>`
+pub struct HtmlRenderer;
+
+impl Renderer for HtmlRenderer {
+    fn render_type(&mut self, tree: &DocTree) -> String {
+        let mut doc = String::from("<article>\n");
+
+        if !tree.src.is_empty() {
+            doc.push_str(
+                format!(
+                    "<h1>{} <a href=\"{}\">[src]</a></h1>\n",
+                    escape_html(&tree.title),
+                    escape_html(&tree.src)
+                ).as_str(),
+            );
+        } else {
+            doc.push_str(format!("<h1>{}</h1>\n", escape_html(&tree.title)).as_str());
+        }
+
+        if !tree.description.is_empty() {
+            doc.push_str(format!("<p>{}</p>\n", inline_to_html(&tree.description)).as_str());
+        }
+
+        doc.push_str("<ul>\n");
+        for (label, value) in &tree.details {
+            doc.push_str(format!("<li>{}: {}</li>\n", label, escape_html(value)).as_str());
+        }
+        doc.push_str("</ul>\n");
+
+        doc
+    }
+
+    fn render_section(&mut self, section: &Section) -> String {
+        format!("<h2>{}</h2>\n", escape_html(&section.title))
+    }
+
+    fn render_field(&mut self, field: &Entry) -> String {
+        entry_to_html(field)
+    }
+
+    fn render_method(&mut self, method: &Entry) -> String {
+        entry_to_html(method)
+    }
+
+    fn render_end(&mut self, _tree: &DocTree) -> String {
+        "</article>\n".to_string()
+    }
+}
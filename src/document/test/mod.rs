@@ -0,0 +1,1581 @@
+use document::document::*;
+use document::render::description_to_markdown;
+use document::render::DocTree;
+use document::render::Entry;
+use document::render::Section;
+use document::renderer::*;
+use model::model::*;
+use parse::parse::*;
+use serde_json;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn test_to_markdown() {
+    let j_class = "package com.example;
+
+    /**
+     * A simple counter
+     *
+     * @author Jane Doe
+     */
+    public class Counter {
+        /** The current count */
+        private int count;
+
+        /**
+         * Creates a counter starting at a value
+         *
+         * @param start The starting value
+         */
+        public Counter(int start) {
+            count = start;
+        }
+
+        /**
+         * Adds to the count
+         *
+         * @param amount The amount to add
+         * @return The new count
+         */
+        public int add(int amount) {
+            return count;
+        }
+    }";
+
+    let expected = concat!(
+        "# Class Counter\n",
+        "\n",
+        "Author(s): Jane Doe  \n",
+        "\n",
+        "Access: public  \n",
+        "Description:  \n",
+        " > A simple counter  \n",
+        "\n",
+        "package: com.example  \n",
+        "\n",
+        "## Dependencies\n",
+        "\n",
+        "<details>  \n",
+        "  <summary>  \n",
+        "    Show dependencies  \n",
+        "  </summary>  \n",
+        "  <ul>  \n",
+        "  </ul>  \n",
+        "</details>  \n",
+        "\n",
+        "## Member Variables\n",
+        "\n",
+        "<a id=\"count\"></a>\n",
+        "\n",
+        "#### int count\n",
+        "\n",
+        "+ Description: The current count  \n",
+        "+ Access: private  \n",
+        "\n",
+        "## Constructors\n",
+        "\n",
+        "### Counter\n",
+        "\n",
+        "```java\n",
+        "public Counter(int start)\n",
+        "```\n",
+        "\n",
+        "+ Description: Creates a counter starting at a value  \n",
+        "+ Access: public  \n",
+        "\n",
+        "| Name | Type | Description |  \n",
+        "| ----- | ----- | ----- |  \n",
+        "| start | `int` | The starting value |  \n",
+        "\n",
+        "## Methods\n",
+        "\n",
+        "### add\n",
+        "\n",
+        "```java\n",
+        "public int add(int amount)\n",
+        "```\n",
+        "\n",
+        "+ Description: Adds to the count  \n",
+        "+ Access: public  \n",
+        "+ return: int - The new count  \n",
+        "\n",
+        "| Name | Type | Description |  \n",
+        "| ----- | ----- | ----- |  \n",
+        "| amount | `int` | The amount to add |  \n",
+        "\n",
+    );
+
+    let doc = to_markdown(&construct_ast(lex_contents(&j_class.to_string())).unwrap());
+
+    assert_eq!(expected, doc);
+}
+
+#[test]
+fn test_param_table() {
+    let j_class = "public class Numbers {
+        /**
+         * Finds the larger value
+         *
+         * @param a The first value
+         */
+        public int max(int a, long b) {
+            return a;
+        }
+    }";
+
+    let expected = concat!(
+        "| Name | Type | Description |  \n",
+        "| ----- | ----- | ----- |  \n",
+        "| a | `int` | The first value |  \n",
+        "| b | `long` |  |  \n",
+    );
+
+    match construct_ast(lex_contents(&j_class.to_string())).unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!(expected, gen_param_table(&class.methods[0].parameters));
+            assert_eq!("", gen_param_table(&Vec::new()));
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_toc() {
+    let j_class = "public class Holder {
+        private int value;
+
+        public int getValue() {
+            return value;
+        }
+    }";
+
+    match construct_ast(lex_contents(&j_class.to_string())).unwrap() {
+        ObjectType::Class(class) => {
+            let toc = gen_toc(&class.name, &class.variables, &class.methods, "", &GenConfig::new());
+
+            assert!(toc.contains("  - [value](#int-value)\n"));
+            assert!(toc.contains("  - [getValue](#getvalue)\n"));
+
+            let toc = gen_toc(&class.name, &class.variables, &class.methods, "Holder.java", &GenConfig::new());
+
+            assert!(toc.contains("  - [getValue](#getvalue-src)\n"));
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_to_json() {
+    let j_class = "package com.example;
+
+    /**
+     * A simple counter
+     */
+    public class Counter {
+        private int count;
+
+        /**
+         * Adds to the count
+         *
+         * @param amount The amount to add
+         */
+        public void add(int amount) {
+            count += amount;
+        }
+    }";
+
+    let mut app = ApplicationDoc::new();
+    app.add_object(construct_ast(lex_contents(&j_class.to_string())).unwrap());
+
+    let json: serde_json::Value = serde_json::from_str(to_json(&app).as_str()).unwrap();
+
+    assert_eq!(1, json["class_num"]);
+    assert_eq!("com.example", json["packages"][0]["name"]);
+    assert_eq!("Counter", json["packages"][0]["members"][0]);
+
+    let class = &json["objects"][0];
+    assert_eq!("Class", class["kind"]);
+    assert_eq!("Counter", class["name"]);
+    assert_eq!("A simple counter", class["description"]);
+    assert_eq!("count", class["variables"][0]["name"]);
+    assert_eq!("add", class["methods"][0]["name"]);
+    assert_eq!("amount", class["methods"][0]["parameters"][0]["name"]);
+    assert_eq!("int", class["methods"][0]["parameters"][0]["var_type"]);
+    assert_eq!("The amount to add", class["methods"][0]["parameters"][0]["desc"]);
+}
+
+#[test]
+fn test_method_signature_block() {
+    let j_class = "public class Numbers {
+        public   static int max(int a,  int b) {
+            return a;
+        }
+    }";
+
+    match construct_ast(lex_contents(&j_class.to_string())).unwrap() {
+        ObjectType::Class(class) => {
+            let doc = gen_method_docs(&class.methods, "", &GenConfig::new());
+
+            assert!(doc.contains("```java\npublic static int max(int a, int b)\n```\n"));
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_generic_method_signature() {
+    let j_class = "public class Numbers {
+        /**
+         * Gets the largest value
+         *
+         * @param <T> The type of the values
+         */
+        public static <T extends Comparable<T>> T max(List<T> xs) throws EmptyException {
+            return null;
+        }
+    }";
+
+    match construct_ast(lex_contents(&j_class.to_string())).unwrap() {
+        ObjectType::Class(mut class) => {
+            let doc = gen_method_docs(&class.methods, "", &GenConfig::new());
+
+            assert!(doc.contains("<a id=\"max\"></a>\n\n### `<T extends Comparable<T>>` max\n\n"));
+            assert!(doc.contains("```java\npublic static <T extends Comparable<T>> T max(List<T> xs) throws EmptyException\n```\n"));
+
+            // Without a captured source line the signature is rebuilt from its parts
+            class.methods[0].ch_signature(String::new());
+            let doc = gen_method_docs(&class.methods, "", &GenConfig::new());
+
+            assert!(doc.contains("```java\npublic static <T extends Comparable<T>> T max(List<T> xs) throws EmptyException\n```\n"));
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_write_markdown_tree() {
+    let user = "package com.example.model;
+
+    public class User {
+        private String name;
+    }";
+    let service = "package com.example.service;
+
+    public class UserService {
+        public void save(User user) {}
+    }";
+
+    let mut app = ApplicationDoc::new();
+    app.add_object(construct_ast(lex_contents(&user.to_string())).unwrap());
+    app.add_object(construct_ast(lex_contents(&service.to_string())).unwrap());
+
+    let out = env::temp_dir().join("lojidoc-markdown-tree-test");
+    let _ = fs::remove_dir_all(&out);
+
+    write_markdown_tree(&app, &out, &GenConfig::new());
+
+    let user_md = out.join("com/example/model/User.md");
+    let service_md = out.join("com/example/service/UserService.md");
+    assert!(user_md.is_file());
+    assert!(service_md.is_file());
+    assert_eq!(3, find_file_type(&out, vec!["md"]).len());
+    assert!(out.join("index.md").is_file());
+
+    let modified = fs::metadata(&user_md).unwrap().modified().unwrap();
+    write_markdown_tree(&app, &out, &GenConfig::new());
+    assert_eq!(modified, fs::metadata(&user_md).unwrap().modified().unwrap());
+    assert!(fs::read_to_string(&service_md).unwrap().starts_with("# Class UserService"));
+
+    fs::remove_dir_all(&out).unwrap();
+}
+
+#[test]
+fn test_package_index() {
+    let app = parse_directory(Path::new("tests/fixtures/project"));
+    let index = gen_package_index(&app, &GenConfig::new());
+
+    assert!(index.contains("+ Classes: 1  \n"));
+    assert!(index.contains("+ Interfaces: 1  \n"));
+    assert!(index.contains("+ Enums: 1  \n"));
+    assert!(index.contains("- com.example.model: The domain model of the example application, shared by every service\n    - [Role](com/example/model/Role.md)\n    - [User](com/example/model/User.md)\n"));
+    assert!(index.contains("- com.example.service\n    - [UserService](com/example/service/UserService.md)\n"));
+}
+
+fn lint_source(source: &str) -> Vec<LintWarning> {
+    lint_object(&construct_ast(lex_contents(&source.to_string())).unwrap())
+}
+
+#[test]
+fn test_lint_class_description() {
+    let warnings = lint_source("public class Empty {
+    }");
+
+    assert_eq!(1, warnings.len());
+    assert_eq!(LintKind::MissingClassDescription, warnings[0].kind);
+    assert_eq!("Empty", warnings[0].name.as_str());
+    assert_eq!("1", warnings[0].line_num.as_str());
+}
+
+#[test]
+fn test_lint_method_description() {
+    let warnings = lint_source("/** Documented */
+    public class Lint {
+        /** @param a The value */
+        public void set(int a) {}
+
+        private void hidden() {}
+    }");
+
+    assert_eq!(1, warnings.len());
+    assert_eq!(LintKind::MissingMethodDescription, warnings[0].kind);
+    assert_eq!("set", warnings[0].name.as_str());
+    assert_eq!("4", warnings[0].line_num.as_str());
+}
+
+#[test]
+fn test_lint_missing_param() {
+    let warnings = lint_source("/** Documented */
+    public class Lint {
+        /**
+         * Sets two values
+         *
+         * @param a The first value
+         */
+        public void set(int a, int b) {}
+    }");
+
+    assert_eq!(1, warnings.len());
+    assert_eq!(LintKind::MissingParam, warnings[0].kind);
+    assert_eq!("b in method: set", warnings[0].name.as_str());
+}
+
+#[test]
+fn test_lint_unknown_param() {
+    let warnings = lint_source("/** Documented */
+    public class Lint {
+        /**
+         * Resizes the shape
+         *
+         * @param widht The new width
+         * @param <T> The unit
+         */
+        public <T> void resize(int width) {}
+    }");
+
+    assert_eq!(2, warnings.len());
+    assert_eq!(LintKind::MissingParam, warnings[0].kind);
+    assert_eq!("width in method: resize", warnings[0].name.as_str());
+    assert_eq!(LintKind::UnknownParam, warnings[1].kind);
+    assert_eq!("widht in method: resize", warnings[1].name.as_str());
+    assert_eq!("9", warnings[1].line_num.as_str());
+}
+
+#[test]
+fn test_lint_missing_return() {
+    let warnings = lint_source("/** Documented */
+    public class Lint {
+        /** Gets the value */
+        public int get() {}
+
+        /**
+         * Gets the name
+         *
+         * @return The name
+         */
+        public String name() {}
+    }");
+
+    assert_eq!(1, warnings.len());
+    assert_eq!(LintKind::MissingReturn, warnings[0].kind);
+    assert_eq!("get", warnings[0].name.as_str());
+}
+
+#[test]
+fn test_to_html() {
+    let j_class = "package com.example;
+
+    /** Math helpers */
+    public class Numbers {
+        /**
+         * Finds the larger of {@code a} and {@code b}, see {@link Math#max}
+         *
+         * @param a The first value
+         * @param b The second value
+         * @return The larger value
+         */
+        public static int max(int a, int b) {
+            return a;
+        }
+    }";
+
+    let html = to_html(&construct_ast(lex_contents(&j_class.to_string())).unwrap());
+
+    assert!(html.starts_with("<article>\n<h1>Class Numbers</h1>\n<p>Math helpers</p>\n"));
+    assert!(html.contains("<h2>Methods</h2>\n<h3 id=\"max\">max</h3>\n"));
+    assert!(html.contains("<pre><code>public static int max(int a, int b)</code></pre>\n"));
+    assert!(html.contains(
+        "<p>Finds the larger of <code>a</code> and <code>b</code>, see <a href=\"Math.html#max\">Math#max</a></p>\n"
+    ));
+    assert!(html.contains(concat!(
+        "<dl>\n",
+        "<dt><code>int</code> <code>a</code></dt>\n<dd>The first value</dd>\n",
+        "<dt><code>int</code> <code>b</code></dt>\n<dd>The second value</dd>\n",
+        "</dl>\n",
+    )));
+}
+
+#[test]
+fn test_deprecated_markdown() {
+    let j_class = "public class Legacy {
+        /**
+         * Old way to run
+         *
+         * @deprecated Use start instead
+         */
+        public void run() {}
+
+        @Deprecated
+        public void stop() {}
+
+        /** Starts the task */
+        public void start() {}
+    }";
+
+    let doc = to_markdown(&construct_ast(lex_contents(&j_class.to_string())).unwrap());
+
+    assert!(doc.contains(concat!(
+        "## Deprecated\n",
+        "\n",
+        "- [run](#-deprecated-run): Use start instead\n",
+        "- [stop](#-deprecated-stop)\n",
+        "\n",
+    )));
+    assert!(doc.contains("### \u{26a0} Deprecated run\n\n> Deprecated: Use start instead\n\n"));
+    assert!(doc.contains("### \u{26a0} Deprecated stop\n\n> Deprecated\n\n"));
+    assert!(doc.contains("### start\n\n"));
+    assert!(!doc.contains("Deprecated start"));
+}
+
+#[test]
+fn test_heading_offset() {
+    let j_class = "public class Shifted {
+        private int value;
+
+        /** Gets the value */
+        public int getValue() {
+            return value;
+        }
+    }";
+    let class = construct_ast(lex_contents(&j_class.to_string())).unwrap();
+
+    let headings = |doc: String| -> Vec<String> {
+        doc.lines()
+            .filter(|l| l.starts_with("#"))
+            .map(|l| l.to_string())
+            .collect()
+    };
+
+    assert_eq!(
+        vec!["# Class Shifted", "## Dependencies", "## Member Variables", "#### int value", "## Methods", "### getValue"],
+        headings(to_markdown_with_offset(&class, 0))
+    );
+    assert_eq!(
+        vec!["### Class Shifted", "#### Dependencies", "#### Member Variables", "###### int value", "#### Methods", "##### getValue"],
+        headings(to_markdown_with_offset(&class, 2))
+    );
+    assert!(to_markdown_with_offset(&class, 2).contains("```java\npublic int getValue()\n```\n"));
+    assert_eq!("###### Deep\n", shift_headings("#### Deep\n", 5));
+}
+
+#[test]
+fn test_gen_config() {
+    let j_class = "public class Configured {
+        private int secret;
+        public int shown;
+
+        /** Gets the secret */
+        private int getSecret() {
+            return secret;
+        }
+
+        /** Gets the shown value */
+        public int getShown() {
+            return shown;
+        }
+    }";
+    let class = construct_ast(lex_contents(&j_class.to_string())).unwrap();
+
+    assert_eq!(to_markdown(&class), render_object(&class, &GenConfig::new()));
+
+    let public_only = render_object(&class, &GenConfig::new().include_private(false).with_toc(true));
+    assert!(public_only.contains("## Contents\n"));
+    assert!(public_only.contains("### getShown\n"));
+    assert!(!public_only.contains("getSecret"));
+    assert!(!public_only.contains("int secret"));
+
+    let html = render_object(&class, &GenConfig::new().include_private(false).format(OutputFormat::Html));
+    assert!(html.starts_with("<article>"));
+    assert!(html.contains("getShown"));
+    assert!(!html.contains("getSecret"));
+
+    let json: serde_json::Value =
+        serde_json::from_str(render_object(&class, &GenConfig::new().format(OutputFormat::Json)).as_str()).unwrap();
+    assert_eq!("Class", json["kind"]);
+    assert_eq!("Configured", json["name"]);
+}
+
+#[test]
+fn test_min_visibility() {
+    let j_class = "public class Visible {
+        private int hidden;
+        int packaged;
+        protected int inherited;
+        public int shown;
+
+        /** Resets the state */
+        private void reset() {
+        }
+    }";
+    let class = construct_ast(lex_contents(&j_class.to_string())).unwrap();
+
+    let private_doc = render_object(&class, &GenConfig::new().min_visibility(Visibility::Private));
+    assert!(private_doc.contains("#### int hidden\n"));
+    assert!(private_doc.contains("#### int packaged\n"));
+    assert!(private_doc.contains("### reset\n"));
+
+    let protected_doc = render_object(&class, &GenConfig::new().min_visibility(Visibility::Protected));
+    assert!(protected_doc.contains("#### int inherited\n"));
+    assert!(!protected_doc.contains("packaged"));
+
+    let public_doc = render_object(&class, &GenConfig::new().min_visibility(Visibility::Public));
+    assert!(public_doc.contains("#### int shown\n"));
+    assert!(!public_doc.contains("hidden"));
+    assert!(!public_doc.contains("inherited"));
+    assert!(!public_doc.contains("reset"));
+
+    let j_inter = "public interface Contract {
+        /** Runs the contract */
+        void run();
+    }";
+    let inter = construct_ast(lex_contents(&j_inter.to_string())).unwrap();
+    assert!(render_object(&inter, &GenConfig::new().min_visibility(Visibility::Public)).contains("### run\n"));
+}
+
+#[test]
+fn test_constant_value() {
+    let j_class = "public class Limits {
+        public static final int MAX = 100;
+    }";
+    let doc = to_markdown(&construct_ast(lex_contents(&j_class.to_string())).unwrap());
+
+    assert!(doc.contains("+ Modifiers: static final\n+ Value: `100`  \n"));
+}
+
+#[test]
+fn test_description_spans() {
+    assert_eq!(
+        "Call it like this:\n\n```java\nnew Formatter().format(value);\n```\n\nThe `format` method escapes &lt;b&gt; and \\*.",
+        description_to_markdown("Call it like this: {@code\nnew Formatter().format(value);\n} The {@code format} method escapes {@literal <b>} and {@literal *}.")
+    );
+}
+
+#[test]
+fn test_type_cross_links() {
+    let engine = "package com.example.parts;
+
+    public class Engine {
+        /** Starts the engine */
+        public void start() {}
+    }";
+    let car = "package com.example.car;
+
+    public class Car {
+        private Engine engine;
+
+        /**
+         * Replaces the engine, see {@link Engine#start()} and {@link Wheel}
+         *
+         * @param engine The new engine
+         * @see Engine
+         */
+        public Engine swap(Engine engine) {
+            return engine;
+        }
+    }";
+
+    let mut app = ApplicationDoc::new();
+    app.add_object(construct_ast(lex_contents(&engine.to_string())).unwrap());
+    app.add_object(construct_ast(lex_contents(&car.to_string())).unwrap());
+
+    let links = TypeLinks::from_app(&app).for_page("com/example/car/Car.md");
+    assert_eq!(Some("../parts/Engine.md".to_string()), links.href("Engine"));
+    assert_eq!(None, links.href("Wheel"));
+    assert_eq!(
+        vec![("Engine".to_string(), "../parts/Engine.md".to_string())],
+        links.type_hrefs("Map<String, List<Engine>>")
+    );
+
+    let car_type = construct_ast(lex_contents(&car.to_string())).unwrap();
+    let html = render_object(&car_type, &GenConfig::new().format(OutputFormat::Html).with_links(links.clone()));
+    assert!(html.contains("<h3 id=\"engine-engine\"><a href=\"../parts/Engine.md\">Engine</a> engine</h3>\n"));
+    assert!(html.contains("<li>Returns: <code><a href=\"../parts/Engine.md\">Engine</a></code></li>\n"));
+    assert!(html.contains("<dt><code><a href=\"../parts/Engine.md\">Engine</a></code> <code>engine</code></dt>"));
+
+    let out = env::temp_dir().join("lojidoc-cross-link-test");
+    let _ = fs::remove_dir_all(&out);
+    write_markdown_tree(&app, &out, &GenConfig::new());
+
+    let doc = fs::read_to_string(out.join("com/example/car/Car.md")).unwrap();
+    assert!(doc.contains("#### [Engine](../parts/Engine.md) engine\n"));
+    assert!(doc.contains("+ return: [Engine](../parts/Engine.md)  \n"));
+    assert!(doc.contains("| engine | [Engine](../parts/Engine.md) | The new engine |"));
+    assert!(doc.contains("+ See: [Engine](../parts/Engine.md)  \n"));
+    assert!(doc.contains(
+        "+ Description: Replaces the engine, see [Engine.start()](../parts/Engine.md#start) and Wheel  \n"
+    ));
+    assert!(fs::read_to_string(out.join("com/example/parts/Engine.md")).unwrap().starts_with("# Class Engine"));
+
+    fs::remove_dir_all(&out).unwrap();
+}
+
+#[test]
+fn test_markdown_from_str() {
+    let doc = render_object(&parse_str("public class Piped {\n    public void run() {}\n}").unwrap(), &GenConfig::new());
+
+    assert!(doc.starts_with("# Class Piped\n"));
+    assert!(doc.contains("### run\n"));
+}
+
+#[test]
+fn test_inheritance_line() {
+    let base = "package com.example.core;
+
+    public class Vehicle {}";
+    let truck = "package com.example.fleet;
+
+    public class Truck extends Vehicle implements Serializable, Comparable<Truck> {}";
+
+    let mut app = ApplicationDoc::new();
+    app.add_object(parse_str(base).unwrap());
+    app.add_object(parse_str(truck).unwrap());
+
+    let links = TypeLinks::from_app(&app).for_page("com/example/fleet/Truck.md");
+    let config = GenConfig::new().with_links(links);
+    let doc = to_markdown_with_config(&app.objects[1], &config);
+
+    assert!(doc.contains(
+        "Extends: [`Vehicle`](../core/Vehicle.md) \u{2014} Implements: `Serializable`, `Comparable<Truck>`  \n"
+    ));
+    assert!(!to_markdown_with_config(&app.objects[0], &config).contains("Extends:"));
+}
+
+#[test]
+fn test_exclude_annotations() {
+    let j_class = "public class Cache {
+        @Internal
+        private int hits;
+
+        /** Clears the cache */
+        public void clear() {
+        }
+
+        /** Rebuilds the cache index */
+        @Internal
+        public void rebuild() {
+        }
+    }";
+    let class = parse_str(j_class).unwrap();
+
+    let full_doc = render_object(&class, &GenConfig::new());
+    assert!(full_doc.contains("### rebuild\n"));
+
+    let doc = render_object(&class, &GenConfig::new().exclude_annotations(&["@Internal"]));
+    assert!(doc.contains("### clear\n"));
+    assert!(!doc.contains("rebuild"));
+    assert!(!doc.contains("hits"));
+}
+
+#[test]
+fn test_field_visibility() {
+    let j_class = "public class Account {
+        public String owner;
+        private long balance;
+        static int openAccounts;
+    }";
+    let class = parse_str(j_class).unwrap();
+
+    match &class {
+        ObjectType::Class(class) => {
+            assert_eq!(Visibility::Package, Visibility::from_access(class.variables[2].access));
+            assert_eq!(vec!["static".to_string()], class.variables[2].modifiers);
+        }
+        _ => panic!("Expected a class"),
+    }
+
+    let doc = render_object(&class, &GenConfig::new().include_private(false));
+    assert!(doc.contains("#### String owner\n"));
+    assert!(doc.contains("#### int openAccounts\n"));
+    assert!(!doc.contains("balance"));
+
+    let doc = render_object(&class, &GenConfig::new().min_visibility(Visibility::Public));
+    assert!(doc.contains("#### String owner\n"));
+    assert!(!doc.contains("openAccounts"));
+}
+
+#[test]
+fn test_front_matter() {
+    assert_eq!(
+        "---\ntitle: \"Parser: legacy \\\"v1\\\"\"\npackage: com.example\ndeprecated: true\n---\n\n",
+        gen_front_matter("Parser: legacy \"v1\"", "com.example", true)
+    );
+
+    let class = parse_str("package com.example;\n\n/** @deprecated Use Scanner */\npublic class Lexer {}").unwrap();
+    let doc = render_object(&class, &GenConfig::new().with_frontmatter(true).heading_offset(1));
+    assert!(doc.starts_with("---\ntitle: Lexer\npackage: com.example\ndeprecated: true\n---\n\n## Class Lexer\n"));
+
+    assert!(render_object(&class, &GenConfig::new()).starts_with("# Class Lexer\n"));
+}
+
+#[test]
+fn test_version_sorting() {
+    let class = parse_str("package com.example;\n\n/**\n * Parses requests\n * @version 2.1.0\n * @since 1.0\n */\npublic class Parser {}").unwrap();
+    let doc = render_object(&class, &GenConfig::new());
+    assert!(doc.contains("Version: 2.1.0 \u{b7} Since: 1.0  \n"));
+
+    let mut versions = vec!["nightly", "2.1.0", "v1.10", "1.9.3", "beta", "2"];
+    versions.sort_by(|a, b| compare_versions(a, b));
+    assert_eq!(vec!["1.9.3", "v1.10", "2", "2.1.0", "beta", "nightly"], versions);
+
+    let mut app = ApplicationDoc::new();
+    app.add_object(parse_str("package com.example;\n/** @version 1.10.0 */\npublic class Alpha {}").unwrap());
+    app.add_object(parse_str("package com.example;\n/** @version 1.2.0 */\npublic class Beta {}").unwrap());
+    app.add_object(parse_str("package com.example;\n/** @version unreleased */\npublic class Gamma {}").unwrap());
+    app.add_object(parse_str("package com.example;\n/** @version 0.9 */\npublic class Delta {}").unwrap());
+
+    let index = gen_package_index(&app, &GenConfig::new().sort_by_version(true));
+    assert!(index.contains(
+        "- com.example\n    - [Delta](com/example/Delta.md)\n    - [Beta](com/example/Beta.md)\n    - [Alpha](com/example/Alpha.md)\n    - [Gamma](com/example/Gamma.md)\n"
+    ));
+}
+
+#[test]
+fn test_method_summary_since() {
+    let j_class = "package com.example;
+
+public class Queue {
+    /**
+     * Adds an item to the queue. Blocks when full.
+     * @since 3.0
+     */
+    public void push(int item) {}
+
+    /** Removes the next item */
+    public int pop() {}
+}";
+    let class = parse_str(j_class).unwrap();
+
+    let doc = render_object(&class, &GenConfig::new().with_method_summary(true, true));
+    assert!(doc.contains("| Method | Description | Since |  \n| ----- | ----- | ----- |  \n"));
+    assert!(doc.contains("| [push](#push) | Adds an item to the queue. | 3.0 |  \n"));
+    assert!(doc.contains("| [pop](#pop) | Removes the next item |  |  \n"));
+
+    let doc = render_object(&class, &GenConfig::new().with_method_summary(true, false));
+    assert!(doc.contains("| [pop](#pop) | Removes the next item |  \n"));
+    assert!(!render_object(&class, &GenConfig::new()).contains("Method Summary"));
+}
+
+#[test]
+fn test_normalize_markdown() {
+    assert_eq!(
+        "# Title\n\nText  \nMore text\n\n```java\nint a;\n\n\nint b;   \n```\n",
+        normalize_markdown("# Title\n\n\n\nText  \nMore text \t\n   \n\n```java\nint a;\n\n\nint b;   \n```\n")
+    );
+
+    let j_class = "package com.example;
+
+public class Pair {
+    /**
+     * Creates a pair
+     * @param left The left value
+     */
+    public Pair(int left) {}
+
+    /** Swaps the values */
+    public void swap() {}
+}";
+    let doc = to_markdown(&parse_str(j_class).unwrap());
+
+    assert!(!doc.contains("\n\n\n"));
+    assert!(doc.contains("| left | `int` | The left value |  \n\n## Methods\n"));
+    assert!(doc.lines().all(|line| line.trim_end() == line || line.ends_with("  ")));
+}
+
+#[test]
+fn test_license_styles() {
+    let class = parse_str("/*\n * Copyright 2018 Example Corp.\n *\n * Licensed under the Apache License\n */\npackage com.example;\n\npublic class Licensed {}").unwrap();
+
+    let doc = to_markdown(&class);
+    assert!(doc.contains("<summary>Show license</summary>\n\n```text\nCopyright 2018 Example Corp.\n\nLicensed under the Apache License\n```\n"));
+
+    let doc = to_markdown_with_config(&class, &GenConfig::new().license_style(LicenseStyle::Comment));
+    assert!(doc.contains("<!--\nCopyright 2018 Example Corp.\n\nLicensed under the Apache License\n-->\n"));
+
+    let doc = to_markdown_with_config(&class, &GenConfig::new().license_style(LicenseStyle::Footer));
+    assert!(doc.ends_with("---\n\nCopyright 2018 Example Corp.\n\nLicensed under the Apache License\n"));
+    assert!(!doc.contains("Show license"));
+}
+
+#[test]
+fn test_wildcard_bounds() {
+    let j_class = "package com.example;
+
+public class Sink {
+    /**
+     * Drains the values into the sink
+     * @param values The values to drain
+     * @param type The type of error to raise
+     */
+    public void drain(List<? super Integer> values, Class<? extends Throwable> type) {}
+}";
+    let class = parse_str(j_class).unwrap();
+
+    match class {
+        ObjectType::Class(ref class) => {
+            assert_eq!("List<? super Integer>", class.methods[0].parameters[0].var_type.as_str());
+            assert_eq!("Class<? extends Throwable>", class.methods[0].parameters[1].var_type.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+
+    let doc = to_markdown(&class);
+    assert!(doc.contains("| values | `List<? super Integer>` | The values to drain |  \n"));
+    assert!(doc.contains("| type | `Class<? extends Throwable>` | The type of error to raise |  \n"));
+}
+
+#[test]
+fn test_type_param_docs() {
+    let j_class = "package com.example;
+
+public class Picker {
+    /**
+     * Picks one of the options
+     * @param <T> the element type
+     * @param options the candidates
+     * @param index the position to pick
+     * @return the picked option
+     */
+    public <T> T pick(List<T> options, int index) {}
+}";
+    let class = parse_str(j_class).unwrap();
+
+    match class {
+        ObjectType::Class(ref class) => {
+            let method = &class.methods[0];
+            assert_eq!(2, method.parameters.len());
+            assert_eq!("the candidates", method.parameters[0].desc.as_str());
+            assert_eq!("the position to pick", method.parameters[1].desc.as_str());
+            assert_eq!(1, method.type_param_docs.len());
+            assert_eq!("T", method.type_param_docs[0].name.as_str());
+            assert_eq!("the element type", method.type_param_docs[0].desc.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+
+    let doc = to_markdown(&class);
+    assert!(doc.contains(
+        "| Type Parameter | Description |  \n| ----- | ----- |  \n| `T` | the element type |  \n\n| Name | Type | Description |  \n"
+    ));
+    assert!(doc.contains("| options | `List<T>` | the candidates |  \n| index | `int` | the position to pick |  \n"));
+    assert!(!doc.contains("| <T>"));
+}
+
+#[test]
+fn test_member_order() {
+    let j_class = "package com.example;
+
+public class Registry {
+    private int size;
+    private String alias;
+
+    /** Removes an entry */
+    public void remove(String key) {}
+
+    /** Adds an entry with a value */
+    public void add(String key, int value) {}
+
+    /** Adds an entry */
+    public void add(String key) {}
+}";
+    let class = parse_str(j_class).unwrap();
+    let position = |doc: &String, text: &str| doc.find(text).expect(text);
+
+    let doc = to_markdown(&class);
+    assert!(position(&doc, "#### int size") < position(&doc, "#### String alias"));
+    assert!(position(&doc, "### remove") < position(&doc, "public void add(String key, int value)"));
+    assert!(position(&doc, "public void add(String key, int value)") < position(&doc, "public void add(String key)\n"));
+
+    let doc = to_markdown_with_config(&class, &GenConfig::new().member_order(MemberOrder::Alphabetical).with_toc(true));
+    assert!(position(&doc, "#### String alias") < position(&doc, "#### int size"));
+    assert!(position(&doc, "public void add(String key)\n") < position(&doc, "public void add(String key, int value)"));
+    assert!(position(&doc, "public void add(String key, int value)") < position(&doc, "### remove"));
+    assert!(doc.contains("- Methods\n  - [add](#add-string)\n  - [add](#add-string-int)\n  - [remove](#remove)\n"));
+}
+
+#[test]
+fn test_escape_descriptions() {
+    let j_class = "package com.example;
+
+public class Matcher {
+    /**
+     * Matches a *pattern* like my_value
+     * @param pattern the alternatives a|b, see *star* and {@code x|y*z}
+     */
+    public void match(String pattern) {}
+}";
+    let doc = to_markdown(&parse_str(j_class).unwrap());
+
+    assert!(doc.contains("+ Description: Matches a \\*pattern\\* like my\\_value  \n"));
+    assert!(doc.contains("| pattern | `String` | the alternatives a\\|b, see \\*star\\* and `x\\|y*z` |  \n"));
+    assert_eq!("\\# Not a heading, [Type](Type.md) stays", description_to_markdown("# Not a heading, [Type](Type.md) stays"));
+}
+
+#[test]
+fn test_value_tags() {
+    let j_class = "package com.example;
+
+public class Limits {
+    /** At most {@value} items are kept */
+    public static final int MAX = 100;
+
+    /**
+     * Trims the items to {@value #MAX}, see {@value Other#MAX}
+     * @param count at most {@value Limits#MAX}
+     */
+    public void trim(int count) {}
+}";
+    let doc = to_markdown(&parse_str(j_class).unwrap());
+
+    assert!(doc.contains("+ Description: At most 100 items are kept  \n"));
+    assert!(doc.contains("+ Description: Trims the items to 100, see {@value Other#MAX}  \n"));
+    assert!(doc.contains("| count | `int` | at most 100 |  \n"));
+}
+
+/// Renderer collecting the names of the visited members
+struct NameCollector {
+    types: Vec<String>,
+    fields: Vec<String>,
+    methods: Vec<String>,
+}
+
+impl Renderer for NameCollector {
+    fn render_type(&mut self, tree: &DocTree) -> String {
+        self.types.push(tree.title.clone());
+        String::new()
+    }
+    fn render_section(&mut self, section: &Section) -> String {
+        format!("[{}]", section.title)
+    }
+    fn render_field(&mut self, field: &Entry) -> String {
+        self.fields.push(field.title.clone());
+        String::new()
+    }
+    fn render_method(&mut self, method: &Entry) -> String {
+        self.methods.push(method.title.clone());
+        String::new()
+    }
+}
+
+#[test]
+fn test_custom_renderer() {
+    let j_class = "package com.example;
+
+public class Stack {
+    private int size;
+
+    public Stack() {}
+
+    public void push(int item) {}
+
+    public int pop() {}
+}";
+    let class = parse_str(j_class).unwrap();
+    let mut collector = NameCollector {
+        types: Vec::new(),
+        fields: Vec::new(),
+        methods: Vec::new(),
+    };
+
+    let doc = render_with(&mut collector, &class, &GenConfig::new());
+    assert_eq!("[Member Variables][Constructors][Methods]", doc.as_str());
+    assert_eq!(vec!["Class Stack".to_string()], collector.types);
+    assert_eq!(vec!["int size".to_string()], collector.fields);
+    assert_eq!(vec!["Stack".to_string(), "push".to_string(), "pop".to_string()], collector.methods);
+
+    assert_eq!(to_html(&class), render_with(&mut HtmlRenderer, &class, &GenConfig::new()));
+    assert!(render_with(&mut MarkdownRenderer, &class, &GenConfig::new()).starts_with("# Class Stack\n\nAccess: public  \n"));
+}
+
+#[test]
+fn test_code_block_indentation() {
+    let j_class = "package com.example;
+
+public class Formatter {
+    /**
+     * Formats a value, for example
+     * {@code
+     * if (value != null) {
+     *     format(value);
+     * }
+     * }
+     */
+    public void format(Object value) {}
+}";
+    let doc = to_markdown(&parse_str(j_class).unwrap());
+
+    assert!(doc.contains("```java\nif (value != null) {\n    format(value);\n}\n```"));
+}
+
+#[test]
+fn test_code_block_tabs() {
+    let j_class = "package com.example;
+
+public class Formatter {
+\t/**
+\t * Formats a value, for example
+\t * {@code
+\t * if (value != null) {
+\t * \tformat(value,
+\t * \t       width);
+\t * }
+\t * }
+\t */
+\tpublic void format(Object value) {}
+}";
+    let doc = to_markdown(&parse_str(j_class).unwrap());
+
+    assert!(doc.contains("```java\nif (value != null) {\n\tformat(value,\n\t       width);\n}\n```"));
+}
+
+#[test]
+fn test_overload_anchors() {
+    let j_class = "package com.example;
+
+public class Numbers {
+    /** Gets the larger of two ints */
+    public static int max(int a, int b) {}
+
+    /** Gets the larger of two doubles */
+    public static double max(double a, double b) {}
+
+    /** Gets the largest value of an array */
+    public static int max(int[] values) {}
+
+    /** Gets the smaller of two ints */
+    public static int min(int a, int b) {}
+}";
+    let class = parse_str(j_class).unwrap();
+    let doc = to_markdown_with_config(&class, &GenConfig::new().with_toc(true).with_method_summary(true, false));
+
+    assert!(doc.contains("  - [max](#max-int-int)\n  - [max](#max-double-double)\n  - [max](#max-intarray)\n  - [min](#min)\n"));
+    assert!(doc.contains("| [max](#max-double-double) | Gets the larger of two doubles |"));
+    assert!(doc.contains("<a id=\"max-int-int\"></a>\n\n### max\n"));
+    assert!(doc.contains("<a id=\"max-double-double\"></a>\n\n### max\n"));
+    assert!(doc.contains("<a id=\"max-intarray\"></a>\n\n### max\n"));
+    assert!(doc.contains("\n### min\n"));
+    assert!(!doc.contains("<a id=\"min"));
+
+    let html = render_object(&class, &GenConfig::new().format(OutputFormat::Html));
+    assert!(html.contains("<h3 id=\"max-double-double\">max</h3>"));
+    assert!(html.contains("<h3 id=\"min\">min</h3>"));
+}
+
+#[test]
+fn test_nested_type_links() {
+    let j_class = "package com.example;
+
+public class Outer {
+    /**
+     * Starts the inner task
+     * @see com.example.Outer.Inner#run
+     */
+    public void start() {}
+
+    /**
+     * Stops the task
+     * @see com.example.Outer.Missing#stop
+     */
+    public void stop() {}
+
+    /** The inner task */
+    public static class Inner {
+        /** Runs the inner task */
+        public void run() {}
+    }
+}";
+
+    let mut app = ApplicationDoc::new();
+    for object in construct_types(lex_contents(&j_class.to_string())) {
+        app.add_object(object);
+    }
+
+    let links = TypeLinks::from_app(&app).for_page("com/example/Outer.md");
+    assert_eq!(Some("Outer.Inner.md".to_string()), links.href("com.example.Outer.Inner"));
+    assert_eq!(Some("Outer.Inner.md".to_string()), links.href("Inner"));
+    assert_eq!(None, links.href("com.example.Outer.Missing"));
+
+    let out = env::temp_dir().join("lojidoc-nested-link-test");
+    let _ = fs::remove_dir_all(&out);
+    write_markdown_tree(&app, &out, &GenConfig::new());
+
+    let doc = fs::read_to_string(out.join("com/example/Outer.md")).unwrap();
+    assert!(doc.contains("+ See: [com.example.Outer.Inner.run](Outer.Inner.md#run)  \n"));
+    assert!(doc.contains("+ See: com.example.Outer.Missing.stop  \n"));
+    assert!(!doc.contains("### run"));
+
+    let inner = fs::read_to_string(out.join("com/example/Outer.Inner.md")).unwrap();
+    assert!(inner.starts_with("# Class Outer.Inner"));
+    assert!(inner.contains("### run\n"));
+
+    fs::remove_dir_all(&out).unwrap();
+}
+
+#[test]
+fn test_nested_type_links_in_directory() {
+    let app = parse_directory(Path::new("tests/fixtures/nested"));
+    let out = env::temp_dir().join("lojidoc-nested-directory-link-test");
+    let _ = fs::remove_dir_all(&out);
+
+    write_markdown_tree(&app, &out, &GenConfig::new());
+
+    let doc = fs::read_to_string(out.join("com/example/Outer.md")).unwrap();
+    let inner = fs::read_to_string(out.join("com/example/Outer.Inner.md")).unwrap();
+    assert!(doc.contains("+ See: [com.example.Outer.Inner.run](Outer.Inner.md#run)  \n"));
+    assert!(inner.contains("<a id=\"run\"></a>\n\n### run [[src]]("));
+
+    fs::remove_dir_all(&out).unwrap();
+}
+
+#[test]
+fn test_metadata_line() {
+    let j_class = "package com.example;
+
+/**
+ * Schedules jobs
+ * @author Alice
+ * @author Bob
+ * @version 2.0
+ */
+public class Scheduler {}";
+    let class = parse_str(j_class).unwrap();
+
+    let doc = render_object(&class, &GenConfig::new());
+    assert!(doc.starts_with("# Class Scheduler\n\nAuthor(s): Alice, Bob \u{b7} Version: 2.0  \n\nAccess: public  \n"));
+
+    let doc = render_object(&class, &GenConfig::new().metadata_separator(" | "));
+    assert!(doc.contains("\nAuthor(s): Alice, Bob | Version: 2.0  \n"));
+
+    let doc = render_object(&parse_str("package com.example;\n\n/** Runs jobs */\npublic class Runner {}").unwrap(), &GenConfig::new());
+    assert!(doc.starts_with("# Class Runner\n\nAccess: public  \n"));
+    assert!(!doc.contains("Author"));
+    assert!(!doc.contains("Version"));
+    assert!(!doc.contains("\u{b7}"));
+}
+
+#[test]
+fn test_modifier_order() {
+    let j_class = "public class Native {
+    /** Loads the library */
+    public static synchronized native void f();
+
+    /** Frees the library */
+    @Deprecated synchronized final static public strictfp void g() {}
+}";
+    let doc = to_markdown(&parse_str(j_class).unwrap());
+
+    assert!(doc.contains("```java\npublic static synchronized native void f()\n```"));
+    assert!(doc.contains("+ Modifiers: static synchronized native\n"));
+    assert!(doc.contains("```java\n@Deprecated public static final synchronized strictfp void g()\n```"));
+    assert!(doc.contains("+ Modifiers: static final synchronized strictfp\n"));
+}
+
+#[test]
+fn test_since_threshold() {
+    let j_class = "package com.example;
+
+public class Cache {
+    private int size;
+
+    /**
+     * Gets a cached value
+     * @since 1.2
+     */
+    public String get(String key) {}
+
+    /**
+     * Removes every cached value
+     * @since 1.5
+     */
+    public void clear() {}
+
+    /**
+     * Evicts the oldest value
+     * @since v1.10.0
+     */
+    public void evict() {}
+
+    /** Stores a value */
+    public void put(String key, String value) {}
+}";
+    let class = parse_str(j_class).unwrap();
+
+    let doc = render_object(&class, &GenConfig::new().since_at_least("1.5").with_toc(true));
+    assert!(doc.contains("### clear\n"));
+    assert!(doc.contains("### evict\n"));
+    assert!(!doc.contains("### get\n"));
+    assert!(!doc.contains("### put\n"));
+    assert!(!doc.contains("size"));
+    assert!(!doc.contains("[get](#get)"));
+
+    let doc = render_object(&class, &GenConfig::new());
+    assert!(doc.contains("### get\n"));
+    assert!(doc.contains("### put\n"));
+}
+
+#[test]
+fn test_single_file() {
+    let model_user = "package com.example.model;
+
+    /** A stored user */
+    public class User {
+        private String name;
+    }";
+    let api_user = "package com.example.api;
+
+    /** A user returned by the API */
+    public class User {
+        private String id;
+    }";
+
+    let mut app = ApplicationDoc::new();
+    app.add_object(parse_str(model_user).unwrap());
+    app.add_object(parse_str(api_user).unwrap());
+
+    let doc = gen_single_file(&app, &GenConfig::new());
+    assert!(doc.starts_with(
+        "# API Documentation\n\n- com.example.api\n    - [User](#com-example-api-user)\n- com.example.model\n    - [User](#com-example-model-user)\n"
+    ));
+    assert!(doc.contains("## com.example.api\n\n<a id=\"com-example-api-user\"></a>\n\n### Class User\n"));
+    assert!(doc.contains("## com.example.model\n\n<a id=\"com-example-model-user\"></a>\n\n### Class User\n"));
+
+    assert!(doc.contains(" > A user returned by the API  \n"));
+    assert!(doc.contains("###### String name\n"));
+    assert!(doc.ends_with("#### No methods in this class\n\n"));
+
+    let out = env::temp_dir().join("lojidoc-single-file-test").join("API.md");
+    let _ = fs::remove_dir_all(out.parent().unwrap());
+
+    write_single_file(&app, &out, &GenConfig::new());
+    assert_eq!(doc, fs::read_to_string(&out).unwrap());
+
+    fs::remove_dir_all(out.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn test_single_file_member_anchors() {
+    let model_user = "package com.example.model;
+
+    public class User {
+        public String describe() {}
+    }";
+    let api_user = "package com.example.api;
+
+    public class User {
+        public String describe() {}
+    }";
+
+    let mut app = ApplicationDoc::new();
+    app.add_object(parse_str(model_user).unwrap());
+    app.add_object(parse_str(api_user).unwrap());
+
+    let doc = gen_single_file(&app, &GenConfig::new().with_toc(true).min_visibility(Visibility::Public));
+    assert!(doc.contains("  - [describe](#com-example-api-user-describe)\n"));
+    assert!(doc.contains("  - [describe](#com-example-model-user-describe)\n"));
+    assert!(doc.contains("<a id=\"com-example-api-user-describe\"></a>\n\n##### describe\n"));
+    assert!(doc.contains("<a id=\"com-example-model-user-describe\"></a>\n\n##### describe\n"));
+    assert!(!doc.contains("<a id=\"describe\">"));
+}
+
+#[test]
+fn test_abstract_members() {
+    let j_class = "public abstract class Shape {
+    /** Computes the area */
+    protected abstract double area();
+
+    public String describe() { return \"\"; }
+}";
+    let class = parse_str(j_class).unwrap();
+
+    match &class {
+        ObjectType::Class(c) => {
+            assert_eq!(vec!["abstract"], c.modifiers);
+            assert_eq!(0, c.variables.len());
+            assert_eq!("area", c.methods[0].name.as_str());
+            assert_eq!(vec!["abstract"], c.methods[0].modifiers);
+            assert_eq!("double", c.methods[0].return_type.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+
+    let doc = to_markdown(&class);
+    assert!(doc.contains("Access: public  \nModifiers: abstract  \n"));
+    assert!(doc.contains("### area\n\n```java\nprotected abstract double area()\n```\n"));
+    assert!(doc.contains("+ Modifiers: abstract\n"));
+    assert!(to_html(&class).contains("<li>Modifiers: abstract</li>"));
+}
+
+#[test]
+fn test_constructor_params() {
+    let j_class = "public class Point {
+    /**
+     * Creates a point
+     *
+     * @param x The horizontal position
+     * @param y The vertical position
+     */
+    public Point(int x, int y) {}
+}";
+    let class = parse_str(j_class).unwrap();
+
+    match &class {
+        ObjectType::Class(c) => {
+            assert!(c.methods[0].is_constructor);
+            assert_eq!("The horizontal position", c.methods[0].parameters[0].desc.as_str());
+            assert_eq!("The vertical position", c.methods[0].parameters[1].desc.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+
+    let doc = to_markdown(&class);
+    assert!(doc.contains(
+        "## Constructors\n\n### Point\n\n```java\npublic Point(int x, int y)\n```\n\n+ Description: Creates a point  \n+ Access: public  \n\n\
+         | Name | Type | Description |  \n| ----- | ----- | ----- |  \n\
+         | x | `int` | The horizontal position |  \n| y | `int` | The vertical position |  \n"
+    ));
+    assert!(to_html(&class).contains("<dt><code>int</code> <code>y</code></dt>\n<dd>The vertical position</dd>\n"));
+}
+
+#[test]
+fn test_record_accessors() {
+    let j_class = "package com.example;
+
+/**
+ * A point on a plane
+ *
+ * @param x The horizontal position
+ * @param y The vertical position
+ */
+public record Point(int x, int y) implements Shape {
+    /** Checks the position */
+    public Point {
+        check(x);
+    }
+
+    public int x() {
+        return x;
+    }
+}";
+    let class = parse_str(j_class).unwrap();
+
+    match &class {
+        ObjectType::Class(c) => {
+            assert!(c.record);
+            assert_eq!("Point", c.name.as_str());
+            assert_eq!(vec!["Shape"], c.interfaces);
+            assert_eq!(2, c.components.len());
+
+            let constructor = &c.methods[0];
+            assert!(constructor.is_constructor);
+            assert_eq!("Checks the position", constructor.description.as_str());
+            assert_eq!("The vertical position", constructor.parameters[1].desc.as_str());
+
+            let accessors: Vec<(&str, &str, &str)> = c.methods[1..]
+                .iter()
+                .map(|m| (m.name.as_str(), m.return_type.as_str(), m.description.as_str()))
+                .collect();
+            assert_eq!(
+                vec![("x", "int", "The horizontal position"), ("y", "int", "The vertical position")],
+                accessors
+            );
+        }
+        _ => panic!("Expected a class"),
+    }
+
+    let doc = to_markdown(&class);
+    assert!(doc.starts_with("# Record Point\n"));
+    assert!(doc.contains("| x | `int` | The horizontal position |  \n"));
+    assert!(doc.contains("### y\n\n```java\npublic int y()\n```\n\n+ Description: The vertical position  \n"));
+}
+
+#[test]
+fn test_base_url_links() {
+    let engine = "package com.example.parts;
+
+    public class Engine {}";
+    let car = "package com.example.car;
+
+    public class Car {
+        /** Replaces the engine */
+        public Engine swap(Engine engine) {
+            return engine;
+        }
+    }";
+
+    let mut app = ApplicationDoc::new();
+    app.add_object(parse_str(engine).unwrap());
+    app.add_object(parse_str(car).unwrap());
+
+    let out = env::temp_dir().join("lojidoc-base-url-test");
+    let _ = fs::remove_dir_all(&out);
+    let config = GenConfig::new().project_title("Garage").base_url("https://example.com/docs/");
+    write_markdown_tree(&app, &out, &config);
+
+    let doc = fs::read_to_string(out.join("com/example/car/Car.md")).unwrap();
+    assert!(doc.contains("+ return: [Engine](https://example.com/docs/com/example/parts/Engine.md)  \n"));
+    assert!(fs::read_to_string(out.join("index.md")).unwrap().starts_with("# Garage\n\n"));
+
+    let links = TypeLinks::from_app(&app).for_page("com/example/car/Car.md");
+    let config = GenConfig::new().with_links(links).base_url("https://example.com");
+    assert_eq!("[`Engine`](https://example.com/com/example/parts/Engine.md)", config.link_code("Engine"));
+    assert!(gen_package_index(&app, &GenConfig::new()).starts_with("# Package Index\n\n"));
+
+    fs::remove_dir_all(&out).unwrap();
+}
+
+#[test]
+fn test_annotation_element_defaults() {
+    let j_annotation = "package com.example;
+
+/** Retries a failing test */
+public @interface Retry {
+    /** The number of attempts */
+    int count() default 1;
+
+    /** The tests that are never retried */
+    String[] exclude();
+}";
+    let annotation = parse_str(j_annotation).unwrap();
+
+    let doc = to_markdown(&annotation);
+    assert!(doc.starts_with("# Annotation Retry\n"));
+    assert!(doc.contains(
+        "## Elements\n\n<a id=\"count\"></a>\n\n#### int count()\n\n+ Description: The number of attempts  \n\
+         + Access: public  \n+ Default: `1`  \n"
+    ));
+    assert!(doc.contains("#### String[] exclude()\n\n+ Description: The tests that are never retried  \n+ Access: public  \n\n"));
+    assert!(!doc.contains("+ Value:"));
+    assert!(!doc.contains("methods"));
+
+    let html = to_html(&annotation);
+    assert!(html.contains("<h1>Annotation Retry</h1>\n"));
+    assert!(html.contains("<li>Default: <code>1</code></li>\n"));
+}
+
+#[test]
+fn test_inline_nested_types() {
+    let j_class = "package com.example;
+
+/** A parking garage */
+public class Garage {
+    /** Parks a car */
+    public void park(Spot spot) {}
+
+    /** A parking spot */
+    public static class Spot {
+        /** The level of the spot */
+        private int level;
+
+        /** A reserved spot */
+        static class Reserved {}
+    }
+}";
+    let mut app = ApplicationDoc::new();
+    for object in parse_types(j_class) {
+        app.add_object(object);
+    }
+    let config = GenConfig::new().nested_types(NestedTypes::Inline);
+
+    let doc = gen_type_page(&app, &app.objects[0], &config);
+    assert!(doc.starts_with("# Class Garage\n"));
+    assert!(doc.contains("## Class Garage.Spot\n\n"));
+    assert!(doc.contains("##### int level\n\n+ Description: The level of the spot  \n"));
+    assert!(doc.contains("### Class Garage.Spot.Reserved\n\n"));
+    assert!(doc.find("## Class Garage.Spot").unwrap() > doc.find("public void park(Spot spot)").unwrap());
+
+    let separate = gen_type_page(&app, &app.objects[0], &GenConfig::new());
+    assert!(!separate.contains("Garage.Spot"));
+
+    let out = env::temp_dir().join("lojidoc-inline-nested-test");
+    let _ = fs::remove_dir_all(&out);
+
+    write_markdown_tree(&app, &out, &config);
+    assert!(out.join("com/example/Garage.md").exists());
+    assert!(!out.join("com/example/Garage.Spot.md").exists());
+    let index = fs::read_to_string(out.join("index.md")).unwrap();
+    assert!(!index.contains("Garage.Spot"));
+    assert!(fs::read_to_string(out.join("com/example/Garage.md")).unwrap().contains("[Spot](Garage.md)"));
+
+    fs::remove_dir_all(&out).unwrap();
+}
+
+#[test]
+fn test_line_numbers() {
+    let j_class = "public class Counter {
+    /** The current count */
+    private int value;
+
+    /** Gets the current count */
+    public int getValue() {
+        return value;
+    }
+}";
+    let class = parse_str(j_class).unwrap();
+
+    let config = GenConfig::new().with_line_numbers(true).with_toc(true);
+    let doc = to_markdown_with_config(&class, &config);
+    assert!(doc.contains("#### int value (line 3)\n\n"));
+    assert!(doc.contains("### getValue (line 6)\n\n"));
+    assert!(doc.contains("  - [getValue](#getvalue-line-6)\n"));
+    assert!(to_html(&class).contains("<h3 id=\"getvalue\">getValue</h3>\n"));
+    assert!(!to_markdown(&class).contains("(line"));
+
+    let class = match class {
+        ObjectType::Class(mut c) => {
+            c.ch_file_path("src/Counter.java".to_string());
+            ObjectType::Class(c)
+        }
+        _ => panic!("Expected a class"),
+    };
+    let config = GenConfig::new().line_url("https://example.com/blob/main/{path}#L{line}");
+    let doc = to_markdown_with_config(&class, &config);
+    assert!(doc.contains("### getValue [[src]](https://example.com/blob/main/src/Counter.java#L6)\n\n"));
+    assert!(!doc.contains("(line"));
+}
+
+#[test]
+fn test_member_links_in_directory() {
+    let app = parse_directory(Path::new("tests/fixtures/project"));
+    let out = env::temp_dir().join("lojidoc-member-link-test");
+    let _ = fs::remove_dir_all(&out);
+
+    write_markdown_tree(&app, &out, &GenConfig::new());
+
+    let service = fs::read_to_string(out.join("com/example/service/UserService.md")).unwrap();
+    let user = fs::read_to_string(out.join("com/example/model/User.md")).unwrap();
+    assert!(service.contains("+ See: [User.getName](../model/User.md#getname)  \n"));
+    // The headings of parsed files end with a source link, so the member name is an explicit anchor
+    assert!(user.contains("<a id=\"getname\"></a>\n\n### getName [[src]]("));
+
+    fs::remove_dir_all(&out).unwrap();
+}
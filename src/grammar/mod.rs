@@ -90,6 +90,20 @@ pub mod grammar {
         ]
     }
 
+    /// Gets the javadoc keywords for custom block tags, e.g. `reviewedBy` or `@threadSafe`,
+    /// which are parsed in addition to the keywords from `get_jdoc_keywords()`
+    ///
+    /// # Arguments
+    ///
+    /// * `tags` - The names of the custom tags, with or without the `@`
+    pub fn get_custom_jdoc_keywords(tags: &[&str]) -> Vec<String> {
+        tags.iter()
+            .map(|tag| tag.trim().trim_start_matches("@"))
+            .filter(|tag| !tag.is_empty())
+            .map(|tag| format!("@{}", tag))
+            .collect()
+    }
+
     pub fn get_spring_keywords<'a>() -> Vec<&'a str> {
         vec![
             "Autowired",
@@ -103,13 +117,15 @@ pub mod grammar {
         ]
     }
 
-    #[derive(Debug, Clone)]
+    #[derive(Debug, Clone, PartialEq)]
     pub enum Stream {
         Import,
         Package,
         Exception,
         Implement,
         Parent,
+        /// The `permits` clause of a sealed class or interface
+        Permit,
         Object(String),
         Access(String),
         Modifier(String),
@@ -117,10 +133,26 @@ pub mod grammar {
         Variable(String),
     }
 
-    #[derive(Clone, Debug)]
+    #[derive(Clone, Debug, PartialEq)]
     pub enum JdocToken {
         Keyword(String),
         Symbol(String),
+        NewLine,
+        /// The indentation of a javadoc line after its leading `*` as written, tabs included,
+        /// before the tokens of the line
+        Indent(String),
+    }
+
+    /// Struct recording the grammar the parser built for a declaration, used to debug
+    /// parse issues
+    #[derive(Clone, Debug)]
+    pub struct DeclarationTrace {
+        pub line_num: String,
+        /// The token ending the declaration: `;`, `{`, or `}`
+        pub end: String,
+        pub gram_parts: Vec<Stream>,
+        /// The tokens of the javadoc comment before the declaration
+        pub doc_tokens: Vec<JdocToken>,
     }
 
     /// Stores the state of javadoc parsing. Each enum field represents a javadoc
@@ -145,6 +177,8 @@ pub mod grammar {
         SerialField,
         Value,
         Version,
+        /// A custom block tag registered when parsing
+        Custom,
     }
 
     /// Struct that represents the parsing state of the high level java declarations
@@ -3,6 +3,10 @@ extern crate colored;
 extern crate mdbook;
 extern crate threadpool;
 extern crate git2;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
 
 mod document;
 mod grammar;
@@ -13,37 +17,56 @@ use mdbook::config::Config;
 use mdbook::MDBook;
 
 use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::prelude::*;
 use std::path::Path;
 use std::path::PathBuf;
+use std::process;
 use std::sync::Arc;
 use threadpool::ThreadPool;
 
 use document::document::find_file_type;
 use document::document::gen_md_book;
 use document::document::generate_markdown;
+use document::document::render_object;
 use document::document::lint_project;
 use document::document::resolve_context;
+use document::document::to_json;
+use model::model::GenConfig;
 use model::model::Options;
 use model::model::ObjectType;
 use model::model::Project;
+use parse::parse::app_stats;
 use parse::parse::parse_file;
+use parse::parse::parse_file_strict;
+use parse::parse::parse_files;
+use parse::parse::parse_str_strict;
+use parse::parse::parse_types;
+use parse::parse::read_file_list;
 
 fn get_project<'a>(files: &Vec<PathBuf>) -> Result<Project, &'a str> {
     let mut project: Project = Project::new();
 
     for file in files {
-        match parse_file(&file, true) {
-            ObjectType::Class(mut class) => {
-                class.ch_file_path(file.to_str().unwrap().to_string());
-                project.add_class(class);
-            }
-            ObjectType::Interface(mut inter) => {
-                inter.ch_file_path(file.to_str().unwrap().to_string());
-                project.add_interface(inter)
-            }
-            ObjectType::Enumeration(mut enumeration) => {
-                enumeration.ch_file_path(file.to_str().unwrap().to_string());
-                project.add_enumeration(enumeration);
+        for object in parse_file(&file) {
+            match object {
+                ObjectType::Class(mut class) => {
+                    class.ch_file_path(file.to_str().unwrap().to_string());
+                    project.add_class(class);
+                }
+                ObjectType::Interface(mut inter) => {
+                    inter.ch_file_path(file.to_str().unwrap().to_string());
+                    project.add_interface(inter)
+                }
+                ObjectType::Enumeration(mut enumeration) => {
+                    enumeration.ch_file_path(file.to_str().unwrap().to_string());
+                    project.add_enumeration(enumeration);
+                }
+                ObjectType::Annotation(mut annotation) => {
+                    annotation.ch_file_path(file.to_str().unwrap().to_string());
+                    project.add_annotation(annotation);
+                }
             }
         }
     }
@@ -65,7 +88,8 @@ pub fn document_single(file_paths: Vec<PathBuf>, options: Options) {
         println!("{}", lint_project(get_project(&file_paths).unwrap()));
     }
 
-    generate_markdown(get_project(&file_paths).unwrap(), options);
+    let config = GenConfig::from_options(&options);
+    generate_markdown(get_project(&file_paths).unwrap(), options, &config);
 
     println!(
         "\nDocumentation finished. Generated {} markdown files.",
@@ -103,18 +127,24 @@ pub fn document(file_paths: Vec<PathBuf>, options: Options) {
                     let mut file = file_cp[(i * 4) + j].clone();
                     let m_context = resolve_context(&file);
 
-                    match parse_file(&file, options_cp.verbose.clone()) {
-                        ObjectType::Class(mut class) => {
-                            class.ch_file_path(m_context);
-                            project.add_class(class.clone());
-                        }
-                        ObjectType::Interface(mut inter) => {
-                            inter.ch_file_path(m_context);
-                            project.add_interface(inter.clone());
-                        }
-                        ObjectType::Enumeration(mut enumeration) => {
-                            enumeration.ch_file_path(m_context);
-                            project.add_enumeration(enumeration.clone());
+                    for object in parse_file(&file) {
+                        match object {
+                            ObjectType::Class(mut class) => {
+                                class.ch_file_path(m_context.clone());
+                                project.add_class(class.clone());
+                            }
+                            ObjectType::Interface(mut inter) => {
+                                inter.ch_file_path(m_context.clone());
+                                project.add_interface(inter.clone());
+                            }
+                            ObjectType::Enumeration(mut enumeration) => {
+                                enumeration.ch_file_path(m_context.clone());
+                                project.add_enumeration(enumeration.clone());
+                            }
+                            ObjectType::Annotation(mut annotation) => {
+                                annotation.ch_file_path(m_context.clone());
+                                project.add_annotation(annotation.clone());
+                            }
                         }
                     }
                 }
@@ -125,7 +155,8 @@ pub fn document(file_paths: Vec<PathBuf>, options: Options) {
                 Err(err) => panic!(err),
             };
 
-            generate_markdown(project, opts_deref);
+            let config = GenConfig::from_options(&opts_deref);
+            generate_markdown(project, opts_deref, &config);
         });
     }
 
@@ -137,10 +168,97 @@ pub fn document(file_paths: Vec<PathBuf>, options: Options) {
     );
 }
 
+/// Parses the whole project and writes the model to `application.json`
+/// in the destination directory
+///
+/// # Arguments
+///
+/// * `files` - The java files of the project
+/// * `options` - The options containing the destination directory
+pub fn document_json(files: &[PathBuf], options: &Options) {
+    let app = parse_files(files);
+    let mut file = File::create(format!("{}/application.json", options.dest))
+        .expect("Unable to create file for the JSON documentation");
+    file.write_all(to_json(&app).as_bytes())
+        .expect("Not able to write to file");
+
+    println!("\nDocumentation finished. Generated application.json");
+}
+
+/// Parses every java file with the strict parser and prints the first unsupported
+/// construct of each file that has one. Returns whether every file was fully parsed.
+///
+/// # Arguments
+///
+/// * `files` - The java files of the project
+pub fn check_strict(files: &[PathBuf]) -> bool {
+    let mut parsed = true;
+
+    for file in files {
+        if let Err(err) = parse_file_strict(file) {
+            eprintln!("{}: {}", file.display(), err);
+            parsed = false;
+        }
+    }
+
+    parsed
+}
+
+/// Reads a single java file from stdin and writes its documentation to stdout
+///
+/// # Arguments
+///
+/// * `config` - The generation settings
+/// * `strict` - Whether to exit with an error for a construct that isn't supported
+pub fn document_stdin(config: &GenConfig, strict: bool) {
+    let mut source = String::new();
+
+    io::stdin()
+        .read_to_string(&mut source)
+        .expect("Unable to read the java source from stdin");
+
+    let objects = if strict {
+        match parse_str_strict(&source) {
+            Ok(objects) => objects,
+            Err(err) => {
+                eprintln!("{}", err);
+                process::exit(1);
+            }
+        }
+    } else {
+        parse_types(&source)
+    };
+    for object in objects {
+        print!("{}", render_object(&object, config));
+    }
+}
+
 fn main() {
     let options = Options::get_options();
-    let file_paths = find_file_type(Path::new(options.dir.clone().as_str()), vec!["java"]);
+    let config = GenConfig::from_options(&options);
+
+    if options.dir.is_empty() {
+        document_stdin(&config, options.strict);
+        return;
+    }
 
+    // An input starting with `@` names a file listing the java files to document
+    let file_paths = if options.dir.starts_with("@") {
+        read_file_list(Path::new(&options.dir[1..]))
+    } else {
+        let mut file_paths = find_file_type(Path::new(options.dir.clone().as_str()), vec!["java"]);
+        file_paths.sort();
+        file_paths
+    };
+
+    if options.strict && !check_strict(&file_paths) {
+        process::exit(1);
+    }
+
+    if options.stats {
+        print!("{}", app_stats(&parse_files(&file_paths)).summary());
+        return;
+    }
 
     fs::create_dir_all(options.dest.as_str()).expect("File path not able to be created");
     println!("\nGenerating documentation from {}\n", options.dir);
@@ -157,9 +275,11 @@ fn main() {
             }
         }
 
-        if options.multi_thread {
+        if options.json {
+            document_json(&file_paths, &options);
+        } else if options.multi_thread {
             document(file_paths, options.clone());
-        } else if options.lint {
+        } else if config.lint {
             println!("{}", lint_project(get_project(&file_paths).unwrap()));
         } else {
             document_single(file_paths, options.clone());
@@ -0,0 +1,52 @@
+use serde::Serialize;
+use serde::Serializer;
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// Enum representing the access level of a java field or method, ordered from the
+/// least to the most visible. Members without an access modifier are package private.
+pub enum Access {
+    Private,
+    Package,
+    Protected,
+    Public,
+}
+
+impl Access {
+    /// Gets the access level from an access modifier
+    ///
+    /// # Arguments
+    ///
+    /// * `modifier` - The access modifier, empty for package private
+    pub fn from_modifier(modifier: &str) -> Access {
+        match modifier.trim() {
+            "public" => Access::Public,
+            "protected" => Access::Protected,
+            "private" => Access::Private,
+            _ => Access::Package,
+        }
+    }
+
+    /// The access modifier of the access level, `package` for package private
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Access::Public => "public",
+            Access::Protected => "protected",
+            Access::Package => "package",
+            Access::Private => "private",
+        }
+    }
+
+    /// The access level as shown in the generated documentation
+    pub fn label(&self) -> &'static str {
+        match *self {
+            Access::Package => "package-private",
+            _ => self.as_str(),
+        }
+    }
+}
+
+impl Serialize for Access {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
@@ -0,0 +1,21 @@
+#[derive(Debug, Clone, Serialize)]
+/// Struct representing an annotation on a member, e.g. `@SuppressWarnings("unchecked")`
+pub struct Annotation {
+    pub name: String,
+    pub args: String,
+}
+
+impl Annotation {
+    pub fn new(name: String) -> Annotation {
+        Annotation {
+            name,
+            args: String::new(),
+        }
+    }
+    pub fn add_arg(&mut self, value: &str) {
+        if !self.args.is_empty() && value != "," && !self.args.ends_with("(") && value != ")" {
+            self.args.push(' ');
+        }
+        self.args.push_str(value);
+    }
+}
@@ -0,0 +1,52 @@
+use model::annotation::Annotation;
+use model::import::Import;
+use model::member::Member;
+
+#[derive(Debug, Serialize)]
+/// Struct containing the documentation of an annotation type declared with
+/// `@interface`. Its elements, e.g. `String value() default "";`, are kept as members
+/// with their default values.
+pub struct AnnotationType {
+    pub package_name: String,
+    pub signature: String,
+    pub deprecation: Option<String>,
+    pub access: String,
+    pub file_path: String,
+    pub line_num: String,
+    pub version: String,
+    pub since: String,
+    pub author: String,
+    pub name: String,
+    pub description: String,
+    pub dependencies: Vec<String>,
+    pub imports: Vec<Import>,
+    pub annotations: Vec<Annotation>,
+    pub custom_tags: Vec<(String, String)>,
+    pub elements: Vec<Member>,
+}
+
+impl AnnotationType {
+    pub fn clone(&self) -> AnnotationType {
+        AnnotationType {
+            package_name: self.package_name.clone(),
+            signature: self.signature.clone(),
+            deprecation: self.deprecation.clone(),
+            access: self.access.clone(),
+            file_path: self.file_path.clone(),
+            line_num: self.line_num.clone(),
+            version: self.version.clone(),
+            since: self.since.clone(),
+            author: self.author.clone(),
+            name: self.name.clone(),
+            description: self.description.clone(),
+            dependencies: self.dependencies.clone(),
+            imports: self.imports.clone(),
+            annotations: self.annotations.clone(),
+            custom_tags: self.custom_tags.clone(),
+            elements: self.elements.iter().map(|e| e.clone()).collect(),
+        }
+    }
+    pub fn ch_file_path(&mut self, value: String) {
+        self.file_path = value;
+    }
+}
@@ -1,25 +1,42 @@
+use model::annotation::Annotation;
+use model::doc::SerialField;
 use model::exception::Exception;
+use model::import::Import;
 use model::member::Member;
 use model::method::Method;
+use model::method::Param;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 /// Struct containing class documentation information
 /// Includes package name, imports, methods, and other data
 pub struct Class {
     pub file_path: String,
+    pub line_num: String,
     pub signature: String,
     pub package_name: String,
-    pub deprecation: String,
+    pub deprecation: Option<String>,
     pub license: String,
     pub parent: String,
     pub access: String,
     pub version: String,
+    pub since: String,
     pub author: String,
     pub name: String,
     pub description: String,
+    pub type_params: Vec<String>,
     pub exceptions: Vec<Exception>,
+    pub serial_data: String,
+    pub serial_fields: Vec<SerialField>,
     pub interfaces: Vec<String>,
+    /// The subclasses permitted to extend a sealed class
+    pub permits: Vec<String>,
+    pub annotations: Vec<Annotation>,
+    pub custom_tags: Vec<(String, String)>,
+    /// Whether the class is a record, declared with its components
+    pub record: bool,
+    pub components: Vec<Param>,
     pub dependencies: Vec<String>,
+    pub imports: Vec<Import>,
     pub modifiers: Vec<String>,
     pub methods: Vec<Method>,
     pub variables: Vec<Member>,
@@ -30,16 +47,27 @@ impl Class {
         Class {
             package_name: String::new(),
             file_path: String::new(),
+            line_num: String::new(),
             signature: String::new(),
             dependencies: Vec::new(),
-            deprecation: String::new(),
+            imports: Vec::new(),
+            deprecation: None,
             license: String::new(),
             parent: String::new(),
             interfaces: Vec::new(),
+            permits: Vec::new(),
+            annotations: Vec::new(),
+            custom_tags: Vec::new(),
+            record: false,
+            components: Vec::new(),
             access: String::new(),
             version: String::new(),
+            since: String::new(),
+            serial_data: String::new(),
+            serial_fields: Vec::new(),
             author: String::new(),
             name: String::new(),
+            type_params: Vec::new(),
             exceptions: Vec::new(),
             description: String::new(),
             modifiers: Vec::new(),
@@ -69,18 +97,29 @@ impl Class {
         Class {
             parent: self.parent.clone(),
             file_path: self.file_path.clone(),
+            line_num: self.line_num.clone(),
             signature: self.signature.clone(),
             package_name: self.package_name.clone(),
             license: self.license.clone(),
             dependencies: self.dependencies.clone(),
+            imports: self.imports.clone(),
             deprecation: self.deprecation.clone(),
             access: self.access.clone(),
             version: self.version.clone(),
+            since: self.since.clone(),
+            serial_data: self.serial_data.clone(),
+            serial_fields: self.serial_fields.clone(),
             author: self.author.clone(),
             name: self.name.clone(),
             description: self.description.clone(),
+            type_params: self.type_params.clone(),
             exceptions: new_except,
             interfaces: self.interfaces.clone(),
+            permits: self.permits.clone(),
+            annotations: self.annotations.clone(),
+            custom_tags: self.custom_tags.clone(),
+            record: self.record,
+            components: self.components.clone(),
             modifiers: new_mods,
             variables: new_vars,
             methods: new_methods,
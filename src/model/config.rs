@@ -0,0 +1,423 @@
+use document::document::compare_versions;
+use model::access::Access;
+use model::annotation::Annotation;
+use model::links::TypeLinks;
+use model::options::Options;
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Enum representing the formats a parsed java type can be rendered to
+pub enum OutputFormat {
+    Markdown,
+    Html,
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Enum representing where the license header of a java file is rendered
+pub enum LicenseStyle {
+    /// A collapsed `<details>` block after the type heading
+    Details,
+    /// An HTML comment, hidden when the markdown is rendered
+    Comment,
+    /// A footer after the member sections
+    Footer,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Enum representing the order the fields and methods of a type are documented in
+pub enum MemberOrder {
+    DeclarationOrder,
+    Alphabetical,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Enum representing where the nested types of a type, e.g. `Outer.Inner`, are documented
+pub enum NestedTypes {
+    /// A page for each nested type, next to the page of the enclosing type
+    Separate,
+    /// Subsections of the enclosing type's page, one heading level deeper
+    Inline,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// Enum representing the visibility of a java member, ordered from the least to
+/// the most visible. Members without an access modifier are package private.
+pub enum Visibility {
+    Private,
+    Package,
+    Protected,
+    Public,
+}
+
+impl Visibility {
+    /// Gets the visibility of a member from its access level
+    ///
+    /// # Arguments
+    ///
+    /// * `access` - The access level of the member
+    pub fn from_access(access: Access) -> Visibility {
+        match access {
+            Access::Public => Visibility::Public,
+            Access::Protected => Visibility::Protected,
+            Access::Package => Visibility::Package,
+            Access::Private => Visibility::Private,
+        }
+    }
+
+    /// Parses a visibility threshold such as `protected` or `package`
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the visibility
+    pub fn from_name(name: &str) -> Option<Visibility> {
+        match name.trim() {
+            "public" => Some(Visibility::Public),
+            "protected" => Some(Visibility::Protected),
+            "package" => Some(Visibility::Package),
+            "private" => Some(Visibility::Private),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Struct for the settings used when generating documentation. Built with
+/// `GenConfig::new()` followed by the builder methods, e.g.
+/// `GenConfig::new().include_private(false).with_toc(true)`
+pub struct GenConfig {
+    pub lint: bool,
+    pub toc: bool,
+    pub method_summary: bool,
+    pub since_column: bool,
+    pub frontmatter: bool,
+    pub sort_by_version: bool,
+    pub include_private: bool,
+    pub heading_offset: usize,
+    pub ignore: String,
+    pub min_visibility: Visibility,
+    pub implicit_public: bool,
+    pub exclude_annotations: Vec<String>,
+    pub since_threshold: Option<String>,
+    pub license_style: LicenseStyle,
+    pub metadata_separator: String,
+    pub project_title: String,
+    pub base_url: String,
+    pub member_order: MemberOrder,
+    pub nested_types: NestedTypes,
+    pub line_numbers: bool,
+    pub line_url: String,
+    pub anchor_prefix: String,
+    pub links: Option<TypeLinks>,
+    pub format: OutputFormat,
+}
+
+impl GenConfig {
+    /// Creates a config matching the default behavior of Lojidoc: every member is
+    /// documented as markdown without a table of contents or heading offset
+    pub fn new() -> GenConfig {
+        GenConfig {
+            lint: false,
+            toc: false,
+            method_summary: false,
+            since_column: false,
+            frontmatter: false,
+            sort_by_version: false,
+            include_private: true,
+            heading_offset: 0,
+            ignore: String::new(),
+            min_visibility: Visibility::Private,
+            implicit_public: false,
+            exclude_annotations: Vec::new(),
+            since_threshold: None,
+            license_style: LicenseStyle::Details,
+            metadata_separator: " \u{b7} ".to_string(),
+            project_title: String::new(),
+            base_url: String::new(),
+            member_order: MemberOrder::DeclarationOrder,
+            nested_types: NestedTypes::Separate,
+            line_numbers: false,
+            line_url: String::new(),
+            anchor_prefix: String::new(),
+            links: None,
+            format: OutputFormat::Markdown,
+        }
+    }
+
+    /// Creates a config from the command line options
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The options passed to Lojidoc
+    pub fn from_options(options: &Options) -> GenConfig {
+        GenConfig::new()
+            .with_lint(options.lint)
+            .with_toc(options.toc)
+            .heading_offset(options.heading_offset)
+            .ignore(options.ignore.as_str())
+            .min_visibility(options.visibility)
+            .format(if options.json {
+                OutputFormat::Json
+            } else {
+                OutputFormat::Markdown
+            })
+    }
+
+    pub fn with_lint(mut self, lint: bool) -> GenConfig {
+        self.lint = lint;
+        self
+    }
+    pub fn with_toc(mut self, toc: bool) -> GenConfig {
+        self.toc = toc;
+        self
+    }
+    /// Adds a summary table of the methods of a type before the member sections
+    ///
+    /// # Arguments
+    ///
+    /// * `method_summary` - Whether the summary table is generated
+    /// * `since_column` - Whether the table has a column with the `@since` version of each method
+    pub fn with_method_summary(mut self, method_summary: bool, since_column: bool) -> GenConfig {
+        self.method_summary = method_summary;
+        self.since_column = since_column;
+        self
+    }
+    pub fn with_frontmatter(mut self, frontmatter: bool) -> GenConfig {
+        self.frontmatter = frontmatter;
+        self
+    }
+    pub fn sort_by_version(mut self, sort_by_version: bool) -> GenConfig {
+        self.sort_by_version = sort_by_version;
+        self
+    }
+    pub fn include_private(mut self, include_private: bool) -> GenConfig {
+        self.include_private = include_private;
+        self
+    }
+    pub fn heading_offset(mut self, heading_offset: usize) -> GenConfig {
+        self.heading_offset = heading_offset;
+        self
+    }
+    pub fn ignore(mut self, ignore: &str) -> GenConfig {
+        self.ignore = ignore.to_string();
+        self
+    }
+    pub fn min_visibility(mut self, min_visibility: Visibility) -> GenConfig {
+        self.min_visibility = min_visibility;
+        self
+    }
+    /// Leaves members annotated with any of the given annotations, e.g. `Internal` or
+    /// `@VisibleForTesting`, out of the documentation
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - The names of the annotations, with or without the `@`
+    pub fn exclude_annotations(mut self, names: &[&str]) -> GenConfig {
+        for name in names {
+            self.exclude_annotations.push(name.trim().trim_start_matches("@").to_string());
+        }
+        self
+    }
+    /// Documents only the members added in or after a version, e.g. for release notes.
+    /// Members without `@since` are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The lowest `@since` version documented, compared leniently following semver
+    pub fn since_at_least(mut self, version: &str) -> GenConfig {
+        self.since_threshold = Some(version.trim().to_string());
+        self
+    }
+    pub fn license_style(mut self, license_style: LicenseStyle) -> GenConfig {
+        self.license_style = license_style;
+        self
+    }
+    /// Sets the separator between the authors, version, and since version of a type
+    /// in its metadata line, a middle dot by default
+    ///
+    /// # Arguments
+    ///
+    /// * `separator` - The separator, including any surrounding spaces
+    pub fn metadata_separator(mut self, separator: &str) -> GenConfig {
+        self.metadata_separator = separator.to_string();
+        self
+    }
+    /// Sets the title of the project, used as the heading of the index page
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title of the project, `Package Index` when empty
+    pub fn project_title(mut self, title: &str) -> GenConfig {
+        self.project_title = title.trim().to_string();
+        self
+    }
+    /// Sets the URL the documentation is published at, so the links between types are
+    /// absolute instead of relative to the page
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The URL of the documentation root, e.g. `https://example.com/docs`
+    pub fn base_url(mut self, base_url: &str) -> GenConfig {
+        self.base_url = base_url.trim().to_string();
+        if let Some(ref mut links) = self.links {
+            links.base_url = self.base_url.clone();
+        }
+        self
+    }
+    pub fn member_order(mut self, member_order: MemberOrder) -> GenConfig {
+        self.member_order = member_order;
+        self
+    }
+    pub fn nested_types(mut self, nested_types: NestedTypes) -> GenConfig {
+        self.nested_types = nested_types;
+        self
+    }
+    /// Adds the line each member is declared on to its heading, e.g. `getValue (line 42)`
+    ///
+    /// # Arguments
+    ///
+    /// * `line_numbers` - Whether the headings have line numbers
+    pub fn with_line_numbers(mut self, line_numbers: bool) -> GenConfig {
+        self.line_numbers = line_numbers;
+        self
+    }
+    /// Sets the template of the source links of members, where `{path}` is replaced
+    /// with the source file path and `{line}` with the line of the member
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - The URL template, e.g. `https://example.com/blob/main/{path}#L{line}`
+    pub fn line_url(mut self, template: &str) -> GenConfig {
+        self.line_url = template.trim().to_string();
+        self
+    }
+    /// Prefixes the anchors of the member headings, so the members keep unique anchors
+    /// when several types are documented on one page
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix, usually the anchor of the type
+    pub fn anchor_prefix(mut self, prefix: &str) -> GenConfig {
+        self.anchor_prefix = prefix.to_string();
+        self
+    }
+    pub fn with_links(mut self, mut links: TypeLinks) -> GenConfig {
+        links.base_url = self.base_url.clone();
+        self.links = Some(links);
+        self
+    }
+    pub fn format(mut self, format: OutputFormat) -> GenConfig {
+        self.format = format;
+        self
+    }
+
+    /// Creates a copy of the config for the members of an interface, which are
+    /// public when they have no access modifier
+    pub fn for_interface(&self) -> GenConfig {
+        let mut config = self.clone();
+        config.implicit_public = true;
+        config
+    }
+
+    /// Determines whether a member with the given access level is documented.
+    /// Members below the minimum visibility and members with the ignored
+    /// permission are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `access` - The access level of the member
+    pub fn documents(&self, access: Access) -> bool {
+        let ignore = self.ignored_access();
+        let access = if self.implicit_public && access == Access::Package {
+            Access::Public
+        } else {
+            access
+        };
+
+        Visibility::from_access(access) >= self.min_visibility && (ignore.is_empty() || access.as_str() != ignore)
+    }
+
+    /// Determines whether a field or method is documented. Members left out by
+    /// `documents`, members with an excluded annotation, and members added before the
+    /// `since_at_least` version are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `access` - The access level of the member
+    /// * `annotations` - The annotations on the member
+    /// * `since` - The `@since` version of the member, empty when it has none
+    pub fn documents_member(&self, access: Access, annotations: &[Annotation], since: &str) -> bool {
+        let excluded = annotations.iter().any(|a| {
+            let name = a.name.split(".").last().unwrap_or("");
+            self.exclude_annotations.iter().any(|e| e == name)
+        });
+        let recent = match self.since_threshold {
+            Some(ref threshold) => since.trim() != "" && compare_versions(since.trim(), threshold) != Ordering::Less,
+            None => true,
+        };
+
+        !excluded && recent && self.documents(access)
+    }
+
+    /// Gets the pages of the parsed types referenced in a type expression, by type
+    /// name. Empty when links are not set.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_expr` - The type expression
+    pub fn type_hrefs(&self, type_expr: &str) -> Vec<(String, String)> {
+        match self.links {
+            Some(ref links) => links.type_hrefs(type_expr),
+            None => Vec::new(),
+        }
+    }
+
+    /// Renders a type reference as inline code, linked when links are set
+    ///
+    /// # Arguments
+    ///
+    /// * `type_name` - The type reference
+    pub fn link_code(&self, type_name: &str) -> String {
+        match self.links {
+            Some(ref links) => links.link_code(type_name),
+            None => format!("`{}`", type_name),
+        }
+    }
+
+    /// Links the `{@link}` tags of a description that reference parsed types, when
+    /// links are set
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The javadoc description
+    pub fn link_references(&self, text: &str) -> String {
+        match self.links {
+            Some(ref links) => links.link_references(text),
+            None => text.to_string(),
+        }
+    }
+
+    /// Adds the anchor prefix, if any, to the anchor of a member heading
+    ///
+    /// # Arguments
+    ///
+    /// * `anchor` - The anchor of the member
+    pub fn prefix_anchor(&self, anchor: String) -> String {
+        if self.anchor_prefix.is_empty() {
+            anchor
+        } else {
+            format!("{}-{}", self.anchor_prefix, anchor)
+        }
+    }
+
+    /// The permission of the members left out of the documentation, or an empty
+    /// string when every member is documented. Leaving out private members takes
+    /// precedence over the `ignore` permission.
+    pub fn ignored_access(&self) -> &str {
+        if !self.include_private {
+            "private"
+        } else {
+            self.ignore.as_str()
+        }
+    }
+}
@@ -1,9 +1,12 @@
-#[derive(Clone)]
+use model::model::ObjectType;
+
+#[derive(Clone, Serialize)]
 /// Struct for a java package. stores the name and member files
 pub struct Package {
     pub name: String,
     pub package_path: String,
     pub members: Vec<String>,
+    pub description: String,
 }
 
 impl Package {
@@ -12,6 +15,7 @@ impl Package {
     }
 }
 
+#[derive(Serialize)]
 /// Struct representing all the application data
 pub struct ApplicationDoc {
     pub file_num: i32,
@@ -19,6 +23,10 @@ pub struct ApplicationDoc {
     pub interface_num: i32,
     pub enum_num: i32,
     pub packages: Vec<Package>,
+    pub objects: Vec<ObjectType>,
+    /// The paths of the parsed java files, each counted once in `file_num`
+    #[serde(skip)]
+    pub files: Vec<String>,
 }
 
 impl ApplicationDoc {
@@ -29,6 +37,8 @@ impl ApplicationDoc {
             class_num: 0,
             interface_num: 0,
             packages: Vec::new(),
+            objects: Vec::new(),
+            files: Vec::new(),
         }
     }
     pub fn add_package_class(&mut self, package: String, dir: String, class: String) {
@@ -46,7 +56,133 @@ impl ApplicationDoc {
                 name: package,
                 package_path: dir,
                 members: vec![class],
+                description: String::new(),
             });
         }
     }
+    /// Sets the description of a package from its `package-info.java` file, adding
+    /// the package when none of its types have been added yet
+    ///
+    /// # Arguments
+    ///
+    /// * `package` - The package parsed from the `package-info.java` file
+    pub fn add_package_info(&mut self, package: Package) {
+        match self.packages.iter_mut().find(|p| p.name == package.name) {
+            Some(existing) => existing.description = package.description,
+            None => self.packages.push(package),
+        }
+    }
+    /// Counts a parsed java file unless a file with the path was counted before. A file
+    /// can declare several types, so the files are counted apart from the types.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the java file
+    pub fn add_file(&mut self, path: &str) {
+        if !self.files.iter().any(|f| f == path) {
+            self.files.push(path.to_string());
+            self.file_num += 1;
+        }
+    }
+    /// Adds a parsed type to the application, updating the type counts and
+    /// registering the type with its package
+    ///
+    /// # Arguments
+    ///
+    /// * `object` - The parsed class, interface, or enumeration
+    pub fn add_object(&mut self, object: ObjectType) {
+        let (package, name) = match object {
+            ObjectType::Class(ref class) => {
+                self.class_num += 1;
+                (class.package_name.clone(), class.name.clone())
+            }
+            ObjectType::Interface(ref inter) => {
+                self.interface_num += 1;
+                (inter.package_name.clone(), inter.name.clone())
+            }
+            ObjectType::Enumeration(ref enumeration) => {
+                self.enum_num += 1;
+                (enumeration.package_name.clone(), enumeration.name.clone())
+            }
+            ObjectType::Annotation(ref annotation) => {
+                self.interface_num += 1;
+                (annotation.package_name.clone(), annotation.name.clone())
+            }
+        };
+
+        self.add_package_class(package.clone(), package.replace(".", "/"), name);
+        self.objects.push(object);
+    }
+    /// Finds a parsed type by its simple name, e.g. `Foo`, or its fully qualified
+    /// name, e.g. `com.example.Foo`. A nested type is also found by the name with its
+    /// enclosing types, e.g. `Outer.Inner`. When several packages declare a type with
+    /// the same simple name the first one added is found.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The simple or fully qualified name of the type
+    pub fn find_class(&self, name: &str) -> Option<&ObjectType> {
+        let query = name.trim();
+
+        self.objects.iter().find(|object| {
+            let (package, name) = object.package_and_name();
+            let simple_name = name.rsplit(".").next().unwrap_or(name);
+
+            name == query || simple_name == query || (!package.is_empty() && format!("{}.{}", package, name) == query)
+        })
+    }
+    /// Determines whether a type with the simple or fully qualified name was parsed
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The simple or fully qualified name of the type
+    pub fn contains(&self, name: &str) -> bool {
+        self.find_class(name).is_some()
+    }
+    /// Merges the application parsed from another module into this one, e.g. to
+    /// generate a combined site for several Gradle subprojects. The members of packages
+    /// with the same name are combined and a type already in the package is kept once.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The application to merge into this one
+    pub fn merge(&mut self, other: ApplicationDoc) {
+        for path in &other.files {
+            self.add_file(path);
+        }
+        self.class_num += other.class_num;
+        self.interface_num += other.interface_num;
+        self.enum_num += other.enum_num;
+
+        for package in other.packages {
+            match self.packages.iter_mut().find(|p| p.name == package.name) {
+                Some(existing) => {
+                    for member in package.members {
+                        if !existing.members.contains(&member) {
+                            existing.members.push(member);
+                        }
+                    }
+                    if existing.description.is_empty() {
+                        existing.description = package.description;
+                    }
+                }
+                None => self.packages.push(package),
+            }
+        }
+
+        for object in other.objects {
+            let duplicate = self.objects.iter().any(|o| o.package_and_name() == object.package_and_name());
+
+            if duplicate {
+                match object {
+                    ObjectType::Class(_) => self.class_num -= 1,
+                    ObjectType::Interface(_) | ObjectType::Annotation(_) => self.interface_num -= 1,
+                    ObjectType::Enumeration(_) => self.enum_num -= 1,
+                }
+            } else {
+                self.objects.push(object);
+            }
+        }
+    }
 }
+
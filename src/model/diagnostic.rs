@@ -0,0 +1,32 @@
+use model::model::ObjectType;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Struct representing a construct the parser skipped because it is not supported.
+/// `line_num` is `None` when the line is not known, e.g. for javadoc tags.
+pub struct Diagnostic {
+    pub construct: String,
+    pub line_num: Option<String>,
+}
+
+impl Diagnostic {
+    pub fn new(construct: String, line_num: Option<String>) -> Diagnostic {
+        Diagnostic {
+            construct,
+            line_num,
+        }
+    }
+    /// Describes the skipped construct in a human readable form
+    pub fn message(&self) -> String {
+        match self.line_num {
+            Some(ref line_num) => format!("Unsupported {} on line {}", self.construct, line_num),
+            None => format!("Unsupported {}", self.construct),
+        }
+    }
+}
+
+/// Struct holding the types parsed from a java file, in declaration order, along
+/// with the diagnostics collected while parsing them
+pub struct ParseOutcome {
+    pub objects: Vec<ObjectType>,
+    pub diagnostics: Vec<Diagnostic>,
+}
@@ -1,17 +1,178 @@
 use model::exception::Exception;
 use model::method::Param;
 
+#[derive(Debug, Clone, Serialize)]
+/// Struct representing a `@serialField` entry of a serializable class
+pub struct SerialField {
+    pub name: String,
+    pub field_type: String,
+    pub desc: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+/// Enum representing a piece of a javadoc description. `{@code}` content that
+/// spans several lines is a `CodeBlock` and a `Link` holds the text shown for a
+/// `{@link}` tag.
+pub enum DocSpan {
+    Text(String),
+    Code(String),
+    CodeBlock(String),
+    Literal(String),
+    Link(String),
+}
+
+impl DocSpan {
+    /// Splits a javadoc description into plain text and `{@code}`, `{@literal}`, and
+    /// `{@link}` spans. Braces inside the tags must be balanced.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The javadoc description
+    pub fn split(text: &str) -> Vec<DocSpan> {
+        let mut spans = Vec::new();
+        let mut rest = text;
+
+        loop {
+            let start = match ["{@code", "{@literal", "{@link"].iter().filter_map(|tag| rest.find(tag)).min() {
+                Some(start) => start,
+                None => break,
+            };
+            let mut depth = 0;
+            let mut end = None;
+
+            for (i, ch) in rest[start..].char_indices() {
+                if ch == '{' {
+                    depth += 1;
+                } else if ch == '}' {
+                    depth -= 1;
+                    if depth == 0 {
+                        end = Some(start + i);
+                        break;
+                    }
+                }
+            }
+
+            let end = match end {
+                Some(end) => end,
+                None => break,
+            };
+
+            if start > 0 {
+                spans.push(DocSpan::Text(rest[..start].to_string()));
+            }
+
+            let tag = &rest[start + 1..end];
+            if tag.starts_with("@literal") {
+                spans.push(DocSpan::Literal(tag["@literal".len()..].trim().to_string()));
+            } else if tag.starts_with("@link") {
+                let mut parts = tag.splitn(3, char::is_whitespace).skip(1);
+                let target = parts.next().unwrap_or("").trim();
+                let label = parts.next().unwrap_or("").trim();
+
+                if !label.is_empty() {
+                    spans.push(DocSpan::Link(label.to_string()));
+                } else {
+                    spans.push(DocSpan::Link(target.trim_start_matches("#").replace("#", ".")));
+                }
+            } else {
+                let code = &tag["@code".len()..];
+
+                if code.contains("\n") {
+                    spans.push(DocSpan::CodeBlock(dedent_code(code)));
+                } else {
+                    spans.push(DocSpan::Code(code.trim().to_string()));
+                }
+            }
+
+            rest = &rest[end + 1..];
+        }
+
+        if !rest.is_empty() {
+            spans.push(DocSpan::Text(rest.to_string()));
+        }
+
+        spans
+    }
+}
+
+/// Removes the indentation shared by the lines of a multi-line `{@code}` tag, keeping
+/// the indentation of each line relative to the others. Text on the line of the tag
+/// itself has no known indentation and is left out when finding the shared one. Only
+/// the whitespace the lines have in common is removed, so mixed tabs and spaces keep
+/// their alignment.
+///
+/// # Arguments
+///
+/// * `code` - The content of the `{@code}` tag
+fn dedent_code(code: &str) -> String {
+    let mut lines: Vec<&str> = code.split("\n").map(|line| line.trim_end()).collect();
+    let first = lines.remove(0).trim();
+    let mut shared: Option<&str> = None;
+
+    for line in lines.iter().filter(|line| line.trim() != "") {
+        let leading = &line[..line.len() - line.trim_start().len()];
+
+        shared = Some(match shared {
+            Some(prefix) => {
+                let common = prefix
+                    .char_indices()
+                    .zip(leading.chars())
+                    .take_while(|&((_, a), b)| a == b)
+                    .last()
+                    .map_or(0, |((i, a), _)| i + a.len_utf8());
+                &prefix[..common]
+            }
+            None => leading,
+        });
+    }
+    let indent = shared.map_or(0, |prefix| prefix.len());
+
+    let mut dedented: Vec<&str> = Vec::new();
+    if !first.is_empty() {
+        dedented.push(first);
+    }
+    for line in lines {
+        dedented.push(if line.len() >= indent { &line[indent..] } else { "" });
+    }
+
+    while dedented.last().map_or(false, |line| line.trim() == "") {
+        dedented.pop();
+    }
+    while dedented.first().map_or(false, |line| line.trim() == "") {
+        dedented.remove(0);
+    }
+
+    dedented.join("\n")
+}
+
+#[derive(Debug, Clone, Copy)]
+/// Struct borrowing the javadoc details every kind of type has
+pub struct TypeDoc<'a> {
+    pub description: &'a str,
+    pub author: &'a str,
+    pub since: &'a str,
+    pub version: &'a str,
+    pub custom_tags: &'a [(String, String)],
+}
+
 /// Struct representing data contained in javadoc comments
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct Doc {
     pub params: Vec<Param>,
     pub description: String,
+    pub spans: Vec<DocSpan>,
     pub author: String,
     pub version: String,
+    pub since: String,
     pub exceptions: Vec<Exception>,
-    pub deprecated: String,
+    /// The `@deprecated` message, empty for a bare tag and `None` without the tag
+    pub deprecated: Option<String>,
     pub return_desc: String,
     pub see: String,
+    pub serial_data: String,
+    pub serial_fields: Vec<SerialField>,
+    /// The custom block tags registered when parsing, e.g. `@reviewedBy`, with their text
+    pub custom_tags: Vec<(String, String)>,
 }
 
 impl Doc {
@@ -19,12 +180,17 @@ impl Doc {
         Doc {
             params: Vec::new(),
             description: String::new(),
+            spans: Vec::new(),
             return_desc: String::new(),
             author: String::new(),
             version: String::new(),
+            since: String::new(),
             exceptions: Vec::new(),
-            deprecated: String::new(),
+            deprecated: None,
             see: String::new(),
+            serial_data: String::new(),
+            serial_fields: Vec::new(),
+            custom_tags: Vec::new(),
         }
     }
 }
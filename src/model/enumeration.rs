@@ -1,11 +1,16 @@
+use model::annotation::Annotation;
 use model::exception::Exception;
+use model::import::Import;
 use model::member::Member;
 use model::method::Method;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct EnumField {
     pub name: String,
+    /// The ordinal of the constant
     pub value: String,
+    /// The arguments passed to the enum constructor, e.g. `3.3e23, 2.4e6`
+    pub args: String,
 }
 
 impl EnumField {
@@ -13,26 +18,32 @@ impl EnumField {
         EnumField {
             name: self.name.clone(),
             value: self.value.clone(),
+            args: self.args.clone(),
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 /// Struct containing enumeration documentation information
 /// Includes package name, imports, methods, and other data
 pub struct Enumeration {
     pub file_path: String,
+    pub line_num: String,
     pub package_name: String,
-    pub deprecation: String,
+    pub deprecation: Option<String>,
     pub license: String,
     pub access: String,
     pub version: String,
+    pub since: String,
     pub author: String,
     pub name: String,
     pub description: String,
     pub exceptions: Vec<Exception>,
     pub interfaces: Vec<String>,
+    pub annotations: Vec<Annotation>,
+    pub custom_tags: Vec<(String, String)>,
     pub dependencies: Vec<String>,
+    pub imports: Vec<Import>,
     pub modifiers: Vec<String>,
     pub fields: Vec<EnumField>,
     pub methods: Vec<Method>,
@@ -65,17 +76,22 @@ impl Enumeration {
 
         Enumeration {
             file_path: self.file_path.clone(),
+            line_num: self.line_num.clone(),
             package_name: self.package_name.clone(),
             license: self.license.clone(),
             dependencies: self.dependencies.clone(),
+            imports: self.imports.clone(),
             deprecation: self.deprecation.clone(),
             access: self.access.clone(),
             version: self.version.clone(),
+            since: self.since.clone(),
             author: self.author.clone(),
             name: self.name.clone(),
             description: self.description.clone(),
             exceptions: new_except,
             interfaces: self.interfaces.clone(),
+            annotations: self.annotations.clone(),
+            custom_tags: self.custom_tags.clone(),
             modifiers: new_mods,
             fields: new_fields,
             variables: new_vars,
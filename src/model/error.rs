@@ -0,0 +1,42 @@
+use std::fmt;
+
+use model::diagnostic::Diagnostic;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Struct representing a java construct the parser does not support, returned when
+/// parsing in strict mode. `line_num` is empty when the line is not known.
+pub struct ParseError {
+    pub construct: String,
+    pub line_num: String,
+}
+
+impl ParseError {
+    pub fn new(construct: String, line_num: String) -> ParseError {
+        ParseError {
+            construct,
+            line_num,
+        }
+    }
+
+    /// Creates the error for a diagnostic collected while parsing
+    ///
+    /// # Arguments
+    ///
+    /// * `diagnostic` - The diagnostic of the unsupported construct
+    pub fn from_diagnostic(diagnostic: Diagnostic) -> ParseError {
+        ParseError {
+            construct: diagnostic.construct,
+            line_num: diagnostic.line_num.unwrap_or(String::new()),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.line_num.is_empty() {
+            write!(f, "Unsupported {}", self.construct)
+        } else {
+            write!(f, "Unsupported {} on line {}", self.construct, self.line_num)
+        }
+    }
+}
@@ -1,8 +1,11 @@
-#[derive(Debug)]
-/// Struct representing method parameter data contained in javadoc and method declaration
+#[derive(Debug, Serialize)]
+/// Struct representing an exception thrown by a method or type. `tag` holds the
+/// javadoc tag it was documented with, `throws` or `exception`, and is empty when
+/// the exception is only declared.
 pub struct Exception {
     pub exception_type: String,
     pub desc: String,
+    pub tag: String,
 }
 
 impl Exception {
@@ -10,6 +13,7 @@ impl Exception {
         Exception {
             exception_type: self.exception_type.clone(),
             desc: self.desc.clone(),
+            tag: self.tag.clone(),
         }
     }
 }
@@ -0,0 +1,23 @@
+#[derive(Debug, Clone, Serialize)]
+/// Struct representing an import declaration of a java file
+pub struct Import {
+    pub path: String,
+    pub is_static: bool,
+    pub wildcard: bool,
+}
+
+impl Import {
+    /// Creates an import from the imported path, e.g. `java.util.*`
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The imported path as written in the declaration
+    /// * `is_static` - Whether the declaration is an `import static`
+    pub fn new(path: &str, is_static: bool) -> Import {
+        Import {
+            path: path.trim_end_matches(".*").to_string(),
+            is_static,
+            wildcard: path.ends_with(".*"),
+        }
+    }
+}
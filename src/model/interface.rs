@@ -1,20 +1,32 @@
+use model::annotation::Annotation;
+use model::import::Import;
 use model::member::Member;
 use model::method::Method;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 /// Struct containing interface documentation information
 /// Includes package name, imports, method templates, and other data
 pub struct Interface {
     pub package_name: String,
     pub signature: String,
-    pub deprecation: String,
+    pub deprecation: Option<String>,
     pub access: String,
     pub file_path: String,
+    pub line_num: String,
     pub version: String,
+    pub since: String,
     pub author: String,
     pub name: String,
     pub description: String,
+    pub type_params: Vec<String>,
+    /// The interfaces extended by the interface
+    pub parents: Vec<String>,
+    /// The types permitted to implement a sealed interface
+    pub permits: Vec<String>,
+    pub annotations: Vec<Annotation>,
+    pub custom_tags: Vec<(String, String)>,
     pub dependencies: Vec<String>,
+    pub imports: Vec<Import>,
     pub variables: Vec<Member>,
     pub methods: Vec<Method>,
 }
@@ -36,13 +48,21 @@ impl Interface {
             package_name: self.package_name.clone(),
             signature: self.signature.clone(),
             dependencies: self.dependencies.clone(),
+            imports: self.imports.clone(),
             deprecation: self.deprecation.clone(),
             access: self.access.clone(),
             file_path: self.access.clone(),
+            line_num: self.line_num.clone(),
             version: self.version.clone(),
+            since: self.since.clone(),
             author: self.author.clone(),
             name: self.name.clone(),
             description: self.description.clone(),
+            type_params: self.type_params.clone(),
+            parents: self.parents.clone(),
+            permits: self.permits.clone(),
+            annotations: self.annotations.clone(),
+            custom_tags: self.custom_tags.clone(),
             variables: new_variables,
             methods: new_methods,
         }
@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+use std::mem;
+
+use document::document::slugify;
+use model::contents::ApplicationDoc;
+use model::model::ObjectType;
+
+#[derive(Debug, Clone)]
+/// Struct mapping the simple names of the parsed types of an application to their
+/// generated markdown pages, used to link type references between pages
+pub struct TypeLinks {
+    pub pages: HashMap<String, String>,
+    pub current_page: String,
+    /// The URL the pages are published at, links are relative when empty
+    pub base_url: String,
+}
+
+impl TypeLinks {
+    pub fn new() -> TypeLinks {
+        TypeLinks {
+            pages: HashMap::new(),
+            current_page: String::new(),
+            base_url: String::new(),
+        }
+    }
+
+    /// Registers the page of a type unless a type with the same name has one
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The simple name of the type
+    /// * `page` - The path of the type's page, relative to the documentation root
+    pub fn add_page(&mut self, name: &str, page: String) {
+        self.pages.entry(name.to_string()).or_insert(page);
+    }
+
+    /// Creates the links for every type of a parsed application. When two packages
+    /// declare a type with the same name the first one parsed is linked. Nested types
+    /// such as `Outer.Inner` are also linked by their simple name.
+    ///
+    /// # Arguments
+    ///
+    /// * `app` - The parsed application
+    pub fn from_app(app: &ApplicationDoc) -> TypeLinks {
+        let mut links = TypeLinks::new();
+
+        for object in &app.objects {
+            let (package_name, name) = match object {
+                ObjectType::Class(class) => (&class.package_name, &class.name),
+                ObjectType::Interface(inter) => (&inter.package_name, &inter.name),
+                ObjectType::Enumeration(enum_ob) => (&enum_ob.package_name, &enum_ob.name),
+                ObjectType::Annotation(annotation) => (&annotation.package_name, &annotation.name),
+            };
+            let package_path = match app.packages.iter().find(|p| &p.name == package_name) {
+                Some(package) => package.package_path.clone(),
+                None => package_name.replace(".", "/"),
+            };
+
+            let page = page_path(&package_path, name);
+            if let Some(simple_name) = name.rsplit(".").next() {
+                if simple_name != name {
+                    links.add_page(simple_name, page.clone());
+                }
+            }
+            links.add_page(name, page);
+        }
+
+        links
+    }
+
+    /// Creates a copy of the links for the page the links are written to
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The path of the page, relative to the documentation root
+    pub fn for_page(&self, page: &str) -> TypeLinks {
+        TypeLinks {
+            pages: self.pages.clone(),
+            current_page: page.to_string(),
+            base_url: self.base_url.clone(),
+        }
+    }
+
+    /// Points the links to nested types such as `Outer.Inner` at the page of their
+    /// top-level type, for nested types documented inline
+    pub fn inline_nested(&mut self) {
+        for page in self.pages.values_mut() {
+            let start = page.rfind("/").map_or(0, |index| index + 1);
+            let stem = page[start..].trim_end_matches(".md").to_string();
+
+            if let Some(index) = stem.find(".") {
+                *page = format!("{}{}.md", &page[..start], &stem[..index]);
+            }
+        }
+    }
+
+    /// Gets the page of a type. A qualified name such as `com.example.Outer.Inner` is
+    /// matched by its longest suffix with a page, so nested types are found through the
+    /// names of their enclosing types.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_name` - The simple or qualified name of the type
+    fn page(&self, type_name: &str) -> Option<&String> {
+        let parts: Vec<&str> = type_name.split(".").collect();
+
+        (0..parts.len()).filter_map(|i| self.pages.get(&parts[i..].join("."))).next()
+    }
+
+    /// Gets the path of a type's page relative to the current page, or its absolute
+    /// URL when a base URL is set. `None` when the type is not part of the application.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_name` - The simple or qualified name of the type
+    pub fn href(&self, type_name: &str) -> Option<String> {
+        let target = match self.page(type_name) {
+            Some(target) => target,
+            None => return None,
+        };
+
+        if !self.base_url.is_empty() {
+            return Some(format!("{}/{}", self.base_url.trim_end_matches("/"), target));
+        }
+
+        let current: Vec<&str> = self.current_page.split("/").filter(|p| !p.is_empty()).collect();
+        let target_parts: Vec<&str> = target.split("/").filter(|p| !p.is_empty()).collect();
+        let current_dirs = &current[..current.len().saturating_sub(1)];
+        let target_dirs = &target_parts[..target_parts.len() - 1];
+        let common = current_dirs
+            .iter()
+            .zip(target_dirs.iter())
+            .take_while(|&(a, b)| a == b)
+            .count();
+
+        let mut href = "../".repeat(current_dirs.len() - common);
+        for dir in &target_dirs[common..] {
+            href.push_str(dir);
+            href.push('/');
+        }
+        href.push_str(target_parts[target_parts.len() - 1]);
+
+        Some(href)
+    }
+
+    /// Gets the pages of the parsed types referenced in a type expression such as
+    /// `Map<String, Engine>`, by type name. Types that are not part of the application
+    /// are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_expr` - The type expression
+    pub fn type_hrefs(&self, type_expr: &str) -> Vec<(String, String)> {
+        let mut hrefs: Vec<(String, String)> = Vec::new();
+
+        for (word, is_name) in split_type(type_expr) {
+            if !is_name || hrefs.iter().any(|(name, _)| *name == word) {
+                continue;
+            }
+            if let Some(href) = self.href(&word) {
+                hrefs.push((word, href));
+            }
+        }
+
+        hrefs
+    }
+
+    /// Renders a type reference as inline code, linked to its page when the type is
+    /// part of the application
+    ///
+    /// # Arguments
+    ///
+    /// * `type_name` - The type reference, e.g. `Base<T>`
+    pub fn link_code(&self, type_name: &str) -> String {
+        let base = type_name.split("<").next().unwrap_or("").trim();
+
+        match self.href(base) {
+            Some(href) => format!("[`{}`]({})", type_name, href),
+            None => format!("`{}`", type_name),
+        }
+    }
+
+    /// Replaces the `{@link}` and `{@linkplain}` tags of a javadoc description that
+    /// reference a parsed type with markdown links. Other tags are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The javadoc description
+    pub fn link_references(&self, text: &str) -> String {
+        let mut linked = String::new();
+        let mut rest = text;
+
+        while let Some(start) = rest.find("{@link") {
+            let end = match rest[start..].find("}") {
+                Some(end) => start + end,
+                None => break,
+            };
+            let mut parts = rest[start + 2..end].trim().splitn(3, char::is_whitespace);
+            let _tag = parts.next();
+            let target = parts.next().unwrap_or("").trim();
+            let label = parts.next().unwrap_or("").trim();
+            let mut target_parts = target.splitn(2, "#");
+            let type_name = target_parts.next().unwrap_or("");
+            let member = target_parts.next().unwrap_or("");
+
+            linked.push_str(&rest[..start]);
+            match self.href(type_name) {
+                Some(href) if !type_name.is_empty() => {
+                    let label = if !label.is_empty() {
+                        label.to_string()
+                    } else if !member.is_empty() {
+                        format!("{}.{}", type_name, member)
+                    } else {
+                        type_name.to_string()
+                    };
+                    // Member headings carry the slug of the member name as a stable anchor
+                    let anchor = slugify(member.split("(").next().unwrap_or(""));
+
+                    if !anchor.is_empty() {
+                        linked.push_str(format!("[{}]({}#{})", label, href, anchor).as_str());
+                    } else {
+                        linked.push_str(format!("[{}]({})", label, href).as_str());
+                    }
+                }
+                _ => linked.push_str(&rest[start..end + 1]),
+            }
+
+            rest = &rest[end + 1..];
+        }
+        linked.push_str(rest);
+
+        linked
+    }
+}
+
+/// Splits a type expression such as `Map<String, Engine>` into the type names it
+/// references and the text between them. The names are marked `true`.
+///
+/// # Arguments
+///
+/// * `type_expr` - The type expression
+pub fn split_type(type_expr: &str) -> Vec<(String, bool)> {
+    let mut parts = Vec::new();
+    let mut word = String::new();
+    let mut other = String::new();
+
+    for ch in type_expr.chars() {
+        if ch.is_alphanumeric() || ch == '_' || ch == '$' || ch == '.' {
+            if !other.is_empty() {
+                parts.push((mem::take(&mut other), false));
+            }
+            word.push(ch);
+        } else {
+            if !word.is_empty() {
+                parts.push((mem::take(&mut word), true));
+            }
+            other.push(ch);
+        }
+    }
+    if !word.is_empty() {
+        parts.push((word, true));
+    }
+    if !other.is_empty() {
+        parts.push((other, false));
+    }
+
+    parts
+}
+
+/// Gets the path of a type's markdown page relative to the documentation root
+///
+/// # Arguments
+///
+/// * `package_path` - The directory of the type's package
+/// * `name` - The name of the type
+pub fn page_path(package_path: &str, name: &str) -> String {
+    if package_path.is_empty() {
+        format!("{}.md", name)
+    } else {
+        format!("{}/{}.md", package_path.trim_end_matches("/"), name)
+    }
+}
@@ -0,0 +1,38 @@
+#[derive(Debug, Clone, PartialEq, Serialize)]
+/// Enum representing the kinds of javadoc problems found when linting
+pub enum LintKind {
+    MissingClassDescription,
+    MissingMethodDescription,
+    MissingParam,
+    /// A `@param` documenting a name the method doesn't declare
+    UnknownParam,
+    MissingReturn,
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// Struct representing a javadoc problem found in a java type
+pub struct LintWarning {
+    pub kind: LintKind,
+    pub name: String,
+    pub line_num: String,
+}
+
+impl LintWarning {
+    pub fn new(kind: LintKind, name: String, line_num: String) -> LintWarning {
+        LintWarning {
+            kind,
+            name,
+            line_num,
+        }
+    }
+    /// Describes the problem in a human readable form
+    pub fn message(&self) -> String {
+        match self.kind {
+            LintKind::MissingClassDescription => format!("Missing description for type {}", self.name),
+            LintKind::MissingMethodDescription => format!("Missing description for method {}", self.name),
+            LintKind::MissingParam => format!("Javadoc parameter not found {}", self.name),
+            LintKind::UnknownParam => format!("Documented parameter not in the signature {}", self.name),
+            LintKind::MissingReturn => format!("Missing @return for method {}", self.name),
+        }
+    }
+}
@@ -1,13 +1,37 @@
-#[derive(Debug)]
+use model::access::Access;
+use model::annotation::Annotation;
+use model::method::Method;
+
+#[derive(Debug, Clone, Copy)]
+/// Enum referencing a field, constructor, or method of a parsed type
+pub enum MemberRef<'a> {
+    Field(&'a Member),
+    Constructor(&'a Method),
+    Method(&'a Method),
+}
+
+impl<'a> MemberRef<'a> {
+    /// The line the member is declared on
+    pub fn line_num(&self) -> &'a str {
+        match *self {
+            MemberRef::Field(member) => member.line_num.as_str(),
+            MemberRef::Constructor(method) | MemberRef::Method(method) => method.line_num.as_str(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
 /// Struct representing member variable data contained in javadoc and declaration
 pub struct Member {
     pub line_num: String,
     pub signature: String,
     pub desc: String,
     pub modifiers: Vec<String>,
-    pub access: String,
+    pub annotations: Vec<Annotation>,
+    pub access: Access,
     pub name: String,
     pub var_type: String,
+    pub default_value: String,
 }
 
 impl Member {
@@ -16,10 +40,12 @@ impl Member {
             line_num: self.line_num.clone(),
             signature: self.signature.clone(),
             desc: self.desc.clone(),
-            access: self.access.clone(),
+            access: self.access,
             name: self.name.clone(),
             modifiers: self.modifiers.clone(),
+            annotations: self.annotations.clone(),
             var_type: self.var_type.clone(),
+            default_value: self.default_value.clone(),
         }
     }
     pub fn new() -> Member {
@@ -27,24 +53,35 @@ impl Member {
             line_num: String::new(),
             signature: String::new(),
             desc: String::new(),
-            access: String::new(),
+            access: Access::Package,
             name: String::new(),
             modifiers: Vec::new(),
+            annotations: Vec::new(),
             var_type: String::new(),
+            default_value: String::new(),
         }
     }
+    pub fn ch_annotations(&mut self, value: Vec<Annotation>) {
+        self.annotations = value;
+    }
+    pub fn ch_desc(&mut self, value: String) {
+        self.desc = value;
+    }
     pub fn ch_name(&mut self, value: String) {
         self.name = value;
     }
     pub fn ch_signature(&mut self, value: String) {
         self.signature = value;
     }
-    pub fn ch_access(&mut self, value: String) {
+    pub fn ch_access(&mut self, value: Access) {
         self.access = value;
     }
     pub fn ch_type(&mut self, value: String) {
         self.var_type = value;
     }
+    pub fn ch_default_value(&mut self, value: String) {
+        self.default_value = value;
+    }
     pub fn add_modifier(&mut self, value: String) {
         self.modifiers.push(value);
     }
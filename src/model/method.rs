@@ -1,6 +1,8 @@
+use model::access::Access;
+use model::annotation::Annotation;
 use model::exception::Exception;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 /// Struct representing method parameter data contained in javadoc and method declaration
 pub struct Param {
     pub desc: String,
@@ -8,18 +10,29 @@ pub struct Param {
     pub var_type: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 /// Struct containing method data from the javadoc and method declaration
 pub struct Method {
     pub line_num: String,
     pub signature: String,
     pub parameters: Vec<Param>,
     pub modifiers: Vec<String>,
+    pub annotations: Vec<Annotation>,
+    pub custom_tags: Vec<(String, String)>,
     pub name: String,
-    pub privacy: String,
+    pub type_params: Vec<String>,
+    pub type_param_docs: Vec<Param>,
+    /// The names documented with `@param` that the method doesn't declare
+    pub unknown_params: Vec<String>,
+    pub privacy: Access,
     pub description: String,
+    pub deprecation: Option<String>,
+    pub see: String,
+    pub since: String,
     pub exceptions: Vec<Exception>,
     pub return_type: String,
+    pub return_desc: String,
+    pub is_constructor: bool,
 }
 
 impl Method {
@@ -27,13 +40,23 @@ impl Method {
         Method {
             parameters: Vec::new(),
             modifiers: Vec::new(),
+            annotations: Vec::new(),
+            custom_tags: Vec::new(),
             exceptions: Vec::new(),
             line_num: String::new(),
             name: String::new(),
+            type_params: Vec::new(),
+            type_param_docs: Vec::new(),
+            unknown_params: Vec::new(),
             signature: String::new(),
-            privacy: String::new(),
+            privacy: Access::Package,
             description: String::new(),
+            deprecation: None,
+            see: String::new(),
+            since: String::new(),
             return_type: String::new(),
+            return_desc: String::new(),
+            is_constructor: false,
         }
     }
     pub fn clone(&mut self) -> Method {
@@ -55,29 +78,70 @@ impl Method {
             line_num: self.line_num.clone(),
             parameters: new_params,
             modifiers: new_modifiers,
+            annotations: self.annotations.clone(),
+            custom_tags: self.custom_tags.clone(),
             exceptions: new_excepts,
             name: self.name.clone(),
+            type_params: self.type_params.clone(),
+            type_param_docs: self.type_param_docs.clone(),
+            unknown_params: self.unknown_params.clone(),
             signature: self.signature.clone(),
-            privacy: self.privacy.clone(),
+            privacy: self.privacy,
             description: self.description.clone(),
+            deprecation: self.deprecation.clone(),
+            see: self.see.clone(),
+            since: self.since.clone(),
             return_type: self.return_type.clone(),
+            return_desc: self.return_desc.clone(),
+            is_constructor: self.is_constructor,
         }
     }
+    pub fn ch_see(&mut self, value: String) {
+        self.see = value;
+    }
+    pub fn ch_since(&mut self, value: String) {
+        self.since = value;
+    }
+    pub fn ch_is_constructor(&mut self, value: bool) {
+        self.is_constructor = value;
+    }
     pub fn ch_line_num(&mut self, value: String) {
         self.line_num = value;
     }
     pub fn ch_signature(&mut self, value: String) {
         self.signature = value;
     }
-    pub fn ch_privacy(&mut self, value: String) {
+    pub fn ch_privacy(&mut self, value: Access) {
         self.privacy = value;
     }
     pub fn add_modifier(&mut self, value: String) {
         self.modifiers.push(value);
     }
+    pub fn ch_annotations(&mut self, value: Vec<Annotation>) {
+        self.annotations = value;
+    }
+    pub fn ch_custom_tags(&mut self, value: Vec<(String, String)>) {
+        self.custom_tags = value;
+    }
+    pub fn ch_deprecation(&mut self, value: Option<String>) {
+        self.deprecation = value;
+    }
+    /// Whether the method has a `@deprecated` tag or a `@Deprecated` annotation
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecation.is_some() || self.annotations.iter().any(|a| a.name == "Deprecated")
+    }
     pub fn ch_method_name(&mut self, value: String) {
         self.name = value;
     }
+    pub fn ch_type_params(&mut self, value: Vec<String>) {
+        self.type_params = value;
+    }
+    pub fn ch_type_param_docs(&mut self, value: Vec<Param>) {
+        self.type_param_docs = value;
+    }
+    pub fn ch_unknown_params(&mut self, value: Vec<String>) {
+        self.unknown_params = value;
+    }
     pub fn ch_description(&mut self, value: String) {
         self.description = value;
     }
@@ -93,4 +157,7 @@ impl Method {
     pub fn ch_return_type(&mut self, value: String) {
         self.return_type = value;
     }
+    pub fn ch_return_desc(&mut self, value: String) {
+        self.return_desc = value;
+    }
 }
@@ -1,37 +1,190 @@
+pub mod access;
+pub mod annotation;
+pub mod annotation_type;
 pub mod class;
+pub mod config;
 pub mod contents;
+pub mod diagnostic;
 pub mod doc;
 pub mod enumeration;
+pub mod error;
 pub mod exception;
+pub mod import;
 pub mod interface;
+pub mod links;
+pub mod lint;
 pub mod member;
 pub mod method;
 pub mod object;
 pub mod project;
+pub mod stats;
 pub mod options;
 
 pub mod model {
     //! Module that contains all necessary data stuctures for parsing javadocs and generating docs
 
+    pub use model::access::Access;
+    pub use model::annotation::Annotation;
+    pub use model::annotation_type::AnnotationType;
     pub use model::class::Class;
+    pub use model::config::GenConfig;
+    pub use model::config::LicenseStyle;
+    pub use model::config::MemberOrder;
+    pub use model::config::NestedTypes;
+    pub use model::config::OutputFormat;
+    pub use model::config::Visibility;
     pub use model::contents::ApplicationDoc;
+    pub use model::contents::Package;
+    pub use model::diagnostic::Diagnostic;
+    pub use model::diagnostic::ParseOutcome;
     pub use model::doc::Doc;
+    pub use model::doc::DocSpan;
+    pub use model::doc::SerialField;
+    pub use model::doc::TypeDoc;
     pub use model::enumeration::Enumeration;
     pub use model::enumeration::EnumField;
+    pub use model::error::ParseError;
     pub use model::exception::Exception;
+    pub use model::import::Import;
     pub use model::interface::Interface;
+    pub use model::links::TypeLinks;
+    pub use model::lint::LintKind;
+    pub use model::lint::LintWarning;
     pub use model::member::Member;
+    pub use model::member::MemberRef;
     pub use model::method::Param;
     pub use model::method::Method;
     pub use model::object::Object;
     pub use model::object::ObjectState;
     pub use model::options::Options;
     pub use model::project::Project;
+    pub use model::stats::ProjectStats;
 
+    #[derive(Serialize)]
+    #[serde(tag = "kind")]
     pub enum ObjectType {
         Class(Class),
         Interface(Interface),
         Enumeration(Enumeration),
+        Annotation(AnnotationType),
+    }
+
+    impl ObjectType {
+        /// The kind of the type as shown in its heading, e.g. `Class` or `Enum`
+        pub fn kind(&self) -> &str {
+            match self {
+                ObjectType::Class(class) => if class.record { "Record" } else { "Class" },
+                ObjectType::Interface(_) => "Interface",
+                ObjectType::Enumeration(_) => "Enum",
+                ObjectType::Annotation(_) => "Annotation",
+            }
+        }
+
+        /// The name of the type, e.g. `Outer.Inner` for a nested type
+        pub fn name(&self) -> &str {
+            self.package_and_name().1
+        }
+
+        /// The path of the file the type was parsed from
+        pub fn path(&self) -> &str {
+            match self {
+                ObjectType::Class(class) => &class.file_path,
+                ObjectType::Interface(inter) => &inter.file_path,
+                ObjectType::Enumeration(enum_ob) => &enum_ob.file_path,
+                ObjectType::Annotation(annotation) => &annotation.file_path,
+            }
+        }
+
+        /// The access modifier of the type
+        pub fn access(&self) -> &str {
+            match self {
+                ObjectType::Class(class) => &class.access,
+                ObjectType::Interface(inter) => &inter.access,
+                ObjectType::Enumeration(enum_ob) => &enum_ob.access,
+                ObjectType::Annotation(annotation) => &annotation.access,
+            }
+        }
+
+        /// The javadoc of the type
+        pub fn doc(&self) -> TypeDoc {
+            match self {
+                ObjectType::Class(class) => TypeDoc {
+                    description: &class.description,
+                    author: &class.author,
+                    since: &class.since,
+                    version: &class.version,
+                    custom_tags: &class.custom_tags,
+                },
+                ObjectType::Interface(inter) => TypeDoc {
+                    description: &inter.description,
+                    author: &inter.author,
+                    since: &inter.since,
+                    version: &inter.version,
+                    custom_tags: &inter.custom_tags,
+                },
+                ObjectType::Enumeration(enum_ob) => TypeDoc {
+                    description: &enum_ob.description,
+                    author: &enum_ob.author,
+                    since: &enum_ob.since,
+                    version: &enum_ob.version,
+                    custom_tags: &enum_ob.custom_tags,
+                },
+                ObjectType::Annotation(annotation) => TypeDoc {
+                    description: &annotation.description,
+                    author: &annotation.author,
+                    since: &annotation.since,
+                    version: &annotation.version,
+                    custom_tags: &annotation.custom_tags,
+                },
+            }
+        }
+
+        /// The fields of the type, or the elements of an annotation type
+        pub fn variables(&self) -> &[Member] {
+            match self {
+                ObjectType::Class(class) => &class.variables,
+                ObjectType::Interface(inter) => &inter.variables,
+                ObjectType::Enumeration(enum_ob) => &enum_ob.variables,
+                ObjectType::Annotation(annotation) => &annotation.elements,
+            }
+        }
+
+        /// The constructors and methods of the type, annotation types have none
+        pub fn methods(&self) -> &[Method] {
+            match self {
+                ObjectType::Class(class) => &class.methods,
+                ObjectType::Interface(inter) => &inter.methods,
+                ObjectType::Enumeration(enum_ob) => &enum_ob.methods,
+                ObjectType::Annotation(_) => &[],
+            }
+        }
+
+        /// The package and name of the type, e.g. `Outer.Inner` for a nested type
+        pub fn package_and_name(&self) -> (&str, &str) {
+            match self {
+                ObjectType::Class(class) => (class.package_name.trim(), &class.name),
+                ObjectType::Interface(inter) => (inter.package_name.trim(), &inter.name),
+                ObjectType::Enumeration(enum_ob) => (enum_ob.package_name.trim(), &enum_ob.name),
+                ObjectType::Annotation(annotation) => (annotation.package_name.trim(), &annotation.name),
+            }
+        }
+
+        /// Iterates over the fields, constructors, and methods of the type in the
+        /// order they were declared
+        pub fn members<'a>(&'a self) -> impl Iterator<Item = MemberRef<'a>> + 'a {
+            let mut members: Vec<MemberRef<'a>> = self.variables().iter().map(|v| MemberRef::Field(v)).collect();
+
+            for method in self.methods() {
+                if method.is_constructor {
+                    members.push(MemberRef::Constructor(method));
+                } else {
+                    members.push(MemberRef::Method(method));
+                }
+            }
+            members.sort_by_key(|m| m.line_num().parse::<usize>().unwrap_or(0));
+
+            members.into_iter()
+        }
     }
 }
 
@@ -1,16 +1,23 @@
+use model::annotation::Annotation;
+use model::annotation_type::AnnotationType;
 use model::class::Class;
+use model::doc::SerialField;
 use model::enumeration::EnumField;
 use model::enumeration::Enumeration;
 use model::exception::Exception;
+use model::import::Import;
 use model::interface::Interface;
 use model::member::Member;
 use model::method::Method;
+use model::method::Param;
 
 #[derive(Debug, Clone)]
 pub enum ObjectState {
     Class,
     Interface,
     Enumeration,
+    Record,
+    Annotation,
     Unset,
 }
 
@@ -20,20 +27,30 @@ pub enum ObjectState {
 pub struct Object {
     pub state: ObjectState,
     pub file_path: String,
+    pub line_num: String,
     pub signature: String,
     pub package_name: String,
-    pub deprecation: String,
+    pub deprecation: Option<String>,
     pub license: String,
     pub parent: String,
     pub access: String,
     pub version: String,
+    pub since: String,
     pub author: String,
     pub name: String,
     pub description: String,
+    pub type_params: Vec<String>,
     pub exceptions: Vec<Exception>,
+    pub serial_data: String,
+    pub serial_fields: Vec<SerialField>,
     pub interfaces: Vec<String>,
+    pub permits: Vec<String>,
+    pub annotations: Vec<Annotation>,
+    pub custom_tags: Vec<(String, String)>,
     pub dependencies: Vec<String>,
+    pub imports: Vec<Import>,
     pub fields: Vec<EnumField>,
+    pub components: Vec<Param>,
     pub modifiers: Vec<String>,
     pub methods: Vec<Method>,
     pub variables: Vec<Member>,
@@ -45,24 +62,46 @@ impl Object {
             state: ObjectState::Unset,
             package_name: String::new(),
             file_path: String::new(),
+            line_num: String::new(),
             signature: String::new(),
             dependencies: Vec::new(),
-            deprecation: String::new(),
+            imports: Vec::new(),
+            deprecation: None,
             license: String::new(),
             parent: String::new(),
             interfaces: Vec::new(),
+            permits: Vec::new(),
+            annotations: Vec::new(),
+            custom_tags: Vec::new(),
             access: String::new(),
             version: String::new(),
+            since: String::new(),
+            serial_data: String::new(),
+            serial_fields: Vec::new(),
             author: String::new(),
             name: String::new(),
+            type_params: Vec::new(),
             exceptions: Vec::new(),
             description: String::new(),
             fields: Vec::new(),
+            components: Vec::new(),
             modifiers: Vec::new(),
             variables: Vec::new(),
             methods: Vec::new(),
         }
     }
+    /// Creates an empty object for the next type declared in the same file. The
+    /// package, license, and imports of the file are kept.
+    pub fn next_in_file(&self) -> Object {
+        let mut next = Object::new();
+
+        next.package_name = self.package_name.clone();
+        next.license = self.license.clone();
+        next.dependencies = self.dependencies.clone();
+        next.imports = self.imports.clone();
+
+        next
+    }
     pub fn to_class(&mut self) -> Class {
         let mut new_methods = Vec::new();
         let mut new_vars = Vec::new();
@@ -85,18 +124,32 @@ impl Object {
         Class {
             parent: self.parent.clone(),
             file_path: self.file_path.clone(),
+            line_num: self.line_num.clone(),
             signature: self.signature.clone(),
             package_name: self.package_name.clone(),
             license: self.license.clone(),
             dependencies: self.dependencies.clone(),
+            imports: self.imports.clone(),
             deprecation: self.deprecation.clone(),
             access: self.access.clone(),
             version: self.version.clone(),
+            since: self.since.clone(),
+            serial_data: self.serial_data.clone(),
+            serial_fields: self.serial_fields.clone(),
             author: self.author.clone(),
             name: self.name.clone(),
             description: self.description.clone(),
+            type_params: self.type_params.clone(),
             exceptions: new_except,
             interfaces: self.interfaces.clone(),
+            permits: self.permits.clone(),
+            annotations: self.annotations.clone(),
+            custom_tags: self.custom_tags.clone(),
+            record: match self.state {
+                ObjectState::Record => true,
+                _ => false,
+            },
+            components: self.components.clone(),
             modifiers: new_mods,
             variables: new_vars,
             methods: new_methods,
@@ -117,13 +170,21 @@ impl Object {
             package_name: self.package_name.clone(),
             signature: self.signature.clone(),
             dependencies: self.dependencies.clone(),
+            imports: self.imports.clone(),
             deprecation: self.deprecation.clone(),
             access: self.access.clone(),
             file_path: self.file_path.clone(),
+            line_num: self.line_num.clone(),
             version: self.version.clone(),
+            since: self.since.clone(),
             author: self.author.clone(),
             name: self.name.clone(),
             description: self.description.clone(),
+            type_params: self.type_params.clone(),
+            parents: self.interfaces.clone(),
+            permits: self.permits.clone(),
+            annotations: self.annotations.clone(),
+            custom_tags: self.custom_tags.clone(),
             variables: new_variables,
             methods: new_methods,
         }
@@ -153,26 +214,54 @@ impl Object {
 
         Enumeration {
             file_path: self.file_path.clone(),
+            line_num: self.line_num.clone(),
             package_name: self.package_name.clone(),
             license: self.license.clone(),
             dependencies: self.dependencies.clone(),
+            imports: self.imports.clone(),
             deprecation: self.deprecation.clone(),
             access: self.access.clone(),
             version: self.version.clone(),
+            since: self.since.clone(),
             author: self.author.clone(),
             name: self.name.clone(),
             description: self.description.clone(),
             exceptions: new_except,
             interfaces: self.interfaces.clone(),
+            annotations: self.annotations.clone(),
+            custom_tags: self.custom_tags.clone(),
             fields: new_fields,
             modifiers: new_mods,
             variables: new_vars,
             methods: new_methods,
         }
     }
+    pub fn to_annotation(&mut self) -> AnnotationType {
+        AnnotationType {
+            package_name: self.package_name.clone(),
+            signature: self.signature.clone(),
+            deprecation: self.deprecation.clone(),
+            access: self.access.clone(),
+            file_path: self.file_path.clone(),
+            line_num: self.line_num.clone(),
+            version: self.version.clone(),
+            since: self.since.clone(),
+            author: self.author.clone(),
+            name: self.name.clone(),
+            description: self.description.clone(),
+            dependencies: self.dependencies.clone(),
+            imports: self.imports.clone(),
+            annotations: self.annotations.clone(),
+            custom_tags: self.custom_tags.clone(),
+            elements: self.variables.iter().map(|v| v.clone()).collect(),
+        }
+    }
     pub fn ch_access(&mut self, value: String) {
         self.access = value;
     }
+    pub fn ch_line_num(&mut self, value: String) {
+        self.line_num = value;
+    }
     pub fn ch_signature(&mut self, value: String) {
         self.signature = value;
     }
@@ -191,6 +280,9 @@ impl Object {
     pub fn ch_description(&mut self, value: String) {
         self.description = value;
     }
+    pub fn ch_type_params(&mut self, value: Vec<String>) {
+        self.type_params = value;
+    }
     pub fn ch_fields(&mut self, value: Vec<EnumField>) {
         self.fields = value;
     }
@@ -198,8 +290,20 @@ impl Object {
         self.parent = value;
     }
     pub fn ch_version(&mut self, value: String) {
+        self.version = value;
+    }
+    pub fn ch_deprecation(&mut self, value: Option<String>) {
         self.deprecation = value;
     }
+    pub fn ch_since(&mut self, value: String) {
+        self.since = value;
+    }
+    pub fn ch_serial_data(&mut self, value: String) {
+        self.serial_data = value;
+    }
+    pub fn ch_serial_fields(&mut self, value: Vec<SerialField>) {
+        self.serial_fields = value;
+    }
     pub fn ch_author(&mut self, value: String) {
         self.author = value;
     }
@@ -209,12 +313,27 @@ impl Object {
     pub fn add_dependency(&mut self, value: String) {
         self.dependencies.push(value);
     }
+    pub fn add_import(&mut self, value: Import) {
+        self.imports.push(value);
+    }
     pub fn add_variable(&mut self, value: Member) {
         self.variables.push(value);
     }
+    pub fn add_permit(&mut self, value: String) {
+        self.permits.push(value);
+    }
+    pub fn ch_annotations(&mut self, value: Vec<Annotation>) {
+        self.annotations = value;
+    }
+    pub fn ch_custom_tags(&mut self, value: Vec<(String, String)>) {
+        self.custom_tags = value;
+    }
     pub fn add_interface(&mut self, value: String) {
         self.interfaces.push(value);
     }
+    pub fn add_component(&mut self, value: Param) {
+        self.components.push(value);
+    }
     pub fn add_modifier(&mut self, value: String) {
         self.modifiers.push(value);
     }
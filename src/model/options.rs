@@ -1,4 +1,5 @@
 use clap::{ App, Arg};
+use model::model::Visibility;
 
 #[derive(Debug, Clone)]
 /// Struct for flags and options passed to Lojidoc
@@ -9,6 +10,12 @@ pub struct Options {
     pub multi_thread: bool,
     pub verbose: bool,
     pub book: bool,
+    pub toc: bool,
+    pub json: bool,
+    pub stats: bool,
+    pub strict: bool,
+    pub heading_offset: usize,
+    pub visibility: Visibility,
     pub dest: String,
     pub dir: String,
     pub ignore: String,
@@ -23,8 +30,8 @@ impl Options {
         .arg(
             Arg::with_name("INPUT")
                 .value_name("FILE")
-                .required(true)
-                .help("Set the input directory to use")
+                .required(false)
+                .help("Set the input directory to use, the java source is read from stdin when not set")
                 .index(1),
         ).arg(
             Arg::with_name("ignore")
@@ -42,6 +49,45 @@ impl Options {
                 .required(false)
                 .short("b")
                 .help("Use mdbook to create a book for your generated documentation"),
+        ).arg(
+           Arg::with_name("toc")
+                .required(false)
+                .short("t")
+                .help("Include a table of contents linking to the fields and methods of each type"),
+        ).arg(
+            Arg::with_name("json")
+                .required(false)
+                .short("j")
+                .help("Write the parsed project to a JSON file instead of markdown"),
+        ).arg(
+            Arg::with_name("stats")
+                .required(false)
+                .long("stats")
+                .help("Print the number of files, types, and lint warnings without generating documentation"),
+        ).arg(
+            Arg::with_name("strict")
+                .required(false)
+                .long("strict")
+                .help("Exit with an error when a java file has a construct that can't be documented"),
+        ).arg(
+            Arg::with_name("heading-offset")
+                .required(false)
+                .value_name("NUMBER")
+                .short("o")
+                .validator(|value| {
+                    value
+                        .parse::<usize>()
+                        .map(|_| ())
+                        .map_err(|_| "The heading offset must be a number".to_string())
+                })
+                .help("Shift every generated markdown heading down by a number of levels"),
+        ).arg(
+            Arg::with_name("visibility")
+                .required(false)
+                .value_name("LEVEL")
+                .short("p")
+                .possible_values(&["public", "protected", "package", "private"])
+                .help("Only document members at or above a visibility"),
         ).arg(
             Arg::with_name("lint")
                 .help("Check a java project for incorrect and missing javadocs")
@@ -72,6 +118,17 @@ impl Options {
             include_def: matches.is_present("include_def"),
             verbose: matches.is_present("verbose"),
             book: matches.is_present("book"),
+            toc: matches.is_present("toc"),
+            json: matches.is_present("json"),
+            stats: matches.is_present("stats"),
+            strict: matches.is_present("strict"),
+            heading_offset: matches
+                .value_of("heading-offset")
+                .unwrap_or("0")
+                .parse()
+                .expect("Heading offset must be a number"),
+            visibility: Visibility::from_name(matches.value_of("visibility").unwrap_or("private"))
+                .expect("Unknown visibility"),
             ignore: matches.value_of("ignore").unwrap_or("").to_string(),
             multi_thread: matches.is_present("multi_thread"),
             dest: matches
@@ -80,7 +137,7 @@ impl Options {
                 .to_string(),
             dir: matches
                 .value_of("INPUT")
-                .expect("Documentation directory not chosen")
+                .unwrap_or("")
                 .to_string(),
         }
     }
@@ -1,3 +1,4 @@
+use model::annotation_type::AnnotationType;
 use model::class::Class;
 use model::enumeration::Enumeration;
 use model::interface::Interface;
@@ -7,6 +8,7 @@ pub struct Project {
     pub classes: Vec<Class>,
     pub interfaces: Vec<Interface>,
     pub enumerations: Vec<Enumeration>,
+    pub annotations: Vec<AnnotationType>,
 }
 
 impl Project {
@@ -15,6 +17,7 @@ impl Project {
             classes: Vec::new(),
             interfaces: Vec::new(),
             enumerations: Vec::new(),
+            annotations: Vec::new(),
         }
     }
     pub fn add_class(&mut self, value: Class) {
@@ -26,4 +29,7 @@ impl Project {
     pub fn add_enumeration(&mut self, value: Enumeration) {
         self.enumerations.push(value);
     }
+    pub fn add_annotation(&mut self, value: AnnotationType) {
+        self.annotations.push(value);
+    }
 }
@@ -0,0 +1,73 @@
+use model::contents::ApplicationDoc;
+use model::lint::LintKind;
+use model::lint::LintWarning;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Struct summarizing a java project without generating its documentation. Holds the
+/// number of parsed files and types along with the number of lint warnings by kind.
+pub struct ProjectStats {
+    pub file_num: i32,
+    pub class_num: i32,
+    pub interface_num: i32,
+    pub enum_num: i32,
+    pub package_num: usize,
+    pub warning_num: usize,
+    pub missing_descriptions: usize,
+    pub missing_params: usize,
+    pub unknown_params: usize,
+    pub missing_returns: usize,
+}
+
+impl ProjectStats {
+    /// Creates the stats of a parsed application, without any lint warnings
+    ///
+    /// # Arguments
+    ///
+    /// * `app` - The parsed application
+    pub fn from_app(app: &ApplicationDoc) -> ProjectStats {
+        ProjectStats {
+            file_num: app.file_num,
+            class_num: app.class_num,
+            interface_num: app.interface_num,
+            enum_num: app.enum_num,
+            package_num: app.packages.len(),
+            warning_num: 0,
+            missing_descriptions: 0,
+            missing_params: 0,
+            unknown_params: 0,
+            missing_returns: 0,
+        }
+    }
+    /// Counts a lint warning of the project
+    ///
+    /// # Arguments
+    ///
+    /// * `warning` - The lint warning
+    pub fn add_warning(&mut self, warning: &LintWarning) {
+        self.warning_num += 1;
+
+        match warning.kind {
+            LintKind::MissingClassDescription | LintKind::MissingMethodDescription => self.missing_descriptions += 1,
+            LintKind::MissingParam => self.missing_params += 1,
+            LintKind::UnknownParam => self.unknown_params += 1,
+            LintKind::MissingReturn => self.missing_returns += 1,
+        }
+    }
+    /// Describes the stats in a human readable form, one count per line
+    pub fn summary(&self) -> String {
+        let mut summary = String::new();
+
+        summary.push_str(format!("Files: {}\n", self.file_num).as_str());
+        summary.push_str(format!("Packages: {}\n", self.package_num).as_str());
+        summary.push_str(format!("Classes: {}\n", self.class_num).as_str());
+        summary.push_str(format!("Interfaces: {}\n", self.interface_num).as_str());
+        summary.push_str(format!("Enums: {}\n", self.enum_num).as_str());
+        summary.push_str(format!("Lint warnings: {}\n", self.warning_num).as_str());
+        summary.push_str(format!("  Missing descriptions: {}\n", self.missing_descriptions).as_str());
+        summary.push_str(format!("  Missing params: {}\n", self.missing_params).as_str());
+        summary.push_str(format!("  Unknown params: {}\n", self.unknown_params).as_str());
+        summary.push_str(format!("  Missing returns: {}\n", self.missing_returns).as_str());
+
+        summary
+    }
+}
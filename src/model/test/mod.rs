@@ -21,11 +21,108 @@ fn test_class_impl() {
 fn test_method_impl() {
     let mut method = Method::new();
     method.ch_method_name("sample".to_string());
-    method.ch_privacy("private".to_string());
+    method.ch_privacy(Access::Private);
     let mut method2 = method.clone();
     method2.ch_method_name("sample2".to_string());
 
     assert_eq!("sample", method.name.as_str());
     assert_eq!("sample2", method2.name.as_str());
-    assert_eq!("private", method2.privacy.as_str());
+    assert_eq!(Access::Private, method2.privacy);
+}
+
+#[test]
+fn test_find_class() {
+    let mut app = ApplicationDoc::new();
+    let mut object = Object::new();
+    object.ch_name(String::from("Engine"));
+    object.ch_package_name("com.example.parts".to_string());
+    app.add_object(ObjectType::Class(object.to_class()));
+
+    let mut object = Object::new();
+    object.ch_name(String::from("Wheel"));
+    object.ch_package_name("com.example.parts".to_string());
+    app.add_object(ObjectType::Enumeration(object.to_enumeration()));
+
+    match app.find_class("Engine") {
+        Some(ObjectType::Class(class)) => assert_eq!("Engine", class.name.as_str()),
+        _ => panic!("Expected the Engine class"),
+    }
+    match app.find_class("com.example.parts.Wheel") {
+        Some(ObjectType::Enumeration(enumeration)) => assert_eq!("Wheel", enumeration.name.as_str()),
+        _ => panic!("Expected the Wheel enum"),
+    }
+    assert!(app.find_class("com.example.car.Engine").is_none());
+    assert!(app.find_class("Axle").is_none());
+    assert!(app.contains("com.example.parts.Engine"));
+}
+
+#[test]
+fn test_find_nested_class() {
+    let mut app = ApplicationDoc::new();
+    let mut object = Object::new();
+    object.ch_name(String::from("Outer"));
+    object.ch_package_name("com.example".to_string());
+    app.add_object(ObjectType::Class(object.to_class()));
+
+    let mut object = Object::new();
+    object.ch_name(String::from("Outer.Inner"));
+    object.ch_package_name("com.example".to_string());
+    app.add_object(ObjectType::Class(object.to_class()));
+
+    for name in vec!["Inner", "Outer.Inner", "com.example.Outer.Inner"] {
+        match app.find_class(name) {
+            Some(ObjectType::Class(class)) => assert_eq!("Outer.Inner", class.name.as_str()),
+            _ => panic!("Expected the nested Inner class for {}", name),
+        }
+    }
+    match app.find_class("com.example.Outer") {
+        Some(ObjectType::Class(class)) => assert_eq!("Outer", class.name.as_str()),
+        _ => panic!("Expected the Outer class"),
+    }
+    assert!(app.find_class("example.Outer.Inner").is_none());
+    assert!(app.find_class("com.example.Inner").is_none());
+}
+
+#[test]
+fn test_merge_application_docs() {
+    let mut core = ApplicationDoc::new();
+    let mut object = Object::new();
+    object.ch_name(String::from("Engine"));
+    object.ch_package_name("com.example.parts".to_string());
+    core.add_file("parts/Engine.java");
+    core.add_object(ObjectType::Class(object.to_class()));
+
+    let mut api = ApplicationDoc::new();
+    let mut object = Object::new();
+    object.ch_name(String::from("Wheel"));
+    object.ch_package_name("com.example.parts".to_string());
+    api.add_file("parts/Wheel.java");
+    api.add_object(ObjectType::Enumeration(object.to_enumeration()));
+
+    // Both modules build the shared Engine source
+    let mut object = Object::new();
+    object.ch_name(String::from("Engine"));
+    object.ch_package_name("com.example.parts".to_string());
+    api.add_file("parts/Engine.java");
+    api.add_object(ObjectType::Class(object.to_class()));
+
+    let mut object = Object::new();
+    object.ch_name(String::from("Driver"));
+    object.ch_package_name("com.example.api".to_string());
+    api.add_file("api/Driver.java");
+    api.add_object(ObjectType::Interface(object.to_interface()));
+
+    core.merge(api);
+
+    assert_eq!(3, core.objects.len());
+    assert_eq!(3, core.file_num);
+    assert_eq!(1, core.class_num);
+    assert_eq!(1, core.interface_num);
+    assert_eq!(1, core.enum_num);
+
+    assert_eq!(2, core.packages.len());
+    assert_eq!("com.example.parts", core.packages[0].name.as_str());
+    assert_eq!(vec!["Engine", "Wheel"], core.packages[0].members);
+    assert_eq!(vec!["Driver"], core.packages[1].members);
+    assert!(core.contains("com.example.api.Driver"));
 }
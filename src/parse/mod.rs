@@ -2,22 +2,147 @@
 pub mod parse {
     //! A module which handles the parsing for java files
 
+    use document::document::find_file_type;
+    use document::document::lint_object;
     use grammar::grammar::*;
-    use model::model::Class;
+    use model::model::Access;
+    use model::model::Annotation;
+    use model::model::ApplicationDoc;
+    use model::model::Diagnostic;
     use model::model::Doc;
+    use model::model::DocSpan;
     use model::model::Exception;
+    use model::model::Import;
     use model::model::Member;
     use model::model::Method;
     use model::model::Object;
+    use model::model::Package;
     use model::model::ObjectState;
     use model::model::ObjectType;
     use model::model::EnumField;
     use model::model::Param;
-
+    use model::model::ParseError;
+    use model::model::ParseOutcome;
+    use model::model::ProjectStats;
+    use model::model::SerialField;
+
+    use std::cmp;
+    use std::collections::HashMap;
+    use std::collections::VecDeque;
     use std::fs::File;
+    use std::io;
+    use std::io::BufRead;
     use std::io::BufReader;
     use std::io::Read;
+    use std::mem;
     use std::path::Path;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+    use threadpool::ThreadPool;
+
+    /// Adds the text collected for a javadoc keyword to the matching field of a `Doc`
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The javadoc keyword the text belongs to
+    /// * `tag` - The keyword as written in the comment, without the `@`
+    /// * `text` - The text following the keyword
+    /// * `doc` - The doc struct to be modified with the new information
+    /// * `diagnostics` - The unsupported constructs found so far
+    fn add_doc_field(state: &JdocState, tag: &str, text: &str, doc: &mut Doc, diagnostics: &mut Vec<Diagnostic>) {
+        match state {
+            JdocState::JdocReturn => doc.return_desc = text.to_string(),
+            JdocState::Param => {
+                let (name, desc) = split_doc_word(text);
+
+                doc.params.push(Param {
+                    var_type: String::new(),
+                    name,
+                    desc,
+                });
+            }
+            JdocState::Author => {
+                // Every `@author` tag adds to the list of authors
+                if !doc.author.is_empty() {
+                    doc.author.push_str(", ");
+                }
+                doc.author.push_str(text);
+            }
+            JdocState::Deprecated => doc.deprecated = Some(text.to_string()),
+            JdocState::Since => doc.since = text.to_string(),
+            JdocState::Link => doc.see = text.to_string(),
+            JdocState::See => doc.see = text.to_string(),
+            JdocState::Exception => {
+                let (exception_type, desc) = split_doc_word(text);
+
+                // `@throws` and `@exception` are parsed the same way
+                if !exception_type.is_empty() {
+                    doc.exceptions.push(Exception {
+                        exception_type,
+                        desc,
+                        tag: tag.to_string(),
+                    });
+                }
+            }
+            JdocState::Version => doc.version = text.to_string(),
+            JdocState::SerialData => doc.serial_data = text.to_string(),
+            JdocState::SerialField => {
+                let word_parts: Vec<&str> = text.split_whitespace().collect();
+
+                if word_parts.len() > 1 {
+                    doc.serial_fields.push(SerialField {
+                        name: word_parts[0].to_string(),
+                        field_type: word_parts[1].to_string(),
+                        desc: word_parts[2..].join(" "),
+                    });
+                }
+            }
+            JdocState::Desc => doc.description = text.to_string(),
+            JdocState::Custom => doc.custom_tags.push((tag.to_string(), text.to_string())),
+            _ => diagnostics.push(Diagnostic::new(format!("javadoc tag @{}", tag), None)),
+        }
+    }
+
+    /// Splits the text of a javadoc tag into its first word and the rest of the text.
+    /// Whitespace inside angle brackets, e.g. in `Map<String, Integer>`, doesn't end
+    /// the first word.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text following the javadoc tag
+    fn split_doc_word(text: &str) -> (String, String) {
+        let mut depth = 0;
+
+        for (i, ch) in text.char_indices() {
+            match ch {
+                '<' => depth += 1,
+                '>' if depth > 0 => depth -= 1,
+                _ if ch.is_whitespace() && depth == 0 => {
+                    return (text[..i].to_string(), text[i..].trim().to_string());
+                }
+                _ => (),
+            }
+        }
+
+        (text.to_string(), String::new())
+    }
+
+    /// Ends the current paragraph of javadoc text with a blank line. Does nothing
+    /// before the first word or when the paragraph has already been ended.
+    ///
+    /// # Arguments
+    ///
+    /// * `word_buf` - The javadoc text collected so far
+    fn paragraph_break(word_buf: &mut String) {
+        if word_buf.trim() != "" && !word_buf.ends_with("\n\n") {
+            let text = word_buf.trim_end().to_string();
+
+            word_buf.clear();
+            word_buf.push_str(text.as_str());
+            word_buf.push_str("\n\n");
+        }
+    }
 
     /// Handles token streams for javadoc comments and returns a `Doc` struct
     /// containing the information parsed from the javadoc comment.
@@ -25,66 +150,32 @@ pub mod parse {
     /// # Arguments
     ///
     /// * `tokens` - A vector of tokens from the javadoc comment
-    fn get_doc(tokens: &Vec<JdocToken>) -> Doc {
-        let mut return_str = String::from("");
-        let mut desc = String::from("");
-        let mut parameters: Vec<Param> = Vec::new();
-        let mut author = String::new();
-        let mut version = String::new();
-        let mut link = String::new();
-        let mut deprecated = String::new();
-        let mut exceptions: Vec<Exception> = Vec::new();
+    /// * `custom_tags` - The keywords of the custom block tags, e.g. `@reviewedBy`
+    /// * `diagnostics` - The unsupported constructs found so far
+    fn get_doc(tokens: &Vec<JdocToken>, custom_tags: &[String], diagnostics: &mut Vec<Diagnostic>) -> Doc {
+        let mut doc = Doc::new();
         let mut state = JdocState::Desc;
+        let mut tag = String::new();
         let mut word_buf = String::new();
+        let mut line_empty = true;
+        let mut code_depth = 0;
 
         for i in 0..tokens.len() {
             match tokens[i].clone() {
                 JdocToken::Keyword(key) => {
-                    let new_desc = word_buf.clone();
-                    if i != 0 {
-                        match state {
-                            JdocState::JdocReturn => {
-                                return_str = new_desc;
-                            }
-                            JdocState::Param => {
-                                let word_parts: Vec<&str> = new_desc.split(" ").collect();
-
-                                if word_parts.len() > 1 {
-                                    parameters.push(Param {
-                                        var_type: String::new(),
-                                        name: word_parts[0].to_string(),
-                                        desc: word_parts[1..].join(" "),
-                                    });
-                                } else if word_parts.len() == 1 {
-                                    parameters.push(Param {
-                                        var_type: String::new(),
-                                        name: word_parts[0].to_string(),
-                                        desc: String::new(),
-                                    });
-                                }
-                            }
-                            JdocState::Author => author = new_desc,
-                            JdocState::Deprecated => deprecated = new_desc,
-                            JdocState::Since => version = new_desc,
-                            JdocState::Link => link = new_desc,
-                            JdocState::See => link = new_desc,
-                            JdocState::Exception => {
-                                let word_parts: Vec<&str> = new_desc.split(" ").collect();
-
-                                if exceptions.len() > 0 {
-                                    exceptions.push(Exception {
-                                        exception_type: word_parts[0].to_string(),
-                                        desc: word_parts[1..].join(""),
-                                    });
-                                }
-                            }
-                            JdocState::Version => version = new_desc,
-                            JdocState::Desc => desc = new_desc,
-                            _ => println!("Code javadoc field not supported"),
-                        }
+                    line_empty = false;
+
+                    // A tag written inside `{@code}` is part of the code, e.g. `{@code @since 2}`
+                    if code_depth > 0 {
+                        word_buf.push_str(format!("{} ", key).as_str());
+                        continue;
+                    }
 
+                    if i != 0 {
+                        add_doc_field(&state, &tag, word_buf.trim(), &mut doc, diagnostics);
                         word_buf.clear();
                     }
+                    tag = key.trim_start_matches("@").to_string();
 
                     match key.as_ref() {
                         "@return" => state = JdocState::JdocReturn,
@@ -105,27 +196,126 @@ pub mod parse {
                         "@serialField" => state = JdocState::SerialField,
                         "@value" => state = JdocState::Value,
                         "@version" => state = JdocState::Version,
-                        _ => println!("Unsupported javadoc keyword used"),
+                        _ if custom_tags.iter().any(|t| t == &key) => state = JdocState::Custom,
+                        _ => diagnostics.push(Diagnostic::new(format!("javadoc keyword {}", key), None)),
                     }
                 }
                 JdocToken::Symbol(key) => {
-                    if key != "*" {
-                        word_buf.push_str(format!("{} ", key.as_str()).as_str());
+                    // The leading `*` of a line isn't part of the text, even without a space after it
+                    let key = if line_empty && key.starts_with("*") {
+                        key.trim_start_matches("*").to_string()
+                    } else {
+                        key
+                    };
+
+                    if key.is_empty() {
+                        continue;
+                    } else if key == "<p>" || key.starts_with("<p>") {
+                        paragraph_break(&mut word_buf);
+                        let rest = key.trim_start_matches("<p>").trim_end_matches("</p>");
+
+                        if !rest.is_empty() {
+                            word_buf.push_str(format!("{} ", rest).as_str());
+                        }
+                        line_empty = false;
+                    } else if key == "</p>" {
+                        line_empty = false;
+                    } else if key != "*" {
+                        word_buf.push_str(format!("{} ", key.trim_end_matches("</p>")).as_str());
+                        line_empty = false;
+                    }
+
+                    // Braces are counted so the end of a `{@code}` tag is known
+                    if key.starts_with("{@code") || code_depth > 0 {
+                        for ch in key.chars() {
+                            if ch == '{' {
+                                code_depth += 1;
+                            } else if ch == '}' && code_depth > 0 {
+                                code_depth -= 1;
+                            }
+                        }
+                    }
+                }
+                JdocToken::Indent(indent) => {
+                    // The indentation of the lines of a code block is kept
+                    if code_depth > 0 && word_buf.ends_with("\n") {
+                        word_buf.push_str(indent.as_str());
+                    }
+                }
+                JdocToken::NewLine => {
+                    // Line breaks are kept inside code, otherwise a line with
+                    // nothing but a `*` separates two paragraphs
+                    if code_depth > 0 {
+                        let text = word_buf.trim_end().to_string();
+
+                        word_buf.clear();
+                        word_buf.push_str(text.as_str());
+                        word_buf.push('\n');
+                    } else if line_empty {
+                        paragraph_break(&mut word_buf);
+                    }
+                    line_empty = true;
+                }
+            }
+        }
+
+        // The text after the last keyword has no following keyword to flush it
+        if !tokens.is_empty() {
+            add_doc_field(&state, &tag, word_buf.trim(), &mut doc, diagnostics);
+        }
+        doc.spans = DocSpan::split(&doc.description);
+
+        doc
+    }
+
+    /// Splits a declaration such as `Box<T extends Comparable<T>>` or `<T> T` into the
+    /// declaration without its type parameter list and the declared type parameters.
+    /// Bounds on each type parameter are preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `decl` - The declaration containing a type parameter list
+    fn split_type_params(decl: &str) -> (String, Vec<String>) {
+        let start = match decl.find("<") {
+            Some(index) => index,
+            None => return (decl.to_string(), Vec::new()),
+        };
+        let mut depth = 0;
+        let mut end = decl.len();
+        let mut params: Vec<String> = Vec::new();
+        let mut param = String::new();
+
+        for (i, ch) in decl[start..].char_indices() {
+            match ch {
+                '<' => {
+                    if depth > 0 {
+                        param.push(ch);
+                    }
+                    depth = depth + 1;
+                }
+                '>' => {
+                    depth = depth - 1;
+                    if depth == 0 {
+                        end = start + i + 1;
+                        break;
                     }
+                    param.push(ch);
+                }
+                ',' if depth == 1 => {
+                    params.push(param.trim().to_string());
+                    param = String::new();
                 }
+                _ => param.push(ch),
             }
         }
 
-        Doc {
-            params: parameters,
-            description: desc,
-            return_desc: return_str,
-            author: author,
-            version: version,
-            exceptions: exceptions,
-            deprecated: deprecated,
-            see: link,
+        if param.trim() != "" {
+            params.push(param.trim().to_string());
         }
+
+        let rest = format!("{} {}", &decl[..start], &decl[end..]);
+
+        (rest.trim().to_string(), params)
     }
 
     /// Enum that represents the state of parsing a object declaration
@@ -134,6 +324,7 @@ pub mod parse {
         Implement,
         Exception,
         Parent,
+        Permit,
         ClassName,
         Other,
     }
@@ -148,12 +339,31 @@ pub mod parse {
     ///
     /// * `gram_parts` - A vector of tokens from the object's declaration
     /// * `java_doc` - The java doc struct with the documentation for the class
-    /// * `class` - The Class struct to be modified with the new information
-    fn get_object(gram_parts: Vec<Stream>, java_doc: &Doc, sign: String, ob: &mut Object) {
+    /// * `ob` - The Object struct to be modified with the new information
+    fn get_object(gram_parts: Vec<Stream>, java_doc: &Doc, ob: &mut Object, line_num: &str, diagnostics: &mut Vec<Diagnostic>) {
         let mut parse_state = ObjectParseState::Other;
+        let mut component_type: Option<String> = None;
+        let record = match ob.state {
+            ObjectState::Record => true,
+            _ => false,
+        };
 
         for i in 0..gram_parts.len() {
             match gram_parts[i].clone() {
+                // The components of a record follow its name, documented with `@param`
+                Stream::Type(var_type) if record && !ob.name.is_empty() => component_type = Some(var_type),
+                Stream::Variable(var) if component_type.is_some() => {
+                    let desc = match java_doc.params.iter().find(|p| p.name == var) {
+                        Some(param) => param.desc.clone(),
+                        None => String::new(),
+                    };
+
+                    ob.add_component(Param {
+                        name: var,
+                        var_type: component_type.take().unwrap_or(String::new()),
+                        desc,
+                    });
+                }
                 Stream::Variable(var) => {
                     match parse_state {
                         ObjectParseState::Implement => ob.add_interface(var),
@@ -161,9 +371,22 @@ pub mod parse {
                             Exception {
                             desc: String::new(),
                             exception_type: var,
+                            tag: String::new(),
                         }),
-                    ObjectParseState::ClassName => ob.ch_name(var),
-                    ObjectParseState::Parent => ob.ch_parent(var),
+                    ObjectParseState::ClassName => {
+                        let (name, type_params) = split_type_params(var.as_str());
+                        ob.ch_name(name);
+                        ob.ch_type_params(type_params);
+                        parse_state = ObjectParseState::Other;
+                    }
+                    // An interface can extend several interfaces, a class extends one class
+                    ObjectParseState::Parent => match ob.state {
+                        ObjectState::Interface => ob.add_interface(var),
+                        _ => ob.ch_parent(var),
+                    },
+                    ObjectParseState::Permit => ob.add_permit(var),
+                    // `sealed` and `non-sealed` are only modifiers before the type keyword
+                    ObjectParseState::Other if var == "sealed" || var == "non-sealed" => ob.add_modifier(var),
                     ObjectParseState::Other => (),
                     }
                 }
@@ -173,17 +396,19 @@ pub mod parse {
                 Stream::Exception => parse_state = ObjectParseState::Exception,
                 Stream::Implement => parse_state = ObjectParseState::Implement,
                 Stream::Parent => parse_state = ObjectParseState::Parent,
-                _ => {
-                    println!("Class pattern not supported {:?}", gram_parts[i]);
-                    println!("{:?}", gram_parts);
-                },
+                Stream::Permit => parse_state = ObjectParseState::Permit,
+                _ => unsupported(diagnostics, format!("type declaration pattern {:?}", gram_parts[i]), line_num),
             }
         }
 
-        ob.ch_signature(sign.clone());
         ob.ch_description(java_doc.description.clone());
         ob.ch_author(java_doc.author.clone());
         ob.ch_version(java_doc.version.clone());
+        ob.ch_since(java_doc.since.clone());
+        ob.ch_deprecation(java_doc.deprecated.clone());
+        ob.ch_serial_data(java_doc.serial_data.clone());
+        ob.ch_serial_fields(java_doc.serial_fields.clone());
+        ob.ch_custom_tags(java_doc.custom_tags.clone());
     }
 
     /// Enum that represents the state of parsing a method declaration
@@ -202,7 +427,13 @@ pub mod parse {
     ///
     /// * `gram_parts` - A vector of tokens from the method's declaration
     /// * `_java_doc` - The java doc struct with the documentation for the method
-    fn get_method(gram_parts: Vec<Stream>, java_doc: &Doc, line_num: String, signature: String) -> Method {
+    fn get_method(
+        gram_parts: Vec<Stream>,
+        java_doc: &Doc,
+        line_num: String,
+        type_name: &str,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> Method {
         let mut method = Method::new();
         let mut param_type = String::new();
         let mut parse_state = MethodParseState::Other;
@@ -213,12 +444,23 @@ pub mod parse {
                     match parse_state {
 
                         MethodParseState::Exception => {
-                        if java_doc.exceptions.len() > 0 {
-                            method.add_exception(Exception {
-                                desc: java_doc.exceptions[0].clone().desc,
+                        let documented = java_doc
+                            .exceptions
+                            .iter()
+                            .find(|e| simple_type_name(&e.exception_type) == simple_type_name(&var));
+
+                        method.add_exception(match documented {
+                            Some(exception) => Exception {
+                                desc: exception.desc.clone(),
                                 exception_type: var.clone(),
-                            });
-                        }
+                                tag: exception.tag.clone(),
+                            },
+                            None => Exception {
+                                desc: String::new(),
+                                exception_type: var.clone(),
+                                tag: String::new(),
+                            },
+                        });
                     },
                     MethodParseState::MethodName => method.ch_method_name(var.clone()),
                     MethodParseState::ParamName => {
@@ -231,39 +473,71 @@ pub mod parse {
                     }
                     MethodParseState::Other => (),
                     }
-                    if method.name == "" {
+                    if method.name.is_empty() && method.return_type.is_empty() {
                         method.ch_return_type(var.clone());
                     }
                 }
                 Stream::Type(key) => {
                     if method.return_type == "" {
-                        method.ch_return_type(key);
+                        if key.starts_with("<") {
+                            let (return_type, type_params) = split_type_params(key.as_str());
+                            method.ch_type_params(type_params);
+                            method.ch_return_type(return_type);
+                        } else {
+                            method.ch_return_type(key);
+                        }
                         parse_state = MethodParseState::MethodName;
                     } else {
                         param_type = key;
                         parse_state = MethodParseState::ParamName;
                     }
                 }
-                Stream::Access(key) => method.ch_privacy(key),
+                Stream::Access(key) => method.ch_privacy(Access::from_modifier(&key)),
                 Stream::Modifier(key) => method.add_modifier(key),
                 Stream::Exception => parse_state = MethodParseState::Exception,
-                _ => println!("Method pattern not supported"),
+                _ => unsupported(diagnostics, format!("method pattern {:?}", gram_parts[i]), &line_num),
             }
         }
         method.ch_line_num(line_num);
-        method.ch_signature(signature);
+
+        // Unchecked exceptions are often documented without being declared
+        for exception in &java_doc.exceptions {
+            let declared = method
+                .exceptions
+                .iter()
+                .any(|e| simple_type_name(&e.exception_type) == simple_type_name(&exception.exception_type));
+
+            if !declared {
+                method.add_exception(exception.clone());
+            }
+        }
+
+        // A constructor is declared with the type name and without a return type
+        if method.name.is_empty() && !type_name.is_empty() && method.return_type == type_name {
+            method.ch_is_constructor(true);
+            method.ch_method_name(type_name.to_string());
+            method.ch_return_type(String::new());
+        }
 
         if java_doc.return_desc != "" {
-            method.ch_return_type(java_doc.return_desc.clone());
+            method.ch_return_desc(java_doc.return_desc.clone());
         }
 
         if java_doc.description != "" {
             method.ch_description(java_doc.description.clone());
         }
+        method.ch_deprecation(java_doc.deprecated.clone());
+        method.ch_see(java_doc.see.clone());
+        method.ch_since(java_doc.since.clone());
+        method.ch_custom_tags(java_doc.custom_tags.clone());
 
         let n_params: Vec<Param> =
             match_params(&mut method, &java_doc.params);
         method.ch_params(n_params);
+        let type_param_docs = match_type_params(&method, &java_doc.params);
+        method.ch_type_param_docs(type_param_docs);
+        let unknown_params = unmatched_params(&method, &java_doc.params);
+        method.ch_unknown_params(unknown_params);
 
         method
     }
@@ -274,9 +548,12 @@ pub mod parse {
     /// # Arguments
     ///
     /// * `gram_parts` - A vector of tokens in the member variable expression
-    fn get_var(gram_parts: Vec<Stream>, line_num: String, signature: String) -> Member {
+    /// * `java_doc` - The javadoc comment directly before the member variable
+    fn get_var(gram_parts: Vec<Stream>, java_doc: &Doc, line_num: String, diagnostics: &mut Vec<Diagnostic>) -> Member {
         let mut member = Member::new();
         let mut member_name = false;
+        member.ch_line_number(line_num.clone());
+        member.ch_desc(java_doc.description.clone());
 
         for i in 0..gram_parts.len() {
             match gram_parts[i].clone() {
@@ -302,48 +579,87 @@ pub mod parse {
                         member_name = true;
                     }
                 }
-                Stream::Access(key) => member.ch_access(key),
+                Stream::Access(key) => member.ch_access(Access::from_modifier(&key)),
                 Stream::Modifier(key) => member.add_modifier(key),
-                _ => println!("Member variable pattern not supported"),
+                _ => unsupported(diagnostics, format!("member variable pattern {:?}", gram_parts[i]), &line_num),
             }
         }
-        member.ch_line_number(line_num);
-        member.ch_signature(signature);
-
 
         member
     }
-    /// Handles token streams for member variables and returns a `Member` struct
-    /// Containing the member variable's data
+    /// Handles the token stream of the constant list of an enum and returns a
+    /// `EnumField` for each constant
     ///
     /// # Arguments
     ///
-    /// * `gram_parts` - A vector of tokens in the member variable expression
-    fn get_enum_fields(gram_parts: Vec<Stream>) -> Vec<EnumField> {
+    /// * `gram_parts` - A vector of tokens in the constant list
+    /// * `args` - The constructor arguments of the constants that have them
+    fn get_enum_fields(
+        gram_parts: Vec<Stream>,
+        args: &[(String, String)],
+        line_num: &str,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> Vec<EnumField> {
         let mut fields: Vec<EnumField>  = Vec::new();
 
         for i in 0..gram_parts.len() {
             match gram_parts[i].clone() {
                 Stream::Variable(var) => {
+                    let constant_args = match args.iter().find(|&(name, _)| name == &var) {
+                        Some((_, value)) => value.clone(),
+                        None => String::new(),
+                    };
+
                     fields.push(EnumField {
                         name: var,
-                        value: i.to_string(),
+                        value: fields.len().to_string(),
+                        args: constant_args,
                     })
                 }
-                _ => println!("Enumeration pattern not supported"),
+                _ => unsupported(diagnostics, format!("enumeration pattern {:?}", gram_parts[i]), line_num),
             }
         }
 
         fields
     }
 
+    /// Matches the `@param <T>` tags of a javadoc with the type parameters declared by
+    /// a method. The declaration of each type parameter, including its bound, is kept
+    /// as the type of the returned parameter.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The method declaring the type parameters
+    /// * `jparams` - The parameters documented in the javadoc
+    pub fn match_type_params(method: &Method, jparams: &[Param]) -> Vec<Param> {
+        method
+            .type_params
+            .iter()
+            .map(|decl| {
+                let name = decl.split_whitespace().next().unwrap_or("").to_string();
+                let tag_name = format!("<{}>", name);
+                let desc = match jparams.iter().find(|p| p.name == tag_name) {
+                    Some(param) => param.desc.clone(),
+                    None => String::new(),
+                };
+
+                Param {
+                    name,
+                    var_type: decl.clone(),
+                    desc,
+                }
+            })
+            .collect()
+    }
+
     pub fn match_params(method: &Method, jparams: &Vec<Param>) -> Vec<Param> {
         let mut new_param: Vec<Param> = Vec::new();
 
         for mut param in method.parameters.clone() {
             let mut found = false;
             for i in 0..jparams.len() {
-                if param.name == jparams[i].name {
+                // Type parameters are documented as `@param <T>` and never match a value parameter
+                if !jparams[i].name.starts_with("<") && param.name == jparams[i].name {
                     new_param.push(Param {
                         name: param.name.clone(),
                         var_type: param.var_type.clone(),
@@ -365,6 +681,28 @@ pub mod parse {
         new_param
     }
 
+    /// Gets the names documented with `@param` that a method doesn't declare, e.g. a
+    /// typo or a parameter that was removed. Type parameters are documented as `<T>`.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The method with its parameters and type parameters
+    /// * `jparams` - The parameters documented in the javadoc
+    pub fn unmatched_params(method: &Method, jparams: &[Param]) -> Vec<String> {
+        jparams
+            .iter()
+            .filter(|jparam| {
+                if jparam.name.starts_with("<") {
+                    let name = jparam.name.trim_start_matches("<").trim_end_matches(">");
+                    !method.type_params.iter().any(|t| t.split_whitespace().next() == Some(name))
+                } else {
+                    !method.parameters.iter().any(|p| p.name == jparam.name)
+                }
+            })
+            .map(|jparam| jparam.name.clone())
+            .collect()
+    }
+
     macro_rules! is_keyword {
         ($w:expr, $k:expr) => {{
             let mut found = false;
@@ -380,110 +718,334 @@ pub mod parse {
 
     fn push_token(curr_token: &String, tokens: &mut Vec<Token>, keywords: &Vec<&str>) {
         if curr_token != "" {
-            let jdoc_keywords = get_jdoc_keywords();
-            let spring_keywords = get_spring_keywords();
             if is_keyword!(curr_token, keywords) {
                 tokens.push(Token::Keyword(curr_token.to_string()));
-            } else if is_keyword!(curr_token, jdoc_keywords) {
-                tokens.push(Token::Keyword(curr_token.to_string()));
-            } else if is_keyword!(curr_token, spring_keywords) {
-                tokens.push(Token::Keyword(curr_token.to_string()));
             } else {
                 tokens.push(Token::Symbol(curr_token.to_string()));
             }
         }
     }
 
-    pub fn lex_contents(content: &String) -> Vec<Token> {
-        let mut tokens: Vec<Token> = Vec::new();
-        let mut curr_token = String::new();
-        let mut block_depth = 0;
-        let mut line_number = 1;
-        let mut blob = content.chars();
-        let keywords = get_keywords();
-        let mut curr_line = String::new();
+    /// Determines whether the parser is in the constant list of an enum, which starts
+    /// after the declaration and ends at the first `;`
+    ///
+    /// # Arguments
+    ///
+    /// * `object` - The type being parsed
+    /// * `enum_constants` - Whether the constants of the type were parsed
+    fn in_enum_constants(object: &Object, enum_constants: bool) -> bool {
+        match object.state {
+            ObjectState::Enumeration => !enum_constants && !object.name.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// Determines whether the parser is in the declaration of a type, before its body
+    fn in_declaration(parse_state: &ParseState) -> bool {
+        match parse_state {
+            ParseState::Other => false,
+            _ => true,
+        }
+    }
+
+    /// Determines whether an object is an annotation type, whose members are elements
+    fn is_annotation(object: &Object) -> bool {
+        match object.state {
+            ObjectState::Annotation => true,
+            _ => false,
+        }
+    }
+
+    /// Determines whether a word is a keyword declaring a type
+    fn is_type_keyword(word: &str) -> bool {
+        word == "class" || word == "interface" || word == "enum" || word == "record" || word == "@interface"
+    }
+
+    /// Struct holding the state of the lexer between characters, so the contents of a
+    /// java file can be lexed all at once or while they are read
+    ///
+    /// Characters inside `//` and `/* */` comments, string literals, and char literals are
+    /// never treated as structural, so they do not change the block depth. The members of
+    /// nested types are lexed like those of a top-level type. Generic type arguments such
+    /// as `Map<String, List<T>>` are kept together as a single token. The contents are
+    /// read by character, so identifiers and comments may contain any unicode text.
+    /// Non-breaking and ideographic spaces separate words like a space.
+    pub struct Lexer {
+        tokens: Vec<Token>,
+        curr_token: String,
+        // Whether each open block is the body of a type, whose members are lexed
+        blocks: Vec<bool>,
+        type_decl: bool,
+        line_number: usize,
+        keywords: Vec<&'static str>,
+        curr_line: String,
+        line_comment: bool,
+        block_comment: bool,
+        literal: Option<char>,
+        escaped: bool,
+        angle_depth: usize,
+        prev_ch: char,
+    }
 
-        tokens.push(Token::LineNumber(line_number.to_string()));
+    impl Lexer {
+        pub fn new() -> Lexer {
+            let mut keywords = get_keywords();
+
+            // Java, javadoc, and spring keywords all become keyword tokens
+            keywords.extend(get_jdoc_keywords());
+            keywords.extend(get_spring_keywords());
+
+            Lexer {
+                tokens: vec![Token::LineNumber("1".to_string())],
+                curr_token: String::new(),
+                blocks: Vec::new(),
+                type_decl: false,
+                line_number: 1,
+                keywords,
+                curr_line: String::new(),
+                line_comment: false,
+                block_comment: false,
+                literal: None,
+                escaped: false,
+                angle_depth: 0,
+                prev_ch: ' ',
+            }
+        }
 
-        loop {
-            match blob.next() {
-                Some(ch) => {
-                    match ch {
-                    ' ' | '\t' | '\r' => {
-                        if block_depth < 2 {
-                            push_token(&curr_token, &mut tokens, &keywords);
+        /// Lexes the next character of the contents
+        ///
+        /// # Arguments
+        ///
+        /// * `ch` - The character
+        pub fn lex_char(&mut self, ch: char) {
+            // Only the tokens outside of method bodies and initializers are kept
+            let emit = self.blocks.iter().all(|b| *b);
+
+            match ch {
+                ' ' | '\t' | '\r' | ',' if self.angle_depth > 0 => {
+                    if emit {
+                        if ch == ',' {
+                            self.curr_token.push(',');
+                        } else if !self.curr_token.ends_with(" ") {
+                            self.curr_token.push(' ');
                         }
-                        curr_token = String::new();
                     }
-                    '\n' => {
-                        if block_depth < 2 {
-                            push_token(&curr_token, &mut tokens, &keywords);
+                }
+                ' ' | '\t' | '\r' | '\u{a0}' | '\u{3000}' => {
+                    if !self.line_comment && !self.block_comment && self.literal.is_none() && is_type_keyword(&self.curr_token) {
+                        self.type_decl = true;
+                    }
+                    if emit {
+                        push_token(&self.curr_token, &mut self.tokens, &self.keywords);
+                    }
+                    self.curr_token.clear();
+                }
+                '\n' => {
+                    if self.angle_depth > 0 {
+                        if emit && !self.curr_token.ends_with(" ") {
+                            self.curr_token.push(' ');
+                        }
+                    } else {
+                        if !self.line_comment && !self.block_comment && self.literal.is_none() && is_type_keyword(&self.curr_token) {
+                            self.type_decl = true;
                         }
+                        if emit {
+                            push_token(&self.curr_token, &mut self.tokens, &self.keywords);
+                        }
+                        self.curr_token.clear();
+                    }
 
-                        line_number = line_number + 1;
-                        tokens.push(Token::LineNumber(line_number.to_string()));
-                        tokens.push(Token::Sign(curr_line.as_str().trim().to_string()));
-                        curr_token = String::new();
-                        curr_line = String::new();
+                    self.line_number = self.line_number + 1;
+                    self.tokens.push(Token::LineNumber(self.line_number.to_string()));
+                    self.tokens.push(Token::Sign(self.curr_line.trim().to_string()));
+                    self.curr_line.clear();
+                    self.line_comment = false;
+                    self.literal = None;
+                }
+                ',' | ';' | '(' | ')' | '{' | '}' if self.line_comment || self.block_comment || self.literal.is_some() => {
+                    if emit {
+                        self.curr_token.push(ch);
                     }
-                    ',' => {
-                        if block_depth < 2 {
-                            push_token(&curr_token, &mut tokens, &keywords);
-                            tokens.push(Token::Join)
-                        }
-                        curr_token = String::new();
+                }
+                ',' => {
+                    if emit {
+                        push_token(&self.curr_token, &mut self.tokens, &self.keywords);
+                        self.tokens.push(Token::Join)
                     }
-                    ';' => {
-                        if block_depth < 2 {
-                            push_token(&curr_token, &mut tokens, &keywords);
-                            tokens.push(Token::ExpressionEnd(";".to_string()));
-                        }
-                        curr_token = String::new();
+                    self.curr_token.clear();
+                }
+                ';' => {
+                    if emit {
+                        push_token(&self.curr_token, &mut self.tokens, &self.keywords);
+                        self.tokens.push(Token::ExpressionEnd(";".to_string()));
                     }
-                    '(' => {
-                        if block_depth < 2 {
-                            push_token(&curr_token, &mut tokens, &keywords);
-                            tokens.push(Token::ParamStart);
-                        }
-                        curr_token = String::new();
+                    self.curr_token.clear();
+                    self.angle_depth = 0;
+                    self.type_decl = false;
+                }
+                '(' => {
+                    if emit {
+                        push_token(&self.curr_token, &mut self.tokens, &self.keywords);
+                        self.tokens.push(Token::ParamStart);
                     }
-                    ')' => {
-                        if block_depth < 2 {
-                            push_token(&curr_token, &mut tokens, &keywords);
-                            tokens.push(Token::ParamEnd);
-                        }
-                        curr_token = String::new();
+                    self.curr_token.clear();
+                    self.angle_depth = 0;
+                }
+                ')' => {
+                    if emit {
+                        push_token(&self.curr_token, &mut self.tokens, &self.keywords);
+                        self.tokens.push(Token::ParamEnd);
                     }
-                    '{' => {
-                        if block_depth < 2 {
-                            push_token(&curr_token, &mut tokens, &keywords);
-                            tokens.push(Token::ExpressionEnd("{".to_string()));
-                        }
-                        curr_token = String::new();
-                        block_depth = block_depth + 1;
+                    self.curr_token.clear();
+                    self.angle_depth = 0;
+                }
+                '{' => {
+                    if emit {
+                        push_token(&self.curr_token, &mut self.tokens, &self.keywords);
+                        self.tokens.push(Token::ExpressionEnd("{".to_string()));
                     }
-                    '}' => {
-                        if block_depth < 2 {
-                            push_token(&curr_token, &mut tokens, &keywords);
-                        }
-                        curr_token = String::new();
-                        block_depth = block_depth - 1;
+                    self.curr_token.clear();
+                    // A top-level block is always the body of the type declared in the file
+                    self.blocks.push(self.blocks.is_empty() || self.type_decl);
+                    self.angle_depth = 0;
+                    self.type_decl = false;
+                }
+                '}' => {
+                    if emit {
+                        push_token(&self.curr_token, &mut self.tokens, &self.keywords);
+                    }
+                    self.curr_token.clear();
+                    self.angle_depth = 0;
+                    self.type_decl = false;
+
+                    // Marks the end of a type, top-level or nested
+                    if self.blocks.pop() == Some(true) && emit {
+                        self.tokens.push(Token::ExpressionEnd("}".to_string()));
+                    }
+                }
+                _ => {
+                    if emit {
+                        self.curr_token.push(ch);
                     }
-                    _ => {
-                        if block_depth < 2 {
-                            curr_token.push_str(ch.to_string().as_str());
+
+                    // Track comment and literal state so their characters are not structural
+                    if let Some(quote) = self.literal {
+                        if self.escaped {
+                            self.escaped = false;
+                        } else if ch == '\\' {
+                            self.escaped = true;
+                        } else if ch == quote {
+                            self.literal = None;
+                        }
+                    } else if self.block_comment {
+                        if self.prev_ch == '*' && ch == '/' {
+                            self.block_comment = false;
+                        }
+                    } else if !self.line_comment {
+                        if ch == '"' || ch == '\'' {
+                            self.literal = Some(ch);
+                        } else if ch == '<' {
+                            self.angle_depth = self.angle_depth + 1;
+                        } else if ch == '>' && self.angle_depth > 0 {
+                            self.angle_depth = self.angle_depth - 1;
+                        } else if self.prev_ch == '/' {
+                            self.line_comment = ch == '/';
+                            self.block_comment = ch == '*';
                         }
                     }
+                }
+            }
+
+            self.curr_line.push(ch);
+            self.prev_ch = ch;
+        }
+
+        /// Takes the tokens lexed so far. The token being read is only returned once it
+        /// is complete.
+        pub fn take_tokens(&mut self) -> Vec<Token> {
+            mem::replace(&mut self.tokens, Vec::new())
+        }
+    }
+
+    /// Splits the contents of a java file into a vector of tokens
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The contents of the java file
+    pub fn lex_contents(content: &String) -> Vec<Token> {
+        let mut lexer = Lexer::new();
+
+        // A byte order mark would otherwise become part of the first token
+        for ch in content.trim_start_matches('\u{feff}').chars() {
+            lexer.lex_char(ch);
+        }
+
+        lexer.take_tokens()
+    }
+
+    /// Iterator over the tokens of a java file, lexing the file a line at a time as it
+    /// is read so the whole file is never held in memory. A line that can't be read,
+    /// e.g. because it is not valid UTF-8, yields the error and ends the stream.
+    pub struct TokenStream<R: BufRead> {
+        reader: R,
+        lexer: Lexer,
+        line: String,
+        pending: VecDeque<Token>,
+        first_line: bool,
+        done: bool,
+    }
+
+    impl<R: BufRead> Iterator for TokenStream<R> {
+        type Item = io::Result<Token>;
 
+        fn next(&mut self) -> Option<io::Result<Token>> {
+            loop {
+                if let Some(token) = self.pending.pop_front() {
+                    return Some(Ok(token));
+                }
+                if self.done {
+                    return None;
                 }
-                curr_line.push_str(ch.to_string().as_str());
 
-                },
-                None => break,
+                self.line.clear();
+                match self.reader.read_line(&mut self.line) {
+                    Ok(0) => self.done = true,
+                    Err(err) => {
+                        self.done = true;
+                        return Some(Err(err));
+                    }
+                    Ok(_) => {
+                        let line = if self.first_line {
+                            self.line.trim_start_matches('\u{feff}')
+                        } else {
+                            self.line.as_str()
+                        };
+
+                        for ch in line.chars() {
+                            self.lexer.lex_char(ch);
+                        }
+                        self.first_line = false;
+                    }
+                }
+                self.pending.extend(self.lexer.take_tokens());
             }
         }
+    }
 
-        tokens
+    /// Lexes a java file while it is read, yielding the same tokens as `lex_contents`
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The reader of the java file
+    pub fn lex_reader<R: BufRead>(reader: R) -> TokenStream<R> {
+        TokenStream {
+            reader,
+            lexer: Lexer::new(),
+            line: String::new(),
+            pending: VecDeque::new(),
+            first_line: true,
+            done: false,
+        }
     }
 
     macro_rules! access_mod_match {
@@ -502,7 +1064,8 @@ pub mod parse {
         ($e:expr) => {
             match $e {
                 Token::Keyword(value) => match value.as_ref() {
-                    "static" | "final" | "abstract" | "synchronized" | "volatile" => true,
+                    "static" | "final" | "abstract" | "synchronized" | "volatile" | "transient"
+                    | "native" | "strictfp" | "default" => true,
                     _ => false,
                 },
                 _ => false,
@@ -510,54 +1073,307 @@ pub mod parse {
         };
     }
 
+    /// Enum that represents the declaration waiting for the source line containing it.
+    /// The lexer emits a line's `Token::Sign` after the tokens of that line.
+    pub enum SignTarget {
+        Object,
+        Method,
+        Variable,
+        Unset,
+    }
+
     /// Constucts a syntax tree based on the stream of token from the lexing
-    /// Outputs a Class struct containing all the data for a java class
+    /// Outputs the first type declared in the file, or `None` when the file declares
+    /// no class, interface, enum, or annotation type
     ///
     /// # Arguments
     ///
     /// * `tokens` - The list of tokens from the lexer
-    pub fn construct_ast(tokens: Vec<Token>) -> ObjectType {
+    pub fn construct_ast(tokens: Vec<Token>) -> Option<ObjectType> {
+        construct_types(tokens).into_iter().next()
+    }
+
+    /// Parses the tokens of a java file into every top-level type it declares, in
+    /// declaration order. A file without a recognizable type has none.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - The tokens of the java file
+    pub fn construct_types(tokens: Vec<Token>) -> Vec<ObjectType> {
+        construct_ast_with_diagnostics(tokens).objects
+    }
+
+    /// Parses the tokens of a java file like `construct_ast`, also returning the
+    /// diagnostics for the constructs that were skipped because they aren't supported
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - The tokens of the java file
+    pub fn construct_ast_with_diagnostics(tokens: Vec<Token>) -> ParseOutcome {
+        let mut diagnostics = Vec::new();
+        let objects = build_ast(tokens, &mut diagnostics, None, &[]);
+
+        ParseOutcome {
+            objects,
+            diagnostics,
+        }
+    }
+
+    /// Parses the tokens of a java file like `construct_ast_with_diagnostics`, also
+    /// capturing custom block tags such as `@reviewedBy` into the `custom_tags` of the
+    /// documented types and methods
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - The tokens of the java file
+    /// * `tags` - The names of the custom tags, with or without the `@`
+    pub fn construct_ast_with_tags(tokens: Vec<Token>, tags: &[&str]) -> ParseOutcome {
+        let mut diagnostics = Vec::new();
+        let objects = build_ast(tokens, &mut diagnostics, None, &get_custom_jdoc_keywords(tags));
+
+        ParseOutcome {
+            objects,
+            diagnostics,
+        }
+    }
+
+    /// Parses the tokens of a java file like `construct_ast_with_diagnostics`, also
+    /// returning the grammar built for every declaration in the order it was parsed.
+    /// Meant for debugging parse issues and for tooling, generating documentation
+    /// doesn't need the traces.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - The tokens of the java file
+    pub fn construct_ast_debug(tokens: Vec<Token>) -> (ParseOutcome, Vec<DeclarationTrace>) {
+        let mut diagnostics = Vec::new();
+        let mut trace = Vec::new();
+        let objects = build_ast(tokens, &mut diagnostics, Some(&mut trace), &[]);
+
+        (
+            ParseOutcome {
+                objects,
+                diagnostics,
+            },
+            trace,
+        )
+    }
+
+    /// Parses the tokens of a java file like `construct_ast`, but returns an error for
+    /// the first construct that is not supported instead of skipping it. Used to fail
+    /// a build when a file can't be fully documented.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - The tokens of the java file
+    pub fn construct_ast_strict(tokens: Vec<Token>) -> Result<Vec<ObjectType>, ParseError> {
+        let outcome = construct_ast_with_diagnostics(tokens);
+
+        match outcome.diagnostics.into_iter().next() {
+            Some(diagnostic) => Err(ParseError::from_diagnostic(diagnostic)),
+            None => Ok(outcome.objects),
+        }
+    }
+
+    /// Parses the tokens of a java file into its top-level types, recording every
+    /// unsupported construct
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - The tokens of the java file
+    /// * `diagnostics` - The unsupported constructs found while parsing
+    /// * `custom_tags` - The keywords of the custom block tags to capture
+    fn build_ast(
+        tokens: Vec<Token>,
+        diagnostics: &mut Vec<Diagnostic>,
+        mut trace: Option<&mut Vec<DeclarationTrace>>,
+        custom_tags: &[String],
+    ) -> Vec<ObjectType> {
+        let mut objects: Vec<ObjectType> = Vec::new();
         let mut annotation = false;
+        let mut annotations: Vec<Annotation> = Vec::new();
+        let mut annotation_depth = 0;
+        let mut in_params = false;
+        // Whether the declaration being parsed has a parameter list, i.e. is a method
+        let mut has_params = false;
         let mut ignore = false;
+        let mut initializer: Option<String> = None;
         let mut object = Object::new();
         let mut in_object = false;
+        // The types enclosing a nested type, with their parse state and the position of
+        // their nested types, and the finished nested types of the current top-level type
+        let mut outer: Vec<(Object, bool, usize)> = Vec::new();
+        let mut nested: Vec<ObjectType> = Vec::new();
+        let mut enum_constants = false;
+        // The arguments of the enum constant being parsed with their depth, and the
+        // arguments of the constants parsed so far
+        let mut constant_args: Option<(String, usize)> = None;
+        let mut constant_values: Vec<(String, String)> = Vec::new();
         let mut parse_state = ParseState::Other;
         let mut doc = false;
         let mut comment = false;
+        let mut line_comment = false;
         let mut jdoc = Doc::new();
         let mut symbols: Vec<String> = Vec::new();
         let mut doc_tokens: Vec<JdocToken> = Vec::new();
+        let mut doc_line_start = 0;
+        let mut traced_doc: Vec<JdocToken> = Vec::new();
         let mut method: Method = Method::new();
         let mut gram_parts: Vec<Stream> = Vec::new();
-        let mut comment_buf = String::new();
+        let mut header_comment = false;
+        let mut header_lines: Vec<String> = Vec::new();
         let mut line_num = String::new();
-        let mut signature = String::new();
-
-        for token in tokens.clone() {
+        let mut sign_target = SignTarget::Unset;
+        let mut sign_lines = String::new();
+        let mut jdoc_keywords = get_jdoc_keywords();
+        jdoc_keywords.extend(custom_tags.iter().map(|t| t.as_str()));
+        let record_keyword = Token::Keyword("record".to_string());
+        let annotation_keyword = Token::Keyword("@interface".to_string());
+        let permits_keyword = Token::Keyword("permits".to_string());
+
+        for token in &tokens {
+            // Collects the arguments of an annotation, keeping track of the line
             if ignore {
-                match token.clone() {
-                    Token::ParamEnd => ignore = false,
-                    _ => continue,
+                let arg = match token {
+                    Token::ParamStart => {
+                        annotation_depth += 1;
+                        Some("(".to_string())
+                    }
+                    Token::ParamEnd => {
+                        annotation_depth -= 1;
+                        if annotation_depth == 0 {
+                            ignore = false;
+                            None
+                        } else {
+                            Some(")".to_string())
+                        }
+                    }
+                    Token::Symbol(word) | Token::Keyword(word) => Some(word.clone()),
+                    Token::Join => Some(",".to_string()),
+                    _ => None,
+                };
+
+                if let (Some(arg), Some(last)) = (arg, annotations.last_mut()) {
+                    last.add_arg(&arg);
                 }
 
-                continue;
+                match token {
+                    Token::LineNumber(_) | Token::Sign(_) => (),
+                    _ => continue,
+                }
             }
 
-            match token.clone() {
-                Token::Keyword(key) => {
-                    let sym_len = symbols.len();
-
-                    // Allows for multiple tokens to be treated as one variable
-                    if sym_len == 1 {
-                        gram_parts.push(Stream::Variable(symbols[0].clone()));
-                    } else if sym_len > 1 {
-                        gram_parts.push(Stream::Type(symbols[..sym_len - 1].join(" ")));
-                        gram_parts.push(Stream::Variable(symbols[sym_len - 1].clone()));
+            // Collects the initializer of a field up to the end of its declaration
+            if let Some(ref mut value) = initializer {
+                match token {
+                    Token::Symbol(word) | Token::Keyword(word) => {
+                        if !value.is_empty() && !value.ends_with("(") {
+                            value.push(' ');
+                        }
+                        value.push_str(word);
+                        continue;
                     }
+                    Token::ParamStart => {
+                        value.push('(');
+                        continue;
+                    }
+                    Token::ParamEnd => {
+                        value.push(')');
+                        continue;
+                    }
+                    Token::Join => {
+                        value.push(',');
+                        continue;
+                    }
+                    _ => (),
+                }
+            }
 
-                    match key.as_ref() {
-                        "class" => {
-                            if !doc && !comment {
+            // Collects the arguments of an enum constant, e.g. `MERCURY(3.3e23, 2.4e6)`
+            let mut finished_args = None;
+            if let Some((ref mut args, ref mut depth)) = constant_args {
+                match token {
+                    Token::ParamStart => {
+                        *depth += 1;
+                        args.push('(');
+                        continue;
+                    }
+                    Token::ParamEnd if *depth > 1 => {
+                        *depth -= 1;
+                        args.push(')');
+                        continue;
+                    }
+                    Token::ParamEnd => finished_args = Some(args.clone()),
+                    Token::Symbol(word) | Token::Keyword(word) if !doc && !comment => {
+                        if !args.is_empty() && !args.ends_with("(") && !args.ends_with(" ") {
+                            args.push(' ');
+                        }
+                        args.push_str(word);
+                        continue;
+                    }
+                    Token::Join if !doc && !comment => {
+                        args.push_str(", ");
+                        continue;
+                    }
+                    _ => (),
+                }
+            }
+            if let Some(args) = finished_args {
+                if let Some(Stream::Variable(name)) = gram_parts.last() {
+                    constant_values.push((name.clone(), args));
+                }
+                constant_args = None;
+                continue;
+            }
+
+            // `record` is only a keyword where a type is declared, e.g. not in `Record record;`
+            let token = match token {
+                Token::Symbol(word) if word == "record" && !doc && !comment && !in_params && symbols.is_empty() => {
+                    &record_keyword
+                }
+                // `@interface` declares an annotation type, it is not an annotation
+                Token::Symbol(word) if word == "@interface" && !doc && !comment => &annotation_keyword,
+                // `permits` lists the subtypes of a sealed type in its declaration
+                Token::Symbol(word) if word == "permits" && !doc && !comment && in_declaration(&parse_state) => {
+                    &permits_keyword
+                }
+                _ => token,
+            };
+
+            match token {
+                Token::Keyword(key) => {
+                    // Keywords inside comments are plain text and never part of a declaration
+                    if comment {
+                        continue;
+                    } else if doc {
+                        if is_keyword!(key, &jdoc_keywords) {
+                            doc_tokens.push(JdocToken::Keyword(key.clone()));
+                        } else {
+                            doc_tokens.push(JdocToken::Symbol(key.clone()));
+                        }
+                        continue;
+                    }
+
+                    let sym_len = symbols.len();
+
+                    // Allows for multiple tokens to be treated as one variable
+                    if sym_len == 1 {
+                        gram_parts.push(Stream::Variable(symbols[0].clone()));
+                    } else if sym_len > 1 {
+                        gram_parts.push(Stream::Type(join_type(&symbols[..sym_len - 1])));
+                        gram_parts.push(Stream::Variable(symbols[sym_len - 1].clone()));
+                    }
+
+                    // A nested type is parsed on its own, the enclosing type resumes at its end
+                    if in_object && is_type_keyword(key) {
+                        let inner = object.next_in_file();
+                        outer.push((mem::replace(&mut object, inner), enum_constants, nested.len()));
+                        enum_constants = false;
+                    }
+
+                    match key.as_str() {
+                        "class" => {
+                            if !doc && !comment {
                                 object.ch_state(ObjectState::Class);
                                 gram_parts.push(Stream::Object(key.to_string()));
                                 parse_state = ParseState::Class;
@@ -580,92 +1396,142 @@ pub mod parse {
                             }
                             in_object = true;
                         }
+                        "record" => {
+                            object.ch_state(ObjectState::Record);
+                            gram_parts.push(Stream::Object(key.to_string()));
+                            parse_state = ParseState::Class;
+                            in_object = true;
+                        }
+                        "@interface" => {
+                            object.ch_state(ObjectState::Annotation);
+                            gram_parts.push(Stream::Object(key.to_string()));
+                            parse_state = ParseState::Interface;
+                            in_object = true;
+                        }
+                        // The default value of an annotation element, e.g. `int value() default 3;`
+                        "default" if in_object && is_annotation(&object) => initializer = Some(String::new()),
                         "package" => {
-                            if comment_buf != "" {
-                                object.ch_license(comment_buf.clone());
+                            if !header_lines.is_empty() {
+                                object.ch_license(clean_license(&header_lines));
                             }
                             gram_parts.push(Stream::Package);
                         }
                         "throws" => gram_parts.push(Stream::Exception),
                         "extends" => gram_parts.push(Stream::Parent),
                         "implements" => gram_parts.push(Stream::Implement),
+                        "permits" => gram_parts.push(Stream::Permit),
                         "import" => gram_parts.push(Stream::Import),
                         _ => {
-                            if access_mod_match!(token.clone()) {
+                            if access_mod_match!(token) {
                                 gram_parts.push(Stream::Access(key.to_string()));
-                            } else if modifier_match!(token.clone()) {
+                            } else if modifier_match!(token) {
                                 gram_parts.push(Stream::Modifier(key.to_string()));
-                            } else if is_keyword!(key, get_jdoc_keywords()) {
-                                doc_tokens.push(JdocToken::Keyword(key.clone()));
-                            } else if doc {
-                                doc_tokens.push(JdocToken::Symbol(key.clone()));
-                            } else if !comment && !doc {
-                                println!("Keyword not supported: {}", key);
+                            } else {
+                                unsupported(diagnostics, format!("keyword {}", key), &line_num);
                             }
                         }
                     }
 
-                    if comment {
-                        comment_buf.push_str(format!("{} ", key).as_str());
-                    }
-
                     symbols.clear();
                     annotation = false;
                 }
                 Token::Symbol(word) => {
-                    match word.as_ref() {
-                        "/**" => doc = true,
-                        "*/" => {
+                    // A block comment before the package declaration is the license header
+                    let header_position =
+                        !doc && !comment && !in_object && objects.is_empty() && object.package_name.is_empty();
+
+                    match word.as_str() {
+                        closer if is_comment_closer(closer) => {
                             if doc {
-                                jdoc = get_doc(&doc_tokens);
+                                jdoc = get_doc(&doc_tokens, custom_tags, diagnostics);
                                 parse_state = ParseState::Other;
+                                if trace.is_some() {
+                                    traced_doc = doc_tokens.clone();
+                                }
                                 doc_tokens.clear();
-                                gram_parts.clear();
+                                // The javadoc of an enum constant follows the previous constants
+                                if !in_enum_constants(&object, enum_constants) {
+                                    gram_parts.clear();
+                                }
                             }
 
                             doc = false;
                             comment = false;
                         }
-                        "//" => comment = true,
+                        "//" => {
+                            if !doc && !comment {
+                                comment = true;
+                                line_comment = true;
+                            }
+                        }
                         "/*" => {
-                            comment_buf = String::new();
+                            if header_position {
+                                header_comment = true;
+                                header_lines.clear();
+                            }
                             comment = true;
                         }
+                        opener if is_doc_opener(opener) => {
+                            // A banner of asterisks can also open the license header
+                            if header_position && opener.starts_with("/***") {
+                                header_comment = true;
+                                header_lines.clear();
+                                comment = true;
+                            } else {
+                                doc = true;
+                                doc_line_start = doc_tokens.len();
+                            }
+                        }
                         _ => {
-                            if word.contains("//") {
+                            if word.contains("//") && !doc && !comment {
+                                // The code before a glued comment is kept, e.g. `@Override//`
+                                let code = &word[..word.find("//").unwrap()];
+
+                                if code.starts_with("@") {
+                                    if !in_params {
+                                        annotations.push(Annotation::new(code.trim_start_matches("@").to_string()));
+                                    }
+                                } else if !code.is_empty() {
+                                    symbols.push(code.to_string());
+                                }
                                 comment = true;
+                                line_comment = true;
                             } else if doc {
-                                if is_keyword!(word, get_jdoc_keywords()) {
+                                if is_keyword!(word, &jdoc_keywords) {
                                     doc_tokens.push(JdocToken::Keyword(word.clone()));
                                 } else {
                                     doc_tokens.push(JdocToken::Symbol(word.clone()));
                                 }
-                            } else if word.contains("@") && !doc {
+                            } else if word.starts_with("@") && !comment {
+                                // An `@` in comment text, e.g. an email address, is never an annotation
+                                if !in_params {
+                                    annotations.push(Annotation::new(word.trim_start_matches("@").to_string()));
+                                }
                                 annotation = true;
                                 continue;
+                            } else if !comment && !in_params && in_object && word.contains("=") {
+                                // The assignment can be attached to the field name, e.g. `x=5`
+                                let index = word.find("=").unwrap();
+
+                                if index > 0 {
+                                    symbols.push(word[..index].to_string());
+                                }
+                                initializer = Some(word[index + 1..].to_string());
                             } else if !comment {
                                 symbols.push(word.to_string());
                             }
                         }
                     }
 
-                    if comment {
-                        if word != "*" && word != "/*" {
-                            comment_buf.push_str(format!("{} ", word).as_str());
-                        }
-                    }
-
                     annotation = false;
                 }
                 Token::Join => {
-                    if symbols.len() > 1 {
-                        let temp_sym = symbols.clone();
-                        gram_parts.push(Stream::Type(temp_sym[..temp_sym.len() - 1].join(" ")));
-                        gram_parts.push(Stream::Variable(temp_sym[temp_sym.len() - 1].clone()));
-                    }
-
-                    if comment {
-                        comment_buf.push_str(",");
+                    // A single symbol is an item of a list, e.g. an implemented interface
+                    if symbols.len() == 1 {
+                        gram_parts.push(Stream::Variable(symbols[0].clone()));
+                    } else if symbols.len() > 1 {
+                        gram_parts.push(Stream::Type(join_type(&symbols[..symbols.len() - 1])));
+                        gram_parts.push(Stream::Variable(symbols[symbols.len() - 1].clone()));
                     }
 
                     symbols.clear();
@@ -674,33 +1540,34 @@ pub mod parse {
                     if annotation {
                         ignore = true;
                         annotation = false;
+                        annotation_depth = 1;
+                    } else if in_enum_constants(&object, enum_constants) {
+                        if let Some(name) = symbols.last() {
+                            gram_parts.push(Stream::Variable(name.clone()));
+                        }
+                        constant_args = Some((String::new(), 1));
                     } else {
-                        let temp_sym = symbols.clone();
-                        if temp_sym.len() == 1 {
-                            gram_parts.push(Stream::Variable(temp_sym[0].clone()));
-                        } else if temp_sym.len() > 1 {
-                            gram_parts.push(Stream::Type(temp_sym[..temp_sym.len() - 1].join(" ")));
-                            gram_parts.push(Stream::Variable(temp_sym[temp_sym.len() - 1].clone()));
+                        in_params = true;
+                        has_params = true;
+
+                        if symbols.len() == 1 {
+                            gram_parts.push(Stream::Variable(symbols[0].clone()));
+                        } else if symbols.len() > 1 {
+                            gram_parts.push(Stream::Type(join_type(&symbols[..symbols.len() - 1])));
+                            gram_parts.push(Stream::Variable(symbols[symbols.len() - 1].clone()));
                         }
                     }
 
-                    if comment {
-                        comment_buf.push_str("(");
-                    }
-
                     symbols.clear();
                 }
                 Token::ParamEnd => {
-                    let temp_sym = symbols.clone();
+                    in_params = false;
+
                     if symbols.len() == 1 {
-                        method.ch_method_name(temp_sym[0].clone());
+                        method.ch_method_name(symbols[0].clone());
                     } else if symbols.len() > 1 {
-                        gram_parts.push(Stream::Type(temp_sym[..temp_sym.len() - 1].join(" ")));
-                        gram_parts.push(Stream::Variable(temp_sym[temp_sym.len() - 1].clone()));
-                    }
-
-                    if comment {
-                        comment_buf.push_str(")");
+                        gram_parts.push(Stream::Type(join_type(&symbols[..symbols.len() - 1])));
+                        gram_parts.push(Stream::Variable(symbols[symbols.len() - 1].clone()));
                     }
                     symbols.clear();
                 }
@@ -709,105 +1576,1060 @@ pub mod parse {
                     if symbols.len() == 1 {
                         gram_parts.push(Stream::Variable(symbols[0].clone()));
                     } else if symbols.len() > 1 {
-                        gram_parts.push(Stream::Type(symbols[..symbols.len() - 1].join(" ")));
+                        gram_parts.push(Stream::Type(join_type(&symbols[..symbols.len() - 1])));
                         gram_parts.push(Stream::Variable(symbols[symbols.len() - 1].clone()));
                     }
 
-                    let mut temp_gram = gram_parts.clone();
+                    let temp_gram = mem::replace(&mut gram_parts, Vec::new());
+                    let default_value = initializer.take();
 
-                    match end.as_ref() {
+                    if let Some(ref mut trace) = trace {
+                        if !temp_gram.is_empty() || !traced_doc.is_empty() {
+                            trace.push(DeclarationTrace {
+                                line_num: line_num.clone(),
+                                end: end.clone(),
+                                gram_parts: temp_gram.clone(),
+                                doc_tokens: mem::replace(&mut traced_doc, Vec::new()),
+                            });
+                        }
+                    }
+
+                    match end.as_str() {
                         ";" => {
                             if !in_object {
                                 if temp_gram.len() > 1 {
-                                    match temp_gram[0].clone() {
-                                        Stream::Import => match temp_gram[1].clone() {
-                                            Stream::Variable(key) => object.add_dependency(key),
-                                            _ => println!("Pattern not supported"),
-                                        },
-                                        Stream::Package => match temp_gram[1].clone() {
-                                            Stream::Variable(key) => object.ch_package_name(key),
-                                            _ => println!("Pattern not supported"),
-                                        },
-                                        _ => object
-                                            .add_variable(get_var(temp_gram, line_num.clone(), signature.clone())),
+                                    match (&temp_gram[0], &temp_gram[1]) {
+                                        (Stream::Import, Stream::Variable(key)) => {
+                                            object.add_dependency(key.clone());
+                                            object.add_import(Import::new(key, false));
+                                        }
+                                        (Stream::Import, Stream::Modifier(key)) if key == "static" && temp_gram.len() > 2 => {
+                                            match temp_gram[2] {
+                                                Stream::Variable(ref path) => {
+                                                    object.add_dependency(path.clone());
+                                                    object.add_import(Import::new(path, true));
+                                                }
+                                                _ => unsupported(diagnostics, "static import".to_string(), &line_num),
+                                            }
+                                        }
+                                        (Stream::Package, Stream::Variable(key)) => object.ch_package_name(key.clone()),
+                                        (Stream::Import, _) => unsupported(diagnostics, "import".to_string(), &line_num),
+                                        (Stream::Package, _) => unsupported(diagnostics, "package declaration".to_string(), &line_num),
+                                        _ => {
+                                            let mut member = get_var(temp_gram, &jdoc, line_num.clone(), diagnostics);
+                                            member.ch_annotations(annotations.clone());
+                                            object.add_variable(member);
+                                            sign_target = SignTarget::Variable;
+                                        }
                                     }
                                 }
                             } else {
+                                // Interface members with an initializer are constants, not methods.
+                                // Abstract and native methods of a class have no body.
+                                let is_field = match object.state {
+                                    ObjectState::Class | ObjectState::Enumeration | ObjectState::Record => {
+                                        default_value.is_some() || !has_params
+                                    }
+                                    ObjectState::Annotation => true,
+                                    _ => default_value.is_some(),
+                                };
+
                                 match object.state {
-                                    ObjectState::Class => {
-                                        object.add_variable(get_var(temp_gram, line_num.clone(), signature.clone()))
+                                    // The constants of an enum end at the first `;`, members follow
+                                    ObjectState::Enumeration if !enum_constants => {
+                                        object.ch_fields(get_enum_fields(temp_gram, &constant_values, &line_num, diagnostics));
+                                        constant_values.clear();
+                                        enum_constants = true;
                                     }
-                                    ObjectState::Enumeration => {
-                                        object.ch_fields(get_enum_fields(temp_gram))
+                                    _ if is_field => {
+                                        let mut member = get_var(temp_gram, &jdoc, line_num.clone(), diagnostics);
+                                        if let Some(value) = default_value {
+                                            member.ch_default_value(value);
+                                        }
+                                        member.ch_annotations(annotations.clone());
+                                        object.add_variable(member);
+                                        sign_target = SignTarget::Variable;
+                                    }
+                                    _ => {
+                                        let mut method = get_method(temp_gram, &jdoc, line_num.clone(), &simple_type_name(&object.name), diagnostics);
+                                        method.ch_annotations(annotations.clone());
+                                        object.add_method(method);
+                                        sign_target = SignTarget::Method;
                                     }
-                                    _ => object.add_method(get_method(
-                                        temp_gram,
-                                        &jdoc,
-                                        line_num.clone(),
-                                        signature.clone(),
-                                    )),
                                 }
                             }
                         }
                         "{" => match parse_state {
                             ParseState::Interface | ParseState::Class | ParseState::Enum => {
-                                get_object(temp_gram.clone(), &jdoc, signature.clone(), &mut object)
+                                get_object(temp_gram, &jdoc, &mut object, &line_num, diagnostics);
+                                object.ch_annotations(annotations.clone());
+                                if let Some((enclosing, _, _)) = outer.last() {
+                                    let name = format!("{}.{}", enclosing.name, object.name);
+                                    object.ch_name(name);
+                                }
+                                object.ch_line_num(line_num.clone());
+                                sign_target = SignTarget::Object;
+                            }
+                            // The body of an anonymous class or array in a field initializer, e.g.
+                            // `= new Runnable() {`, isn't lexed and the field ends at the next `;`
+                            // The body of an enum constant, e.g. `PLUS {`, isn't lexed
+                            ParseState::Other if in_enum_constants(&object, enum_constants) => {
+                                gram_parts = temp_gram;
+                                continue;
+                            }
+                            ParseState::Other if default_value.is_some() => {
+                                let value = default_value.unwrap_or(String::new());
+                                initializer = Some(if value.is_empty() {
+                                    "{ ... }".to_string()
+                                } else {
+                                    format!("{} {{ ... }}", value)
+                                });
+                                gram_parts = temp_gram;
+                                continue;
                             }
                             ParseState::Other => {
-                                object.add_method(get_method(temp_gram, &jdoc, line_num.clone(), signature.clone()))
+                                let mut method = get_method(temp_gram, &jdoc, line_num.clone(), &simple_type_name(&object.name), diagnostics);
+                                method.ch_annotations(annotations.clone());
+                                object.add_method(method);
+                                sign_target = SignTarget::Method;
                             }
                         },
+                        "}" => {
+                            // Enum constants aren't always followed by a `;`
+                            if let ObjectState::Enumeration = object.state {
+                                if !enum_constants && !temp_gram.is_empty() {
+                                    object.ch_fields(get_enum_fields(temp_gram, &constant_values, &line_num, diagnostics));
+                                }
+                                constant_values.clear();
+                            }
+
+                            match outer.pop() {
+                                Some((enclosing, enclosing_constants, position)) => {
+                                    // Nested types follow their enclosing type, in declaration order
+                                    if let Some(finished) = to_object_type(&mut object) {
+                                        nested.insert(position, finished);
+                                    }
+                                    object = enclosing;
+                                    enum_constants = enclosing_constants;
+                                    // The rest of the line can't complete the enclosing type's signature
+                                    sign_target = SignTarget::Unset;
+                                }
+                                None => {
+                                    let next = object.next_in_file();
+                                    if let Some(finished) = to_object_type(&mut object) {
+                                        objects.push(finished);
+                                        object = next;
+                                    }
+                                    objects.append(&mut nested);
+                                    in_object = false;
+                                    enum_constants = false;
+                                }
+                            }
+                        }
                         _ => {
                             if comment {
                                 comment = false;
                             } else if !doc {
-                                panic!("Expression end not allowed");
+                                unsupported(diagnostics, format!("expression end `{}`", end), &line_num);
+                                continue;
                             }
                         }
                     }
 
                     parse_state = ParseState::Other;
+                    has_params = false;
                     jdoc = Doc::new();
+                    annotations.clear();
                     gram_parts.clear();
                     symbols.clear();
                 }
-                Token::LineNumber(num) => line_num = num,
-                Token::Sign(line) => signature = line,
+                Token::LineNumber(num) => {
+                    if doc {
+                        doc_tokens.push(JdocToken::NewLine);
+                    }
+                    if line_comment {
+                        comment = false;
+                        line_comment = false;
+                    }
+                    line_num = num.clone();
+                }
+                Token::Sign(line) => {
+                    // The indentation is only known once the whole line has been lexed
+                    if doc {
+                        if let Some(indent) = doc_line_indent(line) {
+                            doc_tokens.insert(doc_line_start, JdocToken::Indent(indent));
+                        }
+                        doc_line_start = doc_tokens.len();
+                    }
+                    if header_comment {
+                        header_lines.push(line.clone());
+                        header_comment = !line.contains("*/");
+                    }
+
+                    // The source line is only complete after the declaration has been parsed
+                    sign_lines.push_str(line.as_str());
+                    sign_lines.push(' ');
+                    let signature = normalize_signature(&sign_lines);
+
+                    match sign_target {
+                        SignTarget::Object => object.ch_signature(signature),
+                        SignTarget::Method => {
+                            if let Some(method) = object.methods.last_mut() {
+                                method.ch_signature(signature);
+                            }
+                        }
+                        SignTarget::Variable => {
+                            if let Some(member) = object.variables.last_mut() {
+                                member.ch_signature(signature);
+                            }
+                        }
+                        SignTarget::Unset => (),
+                    }
+
+                    // The lines of a declaration spanning several lines are kept until it ends
+                    let in_declaration = !symbols.is_empty() || !gram_parts.is_empty() || in_params;
+                    if !in_declaration || doc || comment {
+                        sign_lines.clear();
+                    }
+
+                    sign_target = SignTarget::Unset;
+                }
             }
         }
 
-        match object.state {
-            ObjectState::Class => return ObjectType::Class(object.to_class()),
-            ObjectState::Interface => return ObjectType::Interface(object.to_interface()),
-            ObjectState::Enumeration => return ObjectType::Enumeration(object.to_enumeration()),
-            ObjectState::Unset => {
-                println!("Java file type not supported. Supported types: class, interface, enum");
-                println!("{:?}", tokens);
-                return ObjectType::Class(object.to_class());
+        // A type without a closing brace ends with the file
+        if let Some(finished) = to_object_type(&mut object) {
+            objects.push(finished);
+        }
+
+        if objects.is_empty() {
+            diagnostics.push(Diagnostic::new(
+                "file, no type found".to_string(),
+                None,
+            ));
+        }
+
+        objects
+    }
+
+    /// Parses the tokens of a `package-info.java` file, which declares no type, into
+    /// its package and the description from the javadoc preceding the declaration.
+    /// Returns `None` when the file has no package declaration.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - The tokens of the `package-info.java` file
+    pub fn construct_package_info(tokens: Vec<Token>) -> Option<Package> {
+        let jdoc_keywords = get_jdoc_keywords();
+        let mut diagnostics = Vec::new();
+        let mut doc = false;
+        let mut in_package = false;
+        let mut doc_tokens: Vec<JdocToken> = Vec::new();
+        let mut jdoc = Doc::new();
+
+        for token in &tokens {
+            match token {
+                Token::Symbol(word) if is_doc_opener(word) => {
+                    doc = true;
+                    doc_tokens.clear();
+                }
+                Token::Symbol(word) if is_comment_closer(word) => {
+                    if doc {
+                        jdoc = get_doc(&doc_tokens, &[], &mut diagnostics);
+                    }
+                    doc = false;
+                }
+                Token::Symbol(word) | Token::Keyword(word) if doc => {
+                    if is_keyword!(word, &jdoc_keywords) {
+                        doc_tokens.push(JdocToken::Keyword(word.clone()));
+                    } else {
+                        doc_tokens.push(JdocToken::Symbol(word.clone()));
+                    }
+                }
+                Token::LineNumber(_) if doc => doc_tokens.push(JdocToken::NewLine),
+                Token::Keyword(key) if key == "package" => in_package = true,
+                Token::Symbol(word) if in_package => {
+                    return Some(Package {
+                        name: word.clone(),
+                        package_path: word.replace(".", "/"),
+                        members: Vec::new(),
+                        description: jdoc.description.trim().to_string(),
+                    });
+                }
+                _ => (),
             }
         }
+
+        None
     }
 
-    /// Root function of the module. Calls the lex and parse functions and returns
-    /// a `Class` struct.
+    /// Parses a single javadoc comment without the declaration it documents, e.g. for
+    /// an editor integration. The `/**` and `*/` delimiters and the leading `*` of each
+    /// line are removed, unsupported tags are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `comment` - The javadoc comment
+    pub fn parse_doc_comment(comment: &str) -> Doc {
+        let jdoc_keywords = get_jdoc_keywords();
+        let mut diagnostics = Vec::new();
+        let mut doc_tokens: Vec<JdocToken> = Vec::new();
+        let mut body = comment.trim().trim_start_matches("/**");
+        if body.ends_with("*/") {
+            body = body.trim_end_matches("/").trim_end_matches("*");
+        }
+
+        for (i, line) in body.lines().enumerate() {
+            let line = line.trim();
+
+            if i > 0 {
+                doc_tokens.push(JdocToken::NewLine);
+            }
+            if let Some(indent) = doc_line_indent(line) {
+                doc_tokens.push(JdocToken::Indent(indent));
+            }
+
+            for word in line.trim_start_matches("*").split_whitespace() {
+                let word = word.to_string();
+
+                if is_keyword!(&word, &jdoc_keywords) {
+                    doc_tokens.push(JdocToken::Keyword(word));
+                } else {
+                    doc_tokens.push(JdocToken::Symbol(word));
+                }
+            }
+        }
+
+        get_doc(&doc_tokens, &[], &mut diagnostics)
+    }
+
+    /// Determines whether a file is a `package-info.java` file
     ///
     /// # Arguments
     ///
     /// * `path` - The path of the java file
-    /// * `lint` - A bool representing whether the class's javadoc comments should be linted
-    pub fn parse_file(path: &Path, _lint: bool) -> ObjectType {
+    pub fn is_package_info(path: &Path) -> bool {
+        path.file_name().map_or(false, |name| name == "package-info.java")
+    }
+
+    /// Parses a `package-info.java` file into its documented package
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the `package-info.java` file
+    pub fn parse_package_info(path: &Path) -> Option<Package> {
         let file = File::open(path).expect("Could not open file");
         let mut contents = String::new();
         let mut buf = BufReader::new(file);
-        let res = buf.read_to_string(&mut contents);
-        if res.is_ok() {
-            let tokens = lex_contents(&contents);
-            construct_ast(tokens)
+
+        match buf.read_to_string(&mut contents) {
+            Ok(_) => construct_package_info(lex_contents(&contents)),
+            Err(_) => {
+                eprintln!("Unable to read file");
+                None
+            }
+        }
+    }
+
+    /// Determines whether a comment opener starts a javadoc block. Besides `/**`,
+    /// openers with extra asterisks such as `/***` and the Doxygen style `/**<` are
+    /// accepted.
+    ///
+    /// # Arguments
+    ///
+    /// * `opener` - The symbol opening the comment
+    fn is_doc_opener(opener: &str) -> bool {
+        opener.starts_with("/**") && opener[3..].trim_start_matches("*").trim_start_matches("<").is_empty()
+    }
+
+    /// Determines whether a symbol closes a block comment, also accepting closers with
+    /// extra asterisks such as `***/`
+    ///
+    /// # Arguments
+    ///
+    /// * `closer` - The symbol
+    fn is_comment_closer(closer: &str) -> bool {
+        closer.ends_with("*/") && closer.trim_end_matches("/").trim_start_matches("*").is_empty()
+    }
+
+    /// Gets the indentation of a javadoc line after its leading `*` and the single space
+    /// following it, `None` for lines without a leading `*` or without any text. Tabs
+    /// are kept as they are, since they affect the alignment of code.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - The trimmed source line
+    fn doc_line_indent(line: &str) -> Option<String> {
+        if !line.starts_with("*") || line.starts_with("*/") {
+            return None;
+        }
+
+        let rest = &line[1..];
+        let indent: String = rest.chars().take_while(|ch| *ch == ' ' || *ch == '\t').collect();
+        if indent.len() == rest.len() {
+            None
+        } else if indent.starts_with(" ") {
+            Some(indent[1..].to_string())
         } else {
-            println!("Unable to read file");
-            ObjectType::Class(Class::new())
+            Some(indent)
+        }
+    }
+
+    /// Gets the text of a license header from the source lines of its block comment,
+    /// removing the comment delimiters and the leading `*` of each line while keeping
+    /// the line breaks
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - The trimmed source lines of the block comment
+    pub fn clean_license(lines: &Vec<String>) -> String {
+        let mut text: Vec<&str> = Vec::new();
+
+        for line in lines {
+            let mut line = line.trim();
+            if line.starts_with("/*") {
+                line = line.trim_start_matches("/").trim_start_matches("*");
+            }
+            if let Some(end) = line.find("*/") {
+                line = line[..end].trim_end_matches("*");
+            }
+            let line = line.trim_start_matches("*");
+            text.push(if line.starts_with(" ") { &line[1..] } else { line });
+        }
+
+        while text.first().map_or(false, |l| l.trim() == "") {
+            text.remove(0);
+        }
+        while text.last().map_or(false, |l| l.trim() == "") {
+            text.pop();
+        }
+
+        text.iter().map(|l| l.trim_end()).collect::<Vec<&str>>().join("\n")
+    }
+
+    /// Replaces the `{@value}` tags of a javadoc text with the constant values they
+    /// reference. `{@value}` references the documented field itself and
+    /// `{@value #FIELD}` a field of the same type. Other references are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The javadoc text
+    /// * `own_value` - The value of the documented field, `None` outside a field's javadoc
+    /// * `type_name` - The name of the type declaring the fields
+    /// * `values` - The constant values of the type's fields by name
+    fn replace_value_tags(
+        text: &str,
+        own_value: Option<&String>,
+        type_name: &str,
+        values: &HashMap<String, String>,
+    ) -> String {
+        let mut replaced = String::new();
+        let mut rest = text;
+
+        while let Some(start) = rest.find("{@value") {
+            let end = match rest[start..].find("}") {
+                Some(end) => start + end,
+                None => break,
+            };
+            let target = rest[start + "{@value".len()..end].trim();
+            let value = if target.is_empty() {
+                own_value
+            } else {
+                let mut parts = target.splitn(2, "#");
+                let target_type = parts.next().unwrap_or("");
+                let field = parts.next().unwrap_or("");
+
+                if target_type.is_empty() || target_type == type_name || target_type.ends_with(&format!(".{}", type_name)) {
+                    values.get(field)
+                } else {
+                    None
+                }
+            };
+
+            replaced.push_str(&rest[..start]);
+            match value {
+                Some(value) => replaced.push_str(value),
+                None => replaced.push_str(&rest[start..end + 1]),
+            }
+            rest = &rest[end + 1..];
+        }
+        replaced.push_str(rest);
+
+        replaced
+    }
+
+    /// Resolves the `{@value}` tags in the javadoc of a parsed type and its members
+    /// using the initializers of the type's fields
+    ///
+    /// # Arguments
+    ///
+    /// * `object` - The parsed object
+    fn resolve_value_tags(object: &mut Object) {
+        let mut values: HashMap<String, String> = HashMap::new();
+        for member in &object.variables {
+            if !member.default_value.is_empty() {
+                values.insert(member.name.clone(), member.default_value.clone());
+            }
+        }
+        let name = object.name.clone();
+
+        object.description = replace_value_tags(&object.description, None, &name, &values);
+        for member in object.variables.iter_mut() {
+            let own_value = values.get(&member.name);
+            member.desc = replace_value_tags(&member.desc, own_value, &name, &values);
+        }
+        for method in object.methods.iter_mut() {
+            method.description = replace_value_tags(&method.description, None, &name, &values);
+            method.return_desc = replace_value_tags(&method.return_desc, None, &name, &values);
+            for param in method.parameters.iter_mut() {
+                param.desc = replace_value_tags(&param.desc, None, &name, &values);
+            }
+        }
+    }
+
+    /// Documents the accessors of a record's components with the `@param` docs of the
+    /// record. Accessors that aren't declared are added, and a compact constructor
+    /// takes the components as its parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `object` - The parsed record
+    fn add_record_accessors(object: &mut Object) {
+        for method in object.methods.iter_mut() {
+            if method.is_constructor && method.parameters.is_empty() && !method.signature.contains("(") {
+                method.ch_params(object.components.clone());
+            }
+        }
+
+        for component in object.components.clone() {
+            let declared = object
+                .methods
+                .iter_mut()
+                .find(|m| m.name == component.name && !m.is_constructor && m.parameters.is_empty());
+
+            match declared {
+                Some(accessor) => {
+                    if accessor.description.is_empty() {
+                        accessor.ch_description(component.desc.clone());
+                    }
+                }
+                None => {
+                    let mut accessor = Method::new();
+
+                    accessor.ch_line_num(object.line_num.clone());
+                    accessor.ch_signature(format!("public {} {}()", component.var_type, component.name));
+                    accessor.ch_privacy(Access::Public);
+                    accessor.ch_method_name(component.name.clone());
+                    accessor.ch_return_type(component.var_type.clone());
+                    accessor.ch_description(component.desc.clone());
+                    object.add_method(accessor);
+                }
+            }
+        }
+    }
+
+    /// Converts a parsed object into the type it declares, `None` when no class,
+    /// interface, or enum was declared
+    ///
+    /// # Arguments
+    ///
+    /// * `object` - The parsed object
+    fn to_object_type(object: &mut Object) -> Option<ObjectType> {
+        resolve_value_tags(object);
+
+        match object.state {
+            ObjectState::Class => Some(ObjectType::Class(object.to_class())),
+            ObjectState::Record => {
+                add_record_accessors(object);
+                Some(ObjectType::Class(object.to_class()))
+            }
+            ObjectState::Interface => Some(ObjectType::Interface(object.to_interface())),
+            ObjectState::Enumeration => Some(ObjectType::Enumeration(object.to_enumeration())),
+            ObjectState::Annotation => Some(ObjectType::Annotation(object.to_annotation())),
+            ObjectState::Unset => None,
+        }
+    }
+
+    /// Parses the source of a single java file. The type name comes from the parsed
+    /// declaration, so no file path is needed. Only the first type declared in the
+    /// source is returned, see `parse_types` for every type, and `None` when the source
+    /// declares no type.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The contents of the java file
+    pub fn parse_str(source: &str) -> Option<ObjectType> {
+        construct_ast(lex_contents(&source.to_string()))
+    }
+
+    /// Parses every top-level type declared in the source of a single java file
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The contents of the java file
+    pub fn parse_types(source: &str) -> Vec<ObjectType> {
+        construct_types(lex_contents(&source.to_string()))
+    }
+
+    /// Parses the source of a single java file, returning an error for the first
+    /// construct the parser does not support
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The contents of the java file
+    pub fn parse_str_strict(source: &str) -> Result<Vec<ObjectType>, ParseError> {
+        construct_ast_strict(lex_contents(&source.to_string()))
+    }
+
+    /// Parses the source of a single java file, also returning the diagnostics for
+    /// the constructs that were skipped
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The contents of the java file
+    pub fn parse_str_with_diagnostics(source: &str) -> ParseOutcome {
+        construct_ast_with_diagnostics(lex_contents(&source.to_string()))
+    }
+
+    /// Parses the source of a single java file, capturing the given custom block tags
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The contents of the java file
+    /// * `tags` - The names of the custom tags, e.g. `reviewedBy`
+    pub fn parse_str_with_tags(source: &str, tags: &[&str]) -> ParseOutcome {
+        construct_ast_with_tags(lex_contents(&source.to_string()), tags)
+    }
+
+    /// Root function of the module. Calls the lex and parse functions and returns
+    /// every top-level type declared in the file. A `package-info.java` file declares
+    /// no type, see `parse_package_info`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the java file
+    pub fn parse_file(path: &Path) -> Vec<ObjectType> {
+        if is_package_info(path) {
+            return Vec::new();
+        }
+
+        let file = File::open(path).expect("Could not open file");
+
+        match lex_reader(BufReader::new(file)).collect::<io::Result<Vec<Token>>>() {
+            Ok(tokens) => construct_types(tokens),
+            Err(_) => {
+                eprintln!("Unable to read file");
+                Vec::new()
+            }
+        }
+    }
+
+    /// Parses a java file like `parse_file`, but returns an error for the first construct
+    /// that is not supported, or when the file can't be read
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the java file
+    pub fn parse_file_strict(path: &Path) -> Result<Vec<ObjectType>, ParseError> {
+        if is_package_info(path) {
+            return Ok(Vec::new());
+        }
+
+        let file = File::open(path).expect("Could not open file");
+
+        match lex_reader(BufReader::new(file)).collect::<io::Result<Vec<Token>>>() {
+            Ok(tokens) => construct_ast_strict(tokens),
+            Err(err) => Err(ParseError::new(format!("file, it could not be read: {}", err), String::new())),
+        }
+    }
+
+    /// Recursively finds every java file under a directory, parses each one, and
+    /// aggregates the results into an `ApplicationDoc` grouped by package.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The directory containing the java source files
+    pub fn parse_directory(root: &Path) -> ApplicationDoc {
+        let mut files: Vec<PathBuf> = find_file_type(root, vec!["java"]);
+        files.sort();
+
+        parse_files(&files)
+    }
+
+    /// Parses a list of java files, in the given order, and aggregates the results
+    /// into an `ApplicationDoc` grouped by package
+    ///
+    /// # Arguments
+    ///
+    /// * `files` - The paths of the java files
+    pub fn parse_files(files: &[PathBuf]) -> ApplicationDoc {
+        let mut app = ApplicationDoc::new();
+        let files = files.to_vec();
+
+        for file in &files {
+            add_source_file(&mut app, file, parse_source_file(file));
+        }
+        add_package_infos(&mut app, &files);
+        resolve_inherited_docs(&mut app);
+
+        app
+    }
+
+    /// Gets the paths listed in the contents of a file list, one path per line. Blank
+    /// lines and lines starting with `#` are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `contents` - The contents of the file list
+    pub fn parse_file_list(contents: &str) -> Vec<PathBuf> {
+        contents
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with("#"))
+            .map(PathBuf::from)
+            .collect()
+    }
+
+    /// Reads a file list such as the `filelist.txt` of a `@filelist.txt` argument
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file list
+    pub fn read_file_list(path: &Path) -> Vec<PathBuf> {
+        let file = File::open(path).expect("Could not open the file list");
+        let mut contents = String::new();
+
+        BufReader::new(file)
+            .read_to_string(&mut contents)
+            .expect("Unable to read the file list");
+
+        parse_file_list(&contents)
+    }
+
+    /// Parses every java file under a directory and summarizes the project without
+    /// generating any documentation. The lint warnings of every type are counted.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The directory containing the java source files
+    pub fn parse_stats(root: &Path) -> ProjectStats {
+        app_stats(&parse_directory(root))
+    }
+
+    /// Summarizes a parsed application, counting the lint warnings of every type
+    ///
+    /// # Arguments
+    ///
+    /// * `app` - The parsed application
+    pub fn app_stats(app: &ApplicationDoc) -> ProjectStats {
+        let mut stats = ProjectStats::from_app(app);
+
+        for object in &app.objects {
+            for warning in lint_object(object) {
+                stats.add_warning(&warning);
+            }
+        }
+
+        stats
+    }
+
+    /// Parses the java files under a directory concurrently using a pool of worker
+    /// threads. The parsed types are added to the `ApplicationDoc` in file path
+    /// order, so the result is identical to `parse_directory`.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The directory containing the java source files
+    /// * `threads` - The number of worker threads to parse with
+    pub fn parse_directory_parallel(root: &Path, threads: usize) -> ApplicationDoc {
+        let mut files: Vec<PathBuf> = find_file_type(root, vec!["java"]);
+        files.sort();
+
+        let pool = ThreadPool::new(cmp::max(threads, 1));
+        let parsed = Arc::new(Mutex::new(Vec::new()));
+
+        for (i, file) in files.clone().into_iter().enumerate() {
+            let parsed_cp = parsed.clone();
+
+            pool.execute(move || {
+                let file_objects = parse_source_file(&file);
+                parsed_cp.lock().unwrap_or_else(|e| e.into_inner()).push((i, file_objects));
+            });
+        }
+
+        pool.join();
+
+        // A worker that panicked poisons the lock, the files parsed by the others are kept
+        let mut objects = match Arc::try_unwrap(parsed) {
+            Ok(res) => res.into_inner().unwrap_or_else(|e| e.into_inner()),
+            Err(shared) => mem::take(&mut *shared.lock().unwrap_or_else(|e| e.into_inner())),
+        };
+        objects.sort_by_key(|&(i, _)| i);
+
+        let mut app = ApplicationDoc::new();
+        for (i, file_objects) in objects {
+            add_source_file(&mut app, &files[i], file_objects);
+        }
+        add_package_infos(&mut app, &files);
+        resolve_inherited_docs(&mut app);
+
+        app
+    }
+
+    /// Adds the types parsed from a java file to an application, counting the file
+    /// once when it declares any type
+    ///
+    /// # Arguments
+    ///
+    /// * `app` - The application the types are added to
+    /// * `file` - The path of the java file
+    /// * `objects` - The types declared in the file, including nested types
+    fn add_source_file(app: &mut ApplicationDoc, file: &Path, objects: Vec<ObjectType>) {
+        if !objects.is_empty() {
+            app.add_file(&file.to_string_lossy());
+        }
+        for object in objects {
+            app.add_object(object);
+        }
+    }
+
+    /// Adds the descriptions from the `package-info.java` files of a project to its
+    /// packages
+    ///
+    /// # Arguments
+    ///
+    /// * `app` - The parsed application
+    /// * `files` - The java files of the project
+    fn add_package_infos(app: &mut ApplicationDoc, files: &[PathBuf]) {
+        for file in files.iter().filter(|f| is_package_info(f)) {
+            if let Some(package) = parse_package_info(file) {
+                app.add_package_info(package);
+            }
         }
     }
+
+    /// The documentation a method can inherit from an overridden method
+    struct InheritedDoc {
+        name: String,
+        param_types: Vec<String>,
+        inherits: bool,
+        description: String,
+        params: Vec<Param>,
+        return_desc: String,
+    }
+
+    /// Joins the source lines of a declaration into a single line signature with
+    /// single spaces between its parts
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - The source lines of the declaration
+    fn normalize_signature(lines: &str) -> String {
+        let words: Vec<&str> = lines.split_whitespace().collect();
+
+        words
+            .join(" ")
+            .replace("( ", "(")
+            .replace(" )", ")")
+            .replace(" ,", ",")
+    }
+
+    /// Records a construct the parser does not support. Parsing continues and the
+    /// construct is left out of the documentation.
+    ///
+    /// # Arguments
+    ///
+    /// * `diagnostics` - The unsupported constructs found so far
+    /// * `construct` - A description of the construct
+    /// * `line_num` - The line the construct is on
+    fn unsupported(diagnostics: &mut Vec<Diagnostic>, construct: String, line_num: &str) {
+        diagnostics.push(Diagnostic::new(construct, Some(line_num.to_string())));
+    }
+
+    /// Gets the simple name of a type reference such as `com.example.Base<T>`
+    fn simple_type_name(name: &str) -> String {
+        let base = name.split("<").next().unwrap_or("").trim();
+
+        base.split(".").last().unwrap_or("").to_string()
+    }
+
+    /// Joins the symbols of a type such as `String []` into one type, keeping the array
+    /// brackets next to the element type
+    fn join_type(symbols: &[String]) -> String {
+        let mut type_name = String::new();
+
+        for symbol in symbols {
+            if !type_name.is_empty() && !symbol.starts_with("[") {
+                type_name.push(' ');
+            }
+            type_name.push_str(symbol.as_str());
+        }
+
+        type_name
+    }
+
+    /// Determines whether a method's documentation should come from the method it overrides
+    fn inherits_doc(method: &Method) -> bool {
+        let description = method.description.trim();
+
+        description == "{@inheritDoc}"
+            || (description.is_empty() && method.annotations.iter().any(|a| a.name == "Override"))
+    }
+
+    /// Finds the documentation of the method a method overrides by searching the
+    /// parent class and implemented interfaces of a type, and their ancestors
+    ///
+    /// # Arguments
+    ///
+    /// * `types` - The supertypes and method docs of every parsed type by name
+    /// * `type_name` - The type to search the supertypes of
+    /// * `name` - The name of the overriding method
+    /// * `param_types` - The parameter types of the overriding method
+    /// * `visited` - The types already searched, guards against cycles
+    fn find_inherited_doc<'a>(
+        types: &'a HashMap<String, (Vec<String>, Vec<InheritedDoc>)>,
+        type_name: &str,
+        name: &str,
+        param_types: &Vec<String>,
+        visited: &mut Vec<String>,
+    ) -> Option<&'a InheritedDoc> {
+        if visited.iter().any(|t| t == type_name) {
+            return None;
+        }
+        visited.push(type_name.to_string());
+
+        let supers = match types.get(type_name) {
+            Some((supers, _)) => supers,
+            None => return None,
+        };
+
+        for parent in supers {
+            if let Some((_, methods)) = types.get(parent) {
+                if let Some(doc) = methods.iter().find(|m| m.name == name && &m.param_types == param_types) {
+                    if !doc.inherits {
+                        return Some(doc);
+                    }
+                }
+            }
+
+            if let Some(doc) = find_inherited_doc(types, parent, name, param_types, visited) {
+                return Some(doc);
+            }
+        }
+
+        None
+    }
+
+    /// Fills in the documentation of methods that use `{@inheritDoc}`, or that override
+    /// a method and have no documentation, from the overridden method in a parent
+    /// class or implemented interface. The parent has to be part of the application.
+    ///
+    /// # Arguments
+    ///
+    /// * `app` - The parsed application
+    pub fn resolve_inherited_docs(app: &mut ApplicationDoc) {
+        let mut types: HashMap<String, (Vec<String>, Vec<InheritedDoc>)> = HashMap::new();
+
+        for object in &app.objects {
+            let (name, supers, methods) = match object {
+                ObjectType::Class(class) => {
+                    let mut supers: Vec<String> = class.interfaces.iter().map(|i| simple_type_name(i)).collect();
+                    if !class.parent.is_empty() {
+                        supers.insert(0, simple_type_name(&class.parent));
+                    }
+                    (&class.name, supers, &class.methods)
+                }
+                ObjectType::Interface(inter) => (
+                    &inter.name,
+                    inter.parents.iter().map(|i| simple_type_name(i)).collect(),
+                    &inter.methods,
+                ),
+                ObjectType::Enumeration(enum_ob) => (
+                    &enum_ob.name,
+                    enum_ob.interfaces.iter().map(|i| simple_type_name(i)).collect(),
+                    &enum_ob.methods,
+                ),
+                ObjectType::Annotation(_) => continue,
+            };
+
+            let docs = methods
+                .iter()
+                .map(|m| InheritedDoc {
+                    name: m.name.clone(),
+                    param_types: m.parameters.iter().map(|p| p.var_type.clone()).collect(),
+                    inherits: inherits_doc(m),
+                    description: m.description.clone(),
+                    params: m.parameters.clone(),
+                    return_desc: m.return_desc.clone(),
+                })
+                .collect();
+
+            types.insert(name.clone(), (supers, docs));
+        }
+
+        for object in app.objects.iter_mut() {
+            let (type_name, methods) = match object {
+                ObjectType::Class(class) => (class.name.clone(), &mut class.methods),
+                ObjectType::Interface(inter) => (inter.name.clone(), &mut inter.methods),
+                ObjectType::Enumeration(enum_ob) => (enum_ob.name.clone(), &mut enum_ob.methods),
+                ObjectType::Annotation(_) => continue,
+            };
+
+            for method in methods.iter_mut() {
+                if !inherits_doc(method) {
+                    continue;
+                }
+
+                let param_types: Vec<String> = method.parameters.iter().map(|p| p.var_type.clone()).collect();
+                let inherited = find_inherited_doc(&types, &type_name, &method.name, &param_types, &mut Vec::new());
+
+                if let Some(doc) = inherited {
+                    method.ch_description(doc.description.clone());
+
+                    for (i, param) in method.parameters.iter_mut().enumerate() {
+                        if param.desc.is_empty() && i < doc.params.len() {
+                            param.desc = doc.params[i].desc.clone();
+                        }
+                    }
+
+                    if method.return_desc.is_empty() && !doc.return_desc.is_empty() {
+                        method.ch_return_desc(doc.return_desc.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Parses a java file and records its path on the parsed type
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The path of the java file
+    fn parse_source_file(file: &Path) -> Vec<ObjectType> {
+        let path = file.to_str().unwrap().to_string();
+
+        parse_file(file)
+            .into_iter()
+            .map(|object| match object {
+                ObjectType::Class(mut class) => {
+                    class.ch_file_path(path.clone());
+                    ObjectType::Class(class)
+                }
+                ObjectType::Interface(mut inter) => {
+                    inter.ch_file_path(path.clone());
+                    ObjectType::Interface(inter)
+                }
+                ObjectType::Enumeration(mut enumeration) => {
+                    enumeration.ch_file_path(path.clone());
+                    ObjectType::Enumeration(enumeration)
+                }
+                ObjectType::Annotation(mut annotation) => {
+                    annotation.ch_file_path(path.clone());
+                    ObjectType::Annotation(annotation)
+                }
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,15 @@
+use document::document::lint_object;
+use grammar::grammar::JdocToken;
+use grammar::grammar::Stream;
 use grammar::grammar::Token;
 use model::model::*;
 use parse::parse::*;
+use serde_json;
+
+use std::fs;
+use std::io;
+use std::io::BufReader;
+use std::path::Path;
 
 #[test]
 fn test_method_lex() {
@@ -172,3 +181,1964 @@ fn test_param_match() {
         String::from("Map<List<Object>, Map<String, List<String>>>")
     );
 }
+
+#[test]
+fn test_comment_structural_chars() {
+    let j_class = "public class Sample {
+                    // if (x) { y();
+                    /* a stray } and ; */
+                    public int getValue(int a) {
+                        return a;
+                    }
+                }";
+
+    let tokens = lex_contents(&j_class.to_string());
+
+    match construct_ast(tokens).unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!("Sample", class.name.as_str());
+            assert_eq!(1, class.methods.len());
+            assert_eq!("getValue", class.methods[0].name.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_interface_method_bodies() {
+    let j_inter = "public interface Sized {
+                    int count();
+
+                    /**
+                     * The size
+                     */
+                    default int size() { return count(); }
+
+                    static Sized empty() {
+                        return null;
+                    }
+                }";
+
+    let tokens = lex_contents(&j_inter.to_string());
+
+    match construct_ast(tokens).unwrap() {
+        ObjectType::Interface(inter) => {
+            assert_eq!("Sized", inter.name.as_str());
+            assert_eq!(3, inter.methods.len());
+            assert_eq!("count", inter.methods[0].name.as_str());
+            assert_eq!("size", inter.methods[1].name.as_str());
+            assert_eq!(vec!["default".to_string()], inter.methods[1].modifiers);
+            assert_eq!("int", inter.methods[1].return_type.as_str());
+            assert_eq!("empty", inter.methods[2].name.as_str());
+            assert_eq!(vec!["static".to_string()], inter.methods[2].modifiers);
+        }
+        _ => panic!("Expected an interface"),
+    }
+}
+
+#[test]
+fn test_generic_type_params() {
+    let j_class = "public class Box<T extends Comparable<T>, U> {
+                    public <K, V extends List<K>> Map<K, V> group(List<V> values) {
+                        return null;
+                    }
+                }";
+
+    let tokens = lex_contents(&j_class.to_string());
+
+    match construct_ast(tokens).unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!("Box", class.name.as_str());
+            assert_eq!(
+                vec!["T extends Comparable<T>".to_string(), "U".to_string()],
+                class.type_params
+            );
+
+            let method = &class.methods[0];
+            assert_eq!("group", method.name.as_str());
+            assert_eq!("Map<K, V>", method.return_type.as_str());
+            assert_eq!(
+                vec!["K".to_string(), "V extends List<K>".to_string()],
+                method.type_params
+            );
+            assert_eq!("List<V>", method.parameters[0].var_type.as_str());
+            assert_eq!("values", method.parameters[0].name.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_parse_directory() {
+    let app = parse_directory(Path::new("tests/fixtures/project"));
+
+    assert_eq!(3, app.file_num);
+    assert_eq!(1, app.class_num);
+    assert_eq!(1, app.interface_num);
+    assert_eq!(1, app.enum_num);
+    assert_eq!(3, app.objects.len());
+    assert_eq!(2, app.packages.len());
+
+    assert_eq!("com.example.model", app.packages[0].name.as_str());
+    assert_eq!("com/example/model", app.packages[0].package_path.as_str());
+    assert_eq!(vec!["Role".to_string(), "User".to_string()], app.packages[0].members);
+
+    assert_eq!("com.example.service", app.packages[1].name.as_str());
+    assert_eq!(vec!["UserService".to_string()], app.packages[1].members);
+}
+
+#[test]
+fn test_object_line_number() {
+    let j_class = "package com.example;
+
+import java.util.List;
+
+/**
+ * A documented class
+ */
+public class Located {
+    public void run() {}
+}";
+
+    match construct_ast(lex_contents(&j_class.to_string())).unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!("8", class.line_num.as_str());
+            assert_eq!("9", class.methods[0].line_num.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_parse_directory_parallel() {
+    let serial = parse_directory(Path::new("tests/fixtures/project"));
+
+    for threads in 1..5 {
+        let parallel = parse_directory_parallel(Path::new("tests/fixtures/project"), threads);
+
+        assert_eq!(
+            serde_json::to_string(&serial).unwrap(),
+            serde_json::to_string(&parallel).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_since_and_version() {
+    let j_class = "/**
+ * A versioned class
+ *
+ * @since 1.0
+ * @version 2.3
+ */
+public class Versioned {
+}";
+
+    match construct_ast(lex_contents(&j_class.to_string())).unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!("1.0", class.since.as_str());
+            assert_eq!("2.3", class.version.as_str());
+            assert_eq!(None, class.deprecation);
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_serial_tags() {
+    let j_class = "/**
+ * A serializable point
+ *
+ * @serialField x int The horizontal position
+ * @serialData The coordinates are written as two ints
+ */
+public class Point {
+}";
+
+    match construct_ast(lex_contents(&j_class.to_string())).unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!("A serializable point", class.description.as_str());
+            assert_eq!(1, class.serial_fields.len());
+            assert_eq!("x", class.serial_fields[0].name.as_str());
+            assert_eq!("int", class.serial_fields[0].field_type.as_str());
+            assert_eq!("The horizontal position", class.serial_fields[0].desc.as_str());
+            assert_eq!("The coordinates are written as two ints", class.serial_data.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_serial_field_spacing() {
+    let doc = parse_doc_comment("/**
+ * @serialField  y\tint   The  vertical\tposition
+ * @serialField\tz long
+ * @serialField w
+ *
+ * short The depth
+ */");
+
+    assert_eq!(3, doc.serial_fields.len());
+    assert_eq!("y", doc.serial_fields[0].name.as_str());
+    assert_eq!("int", doc.serial_fields[0].field_type.as_str());
+    assert_eq!("The vertical position", doc.serial_fields[0].desc.as_str());
+    assert_eq!("z", doc.serial_fields[1].name.as_str());
+    assert_eq!("long", doc.serial_fields[1].field_type.as_str());
+    assert_eq!("", doc.serial_fields[1].desc.as_str());
+    assert_eq!("w", doc.serial_fields[2].name.as_str());
+    assert_eq!("short", doc.serial_fields[2].field_type.as_str());
+    assert_eq!("The depth", doc.serial_fields[2].desc.as_str());
+}
+
+#[test]
+fn test_resolve_inherited_docs() {
+    let parent = "public class Shape {
+    /**
+     * Scales the shape
+     *
+     * @param factor The scale factor
+     * @return The scaled area
+     */
+    public double scale(double factor) {
+        return factor;
+    }
+}";
+    let named = "public interface Named {
+    /** Gets the display name */
+    String name();
+}";
+    let child = "public class Circle extends Shape implements Named {
+    /** {@inheritDoc} */
+    public double scale(double factor) {
+        return factor;
+    }
+
+    @Override
+    public double scale(double factor, double limit) {
+        return factor;
+    }
+
+    @Override
+    public String name() {
+        return null;
+    }
+}";
+
+    let mut app = ApplicationDoc::new();
+    app.add_object(construct_ast(lex_contents(&parent.to_string())).unwrap());
+    app.add_object(construct_ast(lex_contents(&named.to_string())).unwrap());
+    app.add_object(construct_ast(lex_contents(&child.to_string())).unwrap());
+    resolve_inherited_docs(&mut app);
+
+    match app.objects[2] {
+        ObjectType::Class(ref class) => {
+            assert_eq!("Scales the shape", class.methods[0].description.as_str());
+            assert_eq!("The scale factor", class.methods[0].parameters[0].desc.as_str());
+            assert_eq!("The scaled area", class.methods[0].return_desc.as_str());
+            assert_eq!("Override", class.methods[1].annotations[0].name.as_str());
+            assert_eq!("", class.methods[1].description.as_str());
+            assert_eq!("Gets the display name", class.methods[2].description.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_imports() {
+    let j_class = "package com.example;
+
+import java.util.List;
+import static java.lang.Math.max;
+import java.io.*;
+
+public class Imports {
+}";
+
+    match construct_ast(lex_contents(&j_class.to_string())).unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!(3, class.imports.len());
+
+            assert_eq!("java.util.List", class.imports[0].path.as_str());
+            assert!(!class.imports[0].is_static);
+            assert!(!class.imports[0].wildcard);
+
+            assert_eq!("java.lang.Math.max", class.imports[1].path.as_str());
+            assert!(class.imports[1].is_static);
+            assert!(!class.imports[1].wildcard);
+
+            assert_eq!("java.io", class.imports[2].path.as_str());
+            assert!(!class.imports[2].is_static);
+            assert!(class.imports[2].wildcard);
+
+            assert_eq!(
+                vec!["java.util.List", "java.lang.Math.max", "java.io.*"],
+                class.dependencies
+            );
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_stacked_annotations() {
+    let j_class = "public class Annotated {
+    @Inject
+    private Clock clock;
+
+    /** Runs the task */
+    @Deprecated
+    @SuppressWarnings(value = {\"unchecked\", \"rawtypes\"})
+    public void run(@NonNull String name) {
+    }
+}";
+
+    match construct_ast(lex_contents(&j_class.to_string())).unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!(1, class.variables[0].annotations.len());
+            assert_eq!("Inject", class.variables[0].annotations[0].name.as_str());
+            assert_eq!("clock", class.variables[0].name.as_str());
+
+            let method = &class.methods[0];
+            assert_eq!("run", method.name.as_str());
+            assert_eq!("Runs the task", method.description.as_str());
+            assert_eq!("8", method.line_num.as_str());
+            assert_eq!(2, method.annotations.len());
+            assert_eq!("Deprecated", method.annotations[0].name.as_str());
+            assert_eq!("SuppressWarnings", method.annotations[1].name.as_str());
+            assert_eq!(1, method.parameters.len());
+            assert_eq!("name", method.parameters[0].name.as_str());
+            assert!(method.is_deprecated());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_description_paragraphs() {
+    let j_class = "/**
+ * The first paragraph of the description
+ * spans two lines.
+ *
+ * The second paragraph.
+ * <p>The third paragraph.
+ *
+ * @since 1.0
+ */
+public class Paragraphs {
+}";
+
+    match construct_ast(lex_contents(&j_class.to_string())).unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!(
+                "The first paragraph of the description spans two lines.\n\nThe second paragraph.\n\nThe third paragraph.",
+                class.description.as_str()
+            );
+            assert_eq!("1.0", class.since.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_constructors() {
+    let j_class = "public class Account {
+        private int balance;
+
+        /** Creates an empty account */
+        public Account() {
+            balance = 0;
+        }
+
+        /**
+         * Creates an account with a starting balance
+         *
+         * @param balance The starting balance
+         */
+        public Account(int balance) {
+            this.balance = balance;
+        }
+
+        /** Gets the balance */
+        public int getBalance() {
+            return balance;
+        }
+    }";
+
+    match construct_ast(lex_contents(&j_class.to_string())).unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!(3, class.methods.len());
+
+            for constructor in &class.methods[0..2] {
+                assert!(constructor.is_constructor);
+                assert_eq!("Account", constructor.name.as_str());
+                assert_eq!("", constructor.return_type.as_str());
+            }
+            assert_eq!(0, class.methods[0].parameters.len());
+            assert_eq!("balance", class.methods[1].parameters[0].name.as_str());
+            assert_eq!("The starting balance", class.methods[1].parameters[0].desc.as_str());
+
+            assert!(!class.methods[2].is_constructor);
+            assert_eq!("getBalance", class.methods[2].name.as_str());
+            assert_eq!("int", class.methods[2].return_type.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_member_modifiers() {
+    let j_class = "public class Cache {
+        private transient int x;
+        public static final int max;
+        protected volatile int hits;
+    }";
+
+    match construct_ast(lex_contents(&j_class.to_string())).unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!("x", class.variables[0].name.as_str());
+            assert_eq!(Access::Private, class.variables[0].access);
+            assert_eq!(vec!["transient"], class.variables[0].modifiers);
+            assert_eq!("2", class.variables[0].line_num.as_str());
+
+            assert_eq!("max", class.variables[1].name.as_str());
+            assert_eq!(vec!["static", "final"], class.variables[1].modifiers);
+            assert_eq!("3", class.variables[1].line_num.as_str());
+
+            assert_eq!(vec!["volatile"], class.variables[2].modifiers);
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_field_initializers() {
+    let j_class = "public class Settings {
+        public static final int MAX = 100;
+        private List<String> xs = new ArrayList<>();
+        private Map<String, Integer> counts=Collections.unmodifiableMap(load(\"counts\", 2));
+        private int plain;
+    }";
+
+    match construct_ast(lex_contents(&j_class.to_string())).unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!(4, class.variables.len());
+
+            assert_eq!("MAX", class.variables[0].name.as_str());
+            assert_eq!("int", class.variables[0].var_type.as_str());
+            assert_eq!("100", class.variables[0].default_value.as_str());
+
+            assert_eq!("xs", class.variables[1].name.as_str());
+            assert_eq!("List<String>", class.variables[1].var_type.as_str());
+            assert_eq!("new ArrayList<>()", class.variables[1].default_value.as_str());
+
+            assert_eq!("counts", class.variables[2].name.as_str());
+            assert_eq!("Map<String, Integer>", class.variables[2].var_type.as_str());
+            assert_eq!(
+                "Collections.unmodifiableMap(load(\"counts\", 2))",
+                class.variables[2].default_value.as_str()
+            );
+
+            assert_eq!("plain", class.variables[3].name.as_str());
+            assert_eq!("", class.variables[3].default_value.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_interface_constant() {
+    let j_inter = "public interface Limits {
+        int LIMIT = 5;
+
+        /** Gets the limit */
+        int limit();
+    }";
+
+    match construct_ast(lex_contents(&j_inter.to_string())).unwrap() {
+        ObjectType::Interface(inter) => {
+            assert_eq!(1, inter.variables.len());
+            assert_eq!("LIMIT", inter.variables[0].name.as_str());
+            assert_eq!("5", inter.variables[0].default_value.as_str());
+            assert_eq!(1, inter.methods.len());
+            assert_eq!("limit", inter.methods[0].name.as_str());
+        }
+        _ => panic!("Expected an interface"),
+    }
+}
+
+#[test]
+fn test_throws_and_exception_tags() {
+    let j_class = "public class Loader {
+        /**
+         * Loads a resource
+         *
+         * @param name The resource name
+         * @exception java.io.IOException When the resource cannot be read
+         * @throws IllegalArgumentException When the name is empty
+         */
+        public String load(String name) throws IOException {
+            return name;
+        }
+    }";
+
+    match construct_ast(lex_contents(&j_class.to_string())).unwrap() {
+        ObjectType::Class(class) => {
+            let exceptions = &class.methods[0].exceptions;
+
+            assert_eq!(2, exceptions.len());
+            assert_eq!("IOException", exceptions[0].exception_type.as_str());
+            assert_eq!("When the resource cannot be read", exceptions[0].desc.as_str());
+            assert_eq!("exception", exceptions[0].tag.as_str());
+            assert_eq!("IllegalArgumentException", exceptions[1].exception_type.as_str());
+            assert_eq!("When the name is empty", exceptions[1].desc.as_str());
+            assert_eq!("throws", exceptions[1].tag.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_multibyte_identifiers() {
+    let j_class = "\u{feff}package com.example;
+
+/**
+ * Größe eines Kartons\u{3000}für Ärzte
+ */
+public class Karton {
+    /** 箱の幅（センチメートル） */
+    private int größe;
+
+    /**
+     * 大きさを設定する
+     *
+     * @param größe 新しい大きさ
+     */
+    public void setGröße(int größe) {
+        this.größe = größe;
+    }
+}";
+
+    match construct_ast(lex_contents(&j_class.to_string())).unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!("com.example", class.package_name.as_str());
+            assert_eq!("Größe eines Kartons für Ärzte", class.description.as_str());
+            assert_eq!("größe", class.variables[0].name.as_str());
+            assert_eq!("setGröße", class.methods[0].name.as_str());
+            assert_eq!("大きさを設定する", class.methods[0].description.as_str());
+            assert_eq!("größe", class.methods[0].parameters[0].name.as_str());
+            assert_eq!("新しい大きさ", class.methods[0].parameters[0].desc.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_code_spans() {
+    let j_class = "/**
+ * Formats a value. Call it like this:
+ * {@code
+ * Formatter formatter = new Formatter();
+ * formatter.format(value);
+ * }
+ * The {@code format} method escapes {@literal <b>} tags.
+ */
+public class Formatter {
+}";
+
+    match construct_ast(lex_contents(&j_class.to_string())).unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!(
+                vec![
+                    DocSpan::Text("Formats a value. Call it like this: ".to_string()),
+                    DocSpan::CodeBlock("Formatter formatter = new Formatter();\nformatter.format(value);".to_string()),
+                    DocSpan::Text(" The ".to_string()),
+                    DocSpan::Code("format".to_string()),
+                    DocSpan::Text(" method escapes ".to_string()),
+                    DocSpan::Literal("<b>".to_string()),
+                    DocSpan::Text(" tags.".to_string()),
+                ],
+                DocSpan::split(&class.description)
+            );
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_members_in_declaration_order() {
+    let j_class = "public class Counter {
+        private int count;
+
+        public Counter() {
+            count = 0;
+        }
+
+        public void increment() {
+            count++;
+        }
+
+        private int step;
+
+        public int getCount() {
+            return count;
+        }
+    }";
+    let class = construct_ast(lex_contents(&j_class.to_string())).unwrap();
+
+    let names: Vec<String> = class
+        .members()
+        .map(|member| match member {
+            MemberRef::Field(field) => format!("field {}", field.name),
+            MemberRef::Constructor(method) => format!("constructor {}", method.name),
+            MemberRef::Method(method) => format!("method {}", method.name),
+        })
+        .collect();
+
+    assert_eq!(
+        vec![
+            "field count",
+            "constructor Counter",
+            "method increment",
+            "field step",
+            "method getCount",
+        ],
+        names
+    );
+}
+
+#[test]
+fn test_parse_str() {
+    let source = "package com.example;
+
+/** A piped class */
+public class Piped {
+    /** Runs the pipeline */
+    public void run() {}
+}";
+
+    match parse_str(source).unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!("Piped", class.name.as_str());
+            assert_eq!("com.example", class.package_name.as_str());
+            assert_eq!("", class.file_path.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_return_description() {
+    let source = "package com.example;
+
+public class Range {
+    /**
+     * Gets the largest value of the range
+     * @return the maximum value
+     */
+    public int max() {
+        return high;
+    }
+}";
+
+    match parse_str(source).unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!("int", class.methods[0].return_type.as_str());
+            assert_eq!("the maximum value", class.methods[0].return_desc.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_composite_return_types() {
+    let source = "package com.example;
+
+public class Names {
+    public List<String> names() {
+        return list;
+    }
+
+    public String[] toArray() {
+        return array;
+    }
+
+    public Map.Entry<String, Integer> first() {
+        return entry;
+    }
+
+    public int [] counts() {
+        return counts;
+    }
+}";
+
+    match parse_str(source).unwrap() {
+        ObjectType::Class(class) => {
+            let methods: Vec<(&str, &str)> = class
+                .methods
+                .iter()
+                .map(|m| (m.return_type.as_str(), m.name.as_str()))
+                .collect();
+
+            assert_eq!(
+                vec![
+                    ("List<String>", "names"),
+                    ("String[]", "toArray"),
+                    ("Map.Entry<String, Integer>", "first"),
+                    ("int[]", "counts"),
+                ],
+                methods
+            );
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_strict_mode() {
+    let source = "package com.example;
+
+public class Limits {
+    public int count;
+
+    public const int MAX = 10;
+}";
+
+    let error = match parse_str_strict(source) {
+        Err(error) => error,
+        Ok(_) => panic!("Expected an unsupported keyword error"),
+    };
+
+    assert_eq!(ParseError::new("keyword const".to_string(), "6".to_string()), error);
+    assert_eq!("Unsupported keyword const on line 6", error.to_string());
+
+    match parse_str(source).unwrap() {
+        ObjectType::Class(class) => assert_eq!("count", class.variables[0].name.as_str()),
+        _ => panic!("Expected a class"),
+    }
+
+    let supported = "package com.example;
+
+public class Limits {
+    public static final int MAX = 10;
+}";
+
+    assert!(parse_str_strict(supported).is_ok());
+}
+
+#[test]
+fn test_parse_file_strict() {
+    let path = Path::new("tests/fixtures/nested/com/example/Outer.java");
+    assert_eq!(parse_file(path).len(), parse_file_strict(path).unwrap().len());
+
+    let info = Path::new("tests/fixtures/project/com/example/model/package-info.java");
+    assert_eq!(0, parse_file_strict(info).unwrap().len());
+}
+
+#[test]
+fn test_parse_diagnostics() {
+    let source = "package com.example;
+
+/**
+ * A class with limits
+ * @inheritDoc
+ */
+public class Limits {
+    public int count;
+
+    public const int MAX = 10;
+}";
+
+    let outcome = parse_str_with_diagnostics(source);
+
+    assert_eq!(
+        vec![
+            Diagnostic::new("javadoc tag @inheritDoc".to_string(), None),
+            Diagnostic::new("keyword const".to_string(), Some("10".to_string())),
+        ],
+        outcome.diagnostics
+    );
+    assert_eq!("Unsupported keyword const on line 10", outcome.diagnostics[1].message());
+
+    match &outcome.objects[0] {
+        ObjectType::Class(class) => assert_eq!("Limits", class.name.as_str()),
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_generic_tag_types() {
+    let source = "package com.example;
+
+public class Tally {
+    /**
+     * Merges the counts into the tally
+     * @param counts the Map<String, Integer> of counts to merge
+     * @throws Rejected<Map<String, Integer>> when a count is negative
+     */
+    public void merge(Map<String, Integer> counts) {
+        total = 0;
+    }
+}";
+
+    match parse_str(source).unwrap() {
+        ObjectType::Class(class) => {
+            let method = &class.methods[0];
+
+            assert_eq!("Map<String, Integer>", method.parameters[0].var_type.as_str());
+            assert_eq!("counts", method.parameters[0].name.as_str());
+            assert_eq!("the Map<String, Integer> of counts to merge", method.parameters[0].desc.as_str());
+            assert_eq!("Rejected<Map<String, Integer>>", method.exceptions[0].exception_type.as_str());
+            assert_eq!("when a count is negative", method.exceptions[0].desc.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_enum_members() {
+    let source = "package com.example;
+
+/** The sizes of a drink */
+public enum Size {
+    SMALL, MEDIUM, LARGE;
+
+    /** The label shown on the menu */
+    private String label;
+
+    /**
+     * Gets the label of the size
+     * @return the menu label
+     */
+    public String getLabel() {
+        return label;
+    }
+}";
+
+    match parse_str(source).unwrap() {
+        ObjectType::Enumeration(enum_ob) => {
+            let constants: Vec<&str> = enum_ob.fields.iter().map(|f| f.name.as_str()).collect();
+
+            assert_eq!(vec!["SMALL", "MEDIUM", "LARGE"], constants);
+            assert_eq!(1, enum_ob.variables.len());
+            assert_eq!("label", enum_ob.variables[0].name.as_str());
+            assert_eq!("String", enum_ob.variables[0].var_type.as_str());
+            assert_eq!(1, enum_ob.methods.len());
+            assert_eq!("getLabel", enum_ob.methods[0].name.as_str());
+            assert_eq!("String", enum_ob.methods[0].return_type.as_str());
+        }
+        _ => panic!("Expected an enum"),
+    }
+}
+
+#[test]
+fn test_multiline_signature() {
+    let source = "package com.example;
+
+public class Mailer {
+    /** The address mail is sent from */
+    private   String	sender;
+
+    /**
+     * Sends a message
+     * @param to the recipient
+     * @param subject the subject line
+     * @param body the message text
+     */
+    public void send(String to,
+            String subject,
+            String body) {
+        deliver(to);
+    }
+}";
+
+    match parse_str(source).unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!("private String sender;", class.variables[0].signature.as_str());
+            assert_eq!(
+                "public void send(String to, String subject, String body) {",
+                class.methods[0].signature.as_str()
+            );
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_multiple_top_level_types() {
+    let source = "package com.example;
+
+import java.util.List;
+
+/** Reads orders from a file */
+public class OrderReader {
+    /** Reads every order */
+    public List<Order> readAll() {
+        return orders;
+    }
+}
+
+/** A single order */
+class Order {
+    private int quantity;
+}
+
+enum OrderState { OPEN, CLOSED }";
+
+    let types = parse_types(source);
+    assert_eq!(3, types.len());
+
+    match &types[0] {
+        ObjectType::Class(class) => {
+            assert_eq!("OrderReader", class.name.as_str());
+            assert_eq!("readAll", class.methods[0].name.as_str());
+            assert_eq!(0, class.variables.len());
+        }
+        _ => panic!("Expected a class"),
+    }
+    match &types[1] {
+        ObjectType::Class(class) => {
+            assert_eq!("Order", class.name.as_str());
+            assert_eq!("A single order", class.description.as_str());
+            assert_eq!("com.example", class.package_name.as_str());
+            assert_eq!("", class.access.as_str());
+            assert_eq!("quantity", class.variables[0].name.as_str());
+            assert_eq!(0, class.methods.len());
+        }
+        _ => panic!("Expected a class"),
+    }
+    match &types[2] {
+        ObjectType::Enumeration(enum_ob) => {
+            let constants: Vec<&str> = enum_ob.fields.iter().map(|f| f.name.as_str()).collect();
+
+            assert_eq!("OrderState", enum_ob.name.as_str());
+            assert_eq!(vec!["OPEN", "CLOSED"], constants);
+        }
+        _ => panic!("Expected an enum"),
+    }
+}
+
+#[test]
+fn test_field_javadoc() {
+    let source = "package com.example;
+
+public class Buffer {
+    /** The maximum size. */
+    private int max;
+
+    private int used;
+
+    /** Empties the buffer */
+    public void clear() {
+        used = 0;
+    }
+
+    private int[] data;
+}";
+
+    match parse_str(source).unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!("max", class.variables[0].name.as_str());
+            assert_eq!("The maximum size.", class.variables[0].desc.as_str());
+            assert_eq!("used", class.variables[1].name.as_str());
+            assert_eq!("", class.variables[1].desc.as_str());
+            assert_eq!("data", class.variables[2].name.as_str());
+            assert_eq!("", class.variables[2].desc.as_str());
+            assert_eq!("Empties the buffer", class.methods[0].description.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_wrapped_block_tags() {
+    let source = "package com.example;
+
+public class Lazy {
+    /**
+     * Gets the value for a key
+     * @param key the key the value
+     *        is stored under
+     * @return the value
+     *     computed lazily
+     *and cached
+     */
+    public int get(String key) {
+        return cache.get(key);
+    }
+}";
+
+    match parse_str(source).unwrap() {
+        ObjectType::Class(class) => {
+            let method = &class.methods[0];
+
+            assert_eq!("the key the value is stored under", method.parameters[0].desc.as_str());
+            assert_eq!("the value computed lazily and cached", method.return_desc.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_package_info() {
+    let path = Path::new("tests/fixtures/project/com/example/model/package-info.java");
+    let package = parse_package_info(path).expect("Expected a package declaration");
+
+    assert_eq!("com.example.model", package.name.as_str());
+    assert_eq!("com/example/model", package.package_path.as_str());
+    assert_eq!(
+        "The domain model of the example application, shared by every service",
+        package.description.split_whitespace().collect::<Vec<&str>>().join(" ")
+    );
+    assert_eq!(0, parse_file(path).len());
+
+    let app = parse_directory(Path::new("tests/fixtures/project"));
+    assert_eq!(3, app.file_num);
+    assert_eq!(package.description, app.packages[0].description);
+    assert_eq!(vec!["Role".to_string(), "User".to_string()], app.packages[0].members);
+    assert_eq!("", app.packages[1].description.as_str());
+}
+
+#[test]
+fn test_file_count_with_nested_types() {
+    for app in vec![
+        parse_directory(Path::new("tests/fixtures/nested")),
+        parse_directory_parallel(Path::new("tests/fixtures/nested"), 2),
+    ] {
+        assert_eq!(2, app.objects.len());
+        assert_eq!(1, app.file_num);
+        assert_eq!(2, app.class_num);
+    }
+}
+
+#[test]
+fn test_license_header() {
+    let j_class = "/*
+ * Copyright 2018 Example Corp.
+ *
+ * Licensed under the Apache License, Version 2.0 (the \"License\");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ */
+package com.example;
+
+/* Not part of the license */
+public class Licensed {}";
+
+    match parse_str(j_class).unwrap() {
+        ObjectType::Class(class) => assert_eq!(
+            "Copyright 2018 Example Corp.\n\nLicensed under the Apache License, Version 2.0 (the \"License\");\nyou may not use this file except in compliance with the License.\nYou may obtain a copy of the License at\n\n    http://www.apache.org/licenses/LICENSE-2.0",
+            class.license.as_str()
+        ),
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_parse_stats() {
+    let stats = parse_stats(Path::new("tests/fixtures/project"));
+
+    assert_eq!(3, stats.file_num);
+    assert_eq!(2, stats.package_num);
+    assert_eq!(1, stats.class_num);
+    assert_eq!(1, stats.interface_num);
+    assert_eq!(1, stats.enum_num);
+    assert_eq!(0, stats.warning_num);
+    assert!(stats.summary().starts_with("Files: 3\nPackages: 2\nClasses: 1\nInterfaces: 1\nEnums: 1\nLint warnings: 0\n"));
+
+    let mut stats = ProjectStats::from_app(&ApplicationDoc::new());
+    let object = parse_str("package com.example;\n\npublic class Bare {\n    public int count(String name) {}\n}").unwrap();
+    for warning in lint_object(&object) {
+        stats.add_warning(&warning);
+    }
+
+    assert_eq!(4, stats.warning_num);
+    assert_eq!(2, stats.missing_descriptions);
+    assert_eq!(1, stats.missing_params);
+    assert_eq!(1, stats.missing_returns);
+}
+
+#[test]
+fn test_parse_file_list() {
+    let list = "# Model types
+tests/fixtures/project/com/example/model/User.java
+
+  tests/fixtures/project/com/example/model/Role.java
+# tests/fixtures/project/com/example/Missing.java
+tests/fixtures/project/com/example/service/UserService.java
+";
+    let files = parse_file_list(list);
+
+    assert_eq!(3, files.len());
+    assert_eq!(Path::new("tests/fixtures/project/com/example/model/Role.java"), files[1].as_path());
+
+    let app = parse_files(&files);
+    assert_eq!(3, app.file_num);
+    assert_eq!(1, app.class_num);
+    assert_eq!(1, app.interface_num);
+    assert_eq!(1, app.enum_num);
+    assert_eq!(vec!["User".to_string(), "Role".to_string()], app.packages[0].members);
+    assert!(app.contains("com.example.service.UserService"));
+}
+
+#[test]
+fn test_access_levels() {
+    let source = "public class Account {
+        public String owner;
+        protected String branch;
+        long balance;
+        private String pin;
+
+        public void deposit(long amount) {}
+        protected void audit() {}
+        void settle() {}
+        private void lock() {}
+    }";
+
+    let class = match parse_str(source).unwrap() {
+        ObjectType::Class(class) => class,
+        _ => panic!("Expected a class"),
+    };
+    let levels = vec![Access::Public, Access::Protected, Access::Package, Access::Private];
+    let fields: Vec<Access> = class.variables.iter().map(|v| v.access).collect();
+    let methods: Vec<Access> = class.methods.iter().map(|m| m.privacy).collect();
+
+    assert_eq!(levels, fields);
+    assert_eq!(levels, methods);
+    assert_eq!("package", class.variables[2].access.as_str());
+    assert!(serde_json::to_string(&class.methods[2]).unwrap().contains("\"privacy\":\"package\""));
+
+    let config = GenConfig::new().min_visibility(Visibility::Protected);
+    let documented: Vec<&str> = class
+        .methods
+        .iter()
+        .filter(|m| config.documents(m.privacy))
+        .map(|m| m.name.as_str())
+        .collect();
+    assert_eq!(vec!["deposit", "audit"], documented);
+
+    let config = GenConfig::new().min_visibility(Visibility::Public);
+    assert!(config.documents(Access::Public));
+    assert!(!config.documents(Access::Protected));
+    assert!(config.for_interface().documents(Access::Package));
+}
+
+#[test]
+fn test_doc_comment_openers() {
+    let j_class = "/******************************
+ * Copyright 2018 Example Corp.
+ ******************************/
+package com.example;
+
+/***
+ * A bank account
+ */
+public class Account {
+    /**< The name of the owner */
+    public String owner;
+
+    /* Not part of the documentation */
+    public long balance;
+
+    /***
+     * Deposits money into the account
+     * @param amount The amount to deposit
+     */
+    public void deposit(long amount) {}
+}";
+
+    match parse_str(j_class).unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!("Copyright 2018 Example Corp.", class.license.as_str());
+            assert_eq!("A bank account", class.description.as_str());
+            assert_eq!("The name of the owner", class.variables[0].desc.as_str());
+            assert_eq!("", class.variables[1].desc.as_str());
+            assert_eq!("Deposits money into the account", class.methods[0].description.as_str());
+            assert_eq!("The amount to deposit", class.methods[0].parameters[0].desc.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_nested_types() {
+    let source = "package com.example;
+
+public class Outer {
+    public void start() {
+        Runnable task = new Runnable() {
+            public void run() {}
+        };
+    }
+
+    public static class Inner {
+        public Inner(int count) {}
+
+        public void run() {}
+    }
+
+    enum State { ON, OFF }
+
+    public void stop() {}
+}";
+    let types = construct_types(lex_contents(&source.to_string()));
+    let names: Vec<&str> = types
+        .iter()
+        .map(|t| match t {
+            ObjectType::Class(class) => class.name.as_str(),
+            ObjectType::Interface(inter) => inter.name.as_str(),
+            ObjectType::Enumeration(enum_ob) => enum_ob.name.as_str(),
+            ObjectType::Annotation(annotation) => annotation.name.as_str(),
+        })
+        .collect();
+
+    assert_eq!(vec!["Outer", "Outer.Inner", "Outer.State"], names);
+    match &types[0] {
+        ObjectType::Class(class) => {
+            let methods: Vec<&str> = class.methods.iter().map(|m| m.name.as_str()).collect();
+
+            assert_eq!(vec!["start", "stop"], methods);
+            assert_eq!("public class Outer {", class.signature.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+    match &types[1] {
+        ObjectType::Class(class) => {
+            assert!(class.methods[0].is_constructor);
+            assert_eq!("run", class.methods[1].name.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_construct_ast_debug() {
+    let j_class = "public class Calculator {
+    /** Adds two numbers */
+    public static int add(int a, int b) {
+        return a + b;
+    }
+}";
+    let (outcome, trace) = construct_ast_debug(lex_contents(&j_class.to_string()));
+
+    assert_eq!(1, outcome.objects.len());
+    assert_eq!(2, trace.len());
+    assert_eq!(
+        vec![
+            Stream::Access("public".to_string()),
+            Stream::Object("class".to_string()),
+            Stream::Variable("Calculator".to_string()),
+        ],
+        trace[0].gram_parts
+    );
+    assert_eq!("3", trace[1].line_num.as_str());
+    assert_eq!("{", trace[1].end.as_str());
+    assert_eq!(
+        vec![
+            Stream::Access("public".to_string()),
+            Stream::Modifier("static".to_string()),
+            Stream::Type("int".to_string()),
+            Stream::Variable("add".to_string()),
+            Stream::Type("int".to_string()),
+            Stream::Variable("a".to_string()),
+            Stream::Type("int".to_string()),
+            Stream::Variable("b".to_string()),
+        ],
+        trace[1].gram_parts
+    );
+    assert_eq!(
+        vec![
+            JdocToken::Symbol("Adds".to_string()),
+            JdocToken::Symbol("two".to_string()),
+            JdocToken::Symbol("numbers".to_string()),
+        ],
+        trace[1].doc_tokens
+    );
+}
+
+#[test]
+fn test_qualified_exceptions() {
+    let j_class = "public class Loader {
+    /**
+     * Loads the file
+     * @throws IOException When the file can't be read
+     * @throws SQLException When the rows can't be stored
+     */
+    public void load(String path) throws java.io.IOException, java.sql.SQLException {}
+
+    /**
+     * Closes the loader
+     * @throws java.io.IOException When closing fails
+     */
+    public void close() throws IOException {}
+}";
+
+    match parse_str(j_class).unwrap() {
+        ObjectType::Class(class) => {
+            let load = &class.methods[0];
+            let close = &class.methods[1];
+
+            assert_eq!(2, load.exceptions.len());
+            assert_eq!("java.io.IOException", load.exceptions[0].exception_type.as_str());
+            assert_eq!("When the file can't be read", load.exceptions[0].desc.as_str());
+            assert_eq!("java.sql.SQLException", load.exceptions[1].exception_type.as_str());
+            assert_eq!("When the rows can't be stored", load.exceptions[1].desc.as_str());
+            assert_eq!(1, close.exceptions.len());
+            assert_eq!("IOException", close.exceptions[0].exception_type.as_str());
+            assert_eq!("When closing fails", close.exceptions[0].desc.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_description_inline_tags() {
+    let j_class = "public class Counter {
+    /**
+     * Counts the calls. {@code count()} is cheap, so it can be
+     * called often. Calls marked {@code @since 2} are counted too.
+     * @return The number of calls
+     */
+    public int count() {}
+
+    /** Gets the size. {@code size()} then the rest. @return The size */
+    public int size() {}
+}";
+
+    match parse_str(j_class).unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!(
+                "Counts the calls. {@code count()} is cheap, so it can be called often. Calls marked {@code @since 2} are counted too.",
+                class.methods[0].description.as_str()
+            );
+            assert_eq!("The number of calls", class.methods[0].return_desc.as_str());
+            assert_eq!("", class.methods[0].since.as_str());
+            assert_eq!("Gets the size. {@code size()} then the rest.", class.methods[1].description.as_str());
+            assert_eq!("The size", class.methods[1].return_desc.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_streaming_lexer() {
+    let source = "\u{feff}package com.example;\r
+\r
+/** Caches {@code Map<String, List<Integer>>} values */\r
+public class Cache<K, V> {\r
+    // Not a \"string\" or a { brace\r
+    private Map<String, List<Integer>> values = new HashMap<>();\r
+\r
+    public V get(K key) {\r
+        return values.get(\"}\");\r
+    }\r
+\r
+    static class Entry {\r
+        int hits;\r
+    }\r
+}";
+
+    let buffered = lex_contents(&source.to_string());
+    let streamed: Vec<Token> = lex_reader(BufReader::with_capacity(8, source.as_bytes()))
+        .collect::<io::Result<Vec<Token>>>()
+        .unwrap();
+    assert_eq!(buffered, streamed);
+
+    let path = Path::new("tests/fixtures/project/com/example/service/UserService.java");
+    let contents = fs::read_to_string(path).unwrap();
+    let streamed: Vec<Token> = lex_reader(BufReader::new(fs::File::open(path).unwrap()))
+        .collect::<io::Result<Vec<Token>>>()
+        .unwrap();
+    assert_eq!(lex_contents(&contents), streamed);
+
+    // Invalid UTF-8 is an error instead of the end of the file
+    let invalid: &[u8] = b"public class Broken {\n    int \xff value;\n}\n";
+    let streamed: Vec<io::Result<Token>> = lex_reader(BufReader::new(invalid)).collect();
+    assert!(streamed.last().unwrap().is_err());
+    assert!(streamed[..streamed.len() - 1].iter().all(|token| token.is_ok()));
+}
+
+#[test]
+fn test_at_signs_in_comments() {
+    let j_class = "/**
+ * Sends mail. Subclasses mark their overrides with @Override.
+ *
+ * @author bob@example.com
+ */
+public class Mailer {
+    /* Questions go to alice@example.com or @support */
+    private String owner = \"bob@example.com\";
+
+    // Reach bob@example.com, see @Deprecated
+    @Deprecated
+    public void send(String to) {
+    }
+}";
+
+    let outcome = parse_str_with_diagnostics(j_class);
+    assert_eq!(0, outcome.diagnostics.len());
+
+    match outcome.objects[0] {
+        ObjectType::Class(ref class) => {
+            assert_eq!("bob@example.com", class.author.as_str());
+            assert_eq!(
+                "Sends mail. Subclasses mark their overrides with @Override.",
+                class.description.as_str()
+            );
+
+            assert_eq!("owner", class.variables[0].name.as_str());
+            assert_eq!("\"bob@example.com\"", class.variables[0].default_value.as_str());
+            assert_eq!(0, class.variables[0].annotations.len());
+
+            let method = &class.methods[0];
+            assert_eq!("send", method.name.as_str());
+            assert_eq!(1, method.annotations.len());
+            assert_eq!("Deprecated", method.annotations[0].name.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_nested_interfaces_and_enums() {
+    let source = "package com.example;
+
+/** A job scheduler */
+public class Scheduler {
+    /** Receives job events */
+    public interface Listener {
+        /** The default priority */
+        int PRIORITY = 5;
+
+        /** The outcome of a job */
+        enum Outcome { PASSED, FAILED }
+
+        /**
+         * Called when a job finishes
+         * @param id The job id
+         */
+        void finished(int id);
+    }
+
+    /** The state of a job */
+    public enum State {
+        WAITING,
+        RUNNING;
+
+        /** Whether the job is done */
+        public boolean isDone() {
+            return false;
+        }
+    }
+
+    private Listener listener;
+
+    /** Schedules a job */
+    public void schedule(Runnable job) {}
+}";
+    let types = construct_types(lex_contents(&source.to_string()));
+    assert_eq!(4, types.len());
+
+    match &types[0] {
+        ObjectType::Class(class) => {
+            assert_eq!("Scheduler", class.name.as_str());
+            assert_eq!("listener", class.variables[0].name.as_str());
+            assert_eq!(1, class.methods.len());
+            assert_eq!("schedule", class.methods[0].name.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+    match &types[1] {
+        ObjectType::Interface(inter) => {
+            assert_eq!("Scheduler.Listener", inter.name.as_str());
+            assert_eq!("Receives job events", inter.description.as_str());
+            assert_eq!("PRIORITY", inter.variables[0].name.as_str());
+            assert_eq!("finished", inter.methods[0].name.as_str());
+            assert_eq!("The job id", inter.methods[0].parameters[0].desc.as_str());
+        }
+        _ => panic!("Expected an interface"),
+    }
+    match &types[2] {
+        ObjectType::Enumeration(enum_ob) => {
+            assert_eq!("Scheduler.Listener.Outcome", enum_ob.name.as_str());
+            let fields: Vec<&str> = enum_ob.fields.iter().map(|f| f.name.as_str()).collect();
+            assert_eq!(vec!["PASSED", "FAILED"], fields);
+        }
+        _ => panic!("Expected an enum"),
+    }
+    match &types[3] {
+        ObjectType::Enumeration(enum_ob) => {
+            assert_eq!("Scheduler.State", enum_ob.name.as_str());
+            assert_eq!("The state of a job", enum_ob.description.as_str());
+            let fields: Vec<&str> = enum_ob.fields.iter().map(|f| f.name.as_str()).collect();
+            assert_eq!(vec!["WAITING", "RUNNING"], fields);
+            assert_eq!("isDone", enum_ob.methods[0].name.as_str());
+        }
+        _ => panic!("Expected an enum"),
+    }
+}
+
+#[test]
+fn test_fields_and_method_signatures() {
+    let j_inter = "public interface Store {
+    int MAX = 10;
+
+    int size();
+
+    void put(String key, String value);
+}";
+    match parse_str(j_inter).unwrap() {
+        ObjectType::Interface(inter) => {
+            let fields: Vec<&str> = inter.variables.iter().map(|v| v.name.as_str()).collect();
+            let methods: Vec<&str> = inter.methods.iter().map(|m| m.name.as_str()).collect();
+
+            assert_eq!(vec!["MAX"], fields);
+            assert_eq!(vec!["size", "put"], methods);
+            assert_eq!(2, inter.methods[1].parameters.len());
+        }
+        _ => panic!("Expected an interface"),
+    }
+
+    let j_class = "public abstract class MemoryStore {
+    private int count;
+
+    @Size(max = 3)
+    private List<String> keys = new ArrayList<>();
+
+    public abstract int size();
+
+    protected native void flush(int level);
+}";
+    match parse_str(j_class).unwrap() {
+        ObjectType::Class(class) => {
+            let fields: Vec<&str> = class.variables.iter().map(|v| v.name.as_str()).collect();
+            let methods: Vec<&str> = class.methods.iter().map(|m| m.name.as_str()).collect();
+
+            assert_eq!(vec!["count", "keys"], fields);
+            assert_eq!("new ArrayList<>()", class.variables[1].default_value.as_str());
+            assert_eq!(vec!["size", "flush"], methods);
+            assert_eq!("level", class.methods[1].parameters[0].name.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_bare_deprecated_tag() {
+    let j_class = "/**
+ * A legacy parser
+ * @deprecated
+ */
+public class Parser {
+    /**
+     * Parses the input
+     * @deprecated
+     * @since 1.0
+     */
+    public void parse() {}
+
+    /**
+     * Resets the parser
+     * @deprecated Use a new parser
+     */
+    public void reset() {}
+
+    /** Closes the parser */
+    public void close() {}
+}";
+
+    match parse_str(j_class).unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!(Some(String::new()), class.deprecation);
+            assert_eq!("A legacy parser", class.description.as_str());
+
+            assert_eq!(Some(String::new()), class.methods[0].deprecation);
+            assert!(class.methods[0].is_deprecated());
+            assert_eq!("1.0", class.methods[0].since.as_str());
+
+            assert_eq!(Some("Use a new parser".to_string()), class.methods[1].deprecation);
+            assert!(!class.methods[2].is_deprecated());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_annotation_type() {
+    let j_annotation = "package com.example;
+
+/**
+ * Retries a failing test
+ */
+public @interface Retry {
+    /** The number of attempts */
+    int value() default 3;
+
+    String reason() default \"\";
+
+    Class<?>[] exclude();
+}";
+
+    match parse_str(j_annotation).unwrap() {
+        ObjectType::Annotation(annotation) => {
+            assert_eq!("Retry", annotation.name.as_str());
+            assert_eq!("com.example", annotation.package_name.as_str());
+            assert_eq!("Retries a failing test", annotation.description.as_str());
+            assert_eq!(3, annotation.elements.len());
+
+            assert_eq!("value", annotation.elements[0].name.as_str());
+            assert_eq!("int", annotation.elements[0].var_type.as_str());
+            assert_eq!("3", annotation.elements[0].default_value.as_str());
+            assert_eq!("The number of attempts", annotation.elements[0].desc.as_str());
+
+            assert_eq!("reason", annotation.elements[1].name.as_str());
+            assert_eq!("\"\"", annotation.elements[1].default_value.as_str());
+
+            assert_eq!("exclude", annotation.elements[2].name.as_str());
+            assert_eq!("Class<?>[]", annotation.elements[2].var_type.as_str());
+            assert_eq!("", annotation.elements[2].default_value.as_str());
+        }
+        _ => panic!("Expected an annotation type"),
+    }
+}
+
+#[test]
+fn test_comments_after_annotations() {
+    let j_class = "public class Worker {
+    /** Starts the work */
+    @Override // inherited
+    public void run() {}
+
+    @Deprecated /* old */ @SuppressWarnings(\"unused\") // kept for callers
+    // still read by the scheduler
+    public int retries;
+
+    @Test(timeout = 5)// slow
+    void check() {}
+
+    @Override// glued
+    public String toString() {
+        return \"\";
+    }
+}";
+
+    match parse_str(j_class).unwrap() {
+        ObjectType::Class(class) => {
+            let methods: Vec<(&str, Vec<&str>)> = class
+                .methods
+                .iter()
+                .map(|m| (m.name.as_str(), m.annotations.iter().map(|a| a.name.as_str()).collect()))
+                .collect();
+            assert_eq!(
+                vec![("run", vec!["Override"]), ("check", vec!["Test"]), ("toString", vec!["Override"])],
+                methods
+            );
+            assert_eq!("Starts the work", class.methods[0].description.as_str());
+            assert_eq!("public void run() {}", class.methods[0].signature.as_str());
+            assert_eq!("timeout = 5", class.methods[1].annotations[0].args.as_str());
+            assert_eq!("String", class.methods[2].return_type.as_str());
+
+            assert_eq!(1, class.variables.len());
+            assert_eq!("retries", class.variables[0].name.as_str());
+            let annotations: Vec<&str> = class.variables[0].annotations.iter().map(|a| a.name.as_str()).collect();
+            assert_eq!(vec!["Deprecated", "SuppressWarnings"], annotations);
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_interface_extends_several() {
+    let j_interface = "public interface Channel<T> extends Readable<T>, java.io.Closeable {
+    void flush();
+}";
+
+    let inter = parse_str(j_interface).unwrap();
+    match &inter {
+        ObjectType::Interface(inter) => {
+            assert_eq!("Channel", inter.name.as_str());
+            assert_eq!(vec!["Readable<T>", "java.io.Closeable"], inter.parents);
+            assert_eq!(1, inter.methods.len());
+        }
+        _ => panic!("Expected an interface"),
+    }
+    assert!(::document::document::to_markdown(&inter).contains("Extends: `Readable<T>`, `java.io.Closeable`  \n"));
+
+    match parse_str("public class Pipe extends Base implements Channel<String>, Flushable {}").unwrap() {
+        ObjectType::Class(class) => {
+            assert_eq!("Base", class.parent.as_str());
+            assert_eq!(vec!["Channel<String>", "Flushable"], class.interfaces);
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_annotated_generic_declarations() {
+    let source = "package com.example;
+
+/** A sealed shape */
+@Immutable
+@SuppressWarnings(\"unchecked\") public sealed interface Shape<T extends Number & Comparable<T>> permits Circle, Square {
+    T area();
+}
+
+@Entity(name = \"circle\")
+final class Circle<T extends Number> extends Base<T> implements Shape<T>, Comparable<Circle<T>> {
+    public T area() {
+        return null;
+    }
+}
+
+non-sealed class Square implements Shape<Integer> {}
+";
+    let outcome = parse_str_with_diagnostics(source);
+
+    let unsupported: Vec<&str> = outcome.diagnostics.iter().map(|d| d.construct.as_str()).collect();
+    assert_eq!(Vec::<&str>::new(), unsupported);
+    assert_eq!(3, outcome.objects.len());
+
+    match &outcome.objects[0] {
+        ObjectType::Interface(inter) => {
+            assert_eq!("Shape", inter.name.as_str());
+            assert_eq!(vec!["T extends Number & Comparable<T>"], inter.type_params);
+            assert_eq!(vec!["Circle", "Square"], inter.permits);
+            let annotations: Vec<(&str, &str)> =
+                inter.annotations.iter().map(|a| (a.name.as_str(), a.args.as_str())).collect();
+            assert_eq!(vec![("Immutable", ""), ("SuppressWarnings", "\"unchecked\"")], annotations);
+        }
+        _ => panic!("Expected an interface"),
+    }
+    assert!(::document::document::to_markdown(&outcome.objects[0]).contains("Permits: `Circle`, `Square`  \n"));
+
+    match &outcome.objects[1] {
+        ObjectType::Class(class) => {
+            assert_eq!("Circle", class.name.as_str());
+            assert_eq!(vec!["T extends Number"], class.type_params);
+            assert_eq!("Base<T>", class.parent.as_str());
+            assert_eq!(vec!["Shape<T>", "Comparable<Circle<T>>"], class.interfaces);
+            assert_eq!("Entity", class.annotations[0].name.as_str());
+            assert_eq!("name = \"circle\"", class.annotations[0].args.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+
+    match &outcome.objects[2] {
+        ObjectType::Class(class) => {
+            assert_eq!("Square", class.name.as_str());
+            assert_eq!(vec!["non-sealed"], class.modifiers);
+            assert_eq!(0, class.annotations.len());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_field_initializers_with_calls() {
+    let j_class = "public class Foo {
+    private final Logger log = LoggerFactory.getLogger(Foo.class);
+
+    private Runnable task = new Runnable() {
+        public void run() {}
+    };
+
+    private int[] sizes = {1, 2};
+
+    public Foo() { this(1); }
+
+    public Foo(int size) {
+        super(size);
+    }
+
+    public void start() {}
+}";
+
+    match parse_str(j_class).unwrap() {
+        ObjectType::Class(class) => {
+            let fields: Vec<(&str, &str, &str)> = class
+                .variables
+                .iter()
+                .map(|v| (v.var_type.as_str(), v.name.as_str(), v.default_value.as_str()))
+                .collect();
+            assert_eq!(
+                vec![
+                    ("Logger", "log", "LoggerFactory.getLogger(Foo.class)"),
+                    ("Runnable", "task", "new Runnable() { ... }"),
+                    ("int[]", "sizes", "{ ... }"),
+                ],
+                fields
+            );
+            assert_eq!(vec!["final"], class.variables[0].modifiers);
+
+            let methods: Vec<(&str, bool)> = class.methods.iter().map(|m| (m.name.as_str(), m.is_constructor)).collect();
+            assert_eq!(vec![("Foo", true), ("Foo", true), ("start", false)], methods);
+            assert_eq!("size", class.methods[1].parameters[0].name.as_str());
+        }
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_multi_line_enum_constants() {
+    let j_enum = "public enum Planet {
+    /** The closest planet to the sun */
+    MERCURY(3.303e+23, 2.4397e6),
+    VENUS(4.869e+24, Radius.of(6.0518e6)),
+    EARTH(5.976e+24, 6.37814e6) {
+        public boolean inhabited() { return true; }
+    },
+    ;
+
+    private final double mass;
+
+    Planet(double mass, double radius) {
+        this.mass = mass;
+    }
+}";
+
+    let planet = parse_str(j_enum).unwrap();
+    match &planet {
+        ObjectType::Enumeration(enum_ob) => {
+            let fields: Vec<(&str, &str, &str)> = enum_ob
+                .fields
+                .iter()
+                .map(|f| (f.name.as_str(), f.value.as_str(), f.args.as_str()))
+                .collect();
+            assert_eq!(
+                vec![
+                    ("MERCURY", "0", "3.303e+23, 2.4397e6"),
+                    ("VENUS", "1", "4.869e+24, Radius.of(6.0518e6)"),
+                    ("EARTH", "2", "5.976e+24, 6.37814e6"),
+                ],
+                fields
+            );
+
+            assert_eq!(1, enum_ob.variables.len());
+            assert_eq!("mass", enum_ob.variables[0].name.as_str());
+            assert_eq!(1, enum_ob.methods.len());
+            assert!(enum_ob.methods[0].is_constructor);
+            assert_eq!(2, enum_ob.methods[0].parameters.len());
+        }
+        _ => panic!("Expected an enum"),
+    }
+    assert!(::document::document::to_markdown(&planet).contains("- MERCURY(3.303e+23, 2.4397e6)  \n- VENUS"));
+
+    match parse_str("enum Level {\n    LOW,\n    MEDIUM,\n    HIGH\n}").unwrap() {
+        ObjectType::Enumeration(enum_ob) => {
+            let names: Vec<&str> = enum_ob.fields.iter().map(|f| f.name.as_str()).collect();
+            assert_eq!(vec!["LOW", "MEDIUM", "HIGH"], names);
+        }
+        _ => panic!("Expected an enum"),
+    }
+}
+
+#[test]
+fn test_custom_tags() {
+    let source = "/**
+     * A cache of the loaded users
+     *
+     * @reviewedBy Alice
+     */
+    public class UserCache {
+        /**
+         * Clears the cache
+         *
+         * @reviewedBy Bob and Carol
+         */
+        public void clear() {}
+    }";
+
+    let outcome = parse_str_with_tags(source, &["reviewedBy"]);
+    let class = match outcome.objects[0] {
+        ObjectType::Class(ref c) => c,
+        _ => panic!("Expected a class"),
+    };
+
+    assert_eq!(0, outcome.diagnostics.len());
+    assert_eq!(vec![("reviewedBy".to_string(), "Alice".to_string())], class.custom_tags);
+    assert_eq!("A cache of the loaded users", class.description.as_str());
+    assert_eq!(vec![("reviewedBy".to_string(), "Bob and Carol".to_string())], class.methods[0].custom_tags);
+    assert_eq!("Clears the cache", class.methods[0].description.as_str());
+
+    let markdown = ::document::document::to_markdown(&outcome.objects[0]);
+    assert!(markdown.contains("reviewedBy: Alice  \n"));
+    assert!(markdown.contains("+ reviewedBy: Bob and Carol  \n"));
+
+    // A tag that is not registered is not captured
+    match parse_str(source).unwrap() {
+        ObjectType::Class(c) => assert_eq!(0, c.custom_tags.len()),
+        _ => panic!("Expected a class"),
+    }
+}
+
+#[test]
+fn test_no_type_found() {
+    let source = "package com.example.scripts;
+
+    import java.util.List;
+    import java.util.Map;
+
+    // Nothing is declared here yet";
+
+    assert!(construct_ast(lex_contents(&source.to_string())).is_none());
+    assert!(parse_str(source).is_none());
+    assert_eq!(0, parse_types(source).len());
+
+    let outcome = parse_str_with_diagnostics(source);
+    assert_eq!(0, outcome.objects.len());
+    assert_eq!(1, outcome.diagnostics.len());
+    assert_eq!("Unsupported file, no type found", outcome.diagnostics[0].message());
+}
+
+
+#[test]
+fn test_parse_doc_comment() {
+    let doc = parse_doc_comment("/**
+     * Reads a user from the store
+     *
+     * <p>The user is cached after the first read.
+     *
+     * @param id The id of the user
+     * @return The user with the id
+     * @throws NotFoundException When no user has the id
+     */");
+
+    assert_eq!("Reads a user from the store\n\nThe user is cached after the first read.", doc.description.as_str());
+    assert_eq!(1, doc.params.len());
+    assert_eq!("id", doc.params[0].name.as_str());
+    assert_eq!("The id of the user", doc.params[0].desc.as_str());
+    assert_eq!("The user with the id", doc.return_desc.as_str());
+    assert_eq!(1, doc.exceptions.len());
+    assert_eq!("NotFoundException", doc.exceptions[0].exception_type.as_str());
+    assert_eq!("When no user has the id", doc.exceptions[0].desc.as_str());
+
+    // The comment markers are optional
+    let doc = parse_doc_comment("Counts the users @return The count");
+    assert_eq!("Counts the users", doc.description.as_str());
+    assert_eq!("The count", doc.return_desc.as_str());
+}
+
+#[test]
+fn test_unexpected_expression_end() {
+    let tokens = vec![
+        Token::LineNumber("1".to_string()),
+        Token::Keyword("public".to_string()),
+        Token::Keyword("class".to_string()),
+        Token::Symbol("Stray".to_string()),
+        Token::ExpressionEnd("{".to_string()),
+        Token::LineNumber("2".to_string()),
+        Token::ExpressionEnd(")".to_string()),
+        Token::LineNumber("3".to_string()),
+        Token::ExpressionEnd("}".to_string()),
+    ];
+
+    let outcome = construct_ast_with_diagnostics(tokens);
+    assert_eq!(1, outcome.objects.len());
+    assert_eq!(1, outcome.diagnostics.len());
+    assert_eq!("Unsupported expression end `)` on line 2", outcome.diagnostics[0].message());
+
+    // A stray closing brace in the source is skipped as well
+    let outcome = parse_str_with_diagnostics("public class Stray {\n    public void run() {}\n}\n}\n");
+    assert_eq!(1, outcome.objects.len());
+}
@@ -0,0 +1,19 @@
+package com.example;
+
+/**
+ * Runs tasks
+ */
+public class Outer {
+    /**
+     * Starts the inner task
+     *
+     * @see com.example.Outer.Inner#run
+     */
+    public void start() {}
+
+    /** The inner task */
+    public static class Inner {
+        /** Runs the inner task */
+        public void run() {}
+    }
+}
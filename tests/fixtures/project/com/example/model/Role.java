@@ -0,0 +1,8 @@
+package com.example.model;
+
+/**
+ * The roles a user can have
+ */
+public enum Role {
+    ADMIN, MEMBER, GUEST;
+}
@@ -0,0 +1,22 @@
+package com.example.model;
+
+import java.util.List;
+
+/**
+ * A user of the application
+ *
+ * @author Jane Doe
+ */
+public class User {
+    /** The user name */
+    private String name;
+
+    /**
+     * Gets the name of the user
+     *
+     * @return The user name
+     */
+    public String getName() {
+        return name;
+    }
+}
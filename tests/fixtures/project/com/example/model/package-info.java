@@ -0,0 +1,5 @@
+/**
+ * The domain model of the example application,
+ * shared by every service
+ */
+package com.example.model;
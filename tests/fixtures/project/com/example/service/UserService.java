@@ -0,0 +1,17 @@
+package com.example.service;
+
+import com.example.model.User;
+
+/**
+ * Looks up users
+ */
+public interface UserService {
+    /**
+     * Finds a user by name
+     *
+     * @param name The name of the user
+     * @return The matching user
+     * @see User#getName
+     */
+    User findUser(String name);
+}